@@ -0,0 +1,201 @@
+use super::*;
+
+const NEWTON_TRIALS: usize = 32;
+
+impl<C> ArcLengthReparametrized<C>
+where
+    C: BoundedCurve,
+    C::Vector: InnerSpace<Scalar = f64>,
+{
+    /// Reparametrizes `curve` by the arc length.
+    /// The parameter range of the returned curve is `(0.0, length)`.
+    ///
+    /// # Panics
+    ///
+    /// `tol` must be greater than or equal to `TOLERANCE`.
+    pub fn new(curve: C, tol: f64) -> Self {
+        let table = algo::curve::arc_length_table(&curve, curve.range_tuple(), tol);
+        Self {
+            curve,
+            table,
+            scalar: 1.0,
+        }
+    }
+
+    /// Returns the parameter of the entity curve corresponding to the parameter `s` of `self`.
+    pub fn curve_parameter(&self, s: f64) -> f64 {
+        let idx = self.table.partition_point(|&(_, x)| x < s);
+        let idx = idx.clamp(1, self.table.len() - 1);
+        let ((t0, s0), (t1, s1)) = (self.table[idx - 1], self.table[idx]);
+        let length = (s - s0) / self.scalar;
+        let mut t = match s1 - s0 > 0.0 {
+            true => t0 + (t1 - t0) * (s - s0) / (s1 - s0),
+            false => t0,
+        };
+        for _ in 0..NEWTON_TRIALS {
+            let speed = self.curve.der(t).magnitude();
+            if speed.so_small() {
+                break;
+            }
+            let delta = (algo::curve::gauss_legendre_length(&self.curve, (t0, t)) - length) / speed;
+            t -= delta;
+            if delta.abs() < TOLERANCE2 {
+                break;
+            }
+        }
+        t
+    }
+
+    /// Returns the parameter of `self` corresponding to the parameter `t` of the entity curve.
+    pub fn arc_length_parameter(&self, t: f64) -> f64 {
+        let idx = self.table.partition_point(|&(x, _)| x < t);
+        let (t0, s0) = self.table[idx.clamp(1, self.table.len() - 1) - 1];
+        s0 + algo::curve::gauss_legendre_length(&self.curve, (t0, t)) * self.scalar
+    }
+}
+
+impl<C> ArcLengthReparametrized<C> {
+    /// Returns the reference of the entity curve.
+    #[inline(always)]
+    pub const fn entity_curve(&self) -> &C { &self.curve }
+    /// Returns the entity curve.
+    #[inline(always)]
+    pub fn into_entity_curve(self) -> C { self.curve }
+
+    fn map_hint(&self, hint: SPHint1D) -> SPHint1D
+    where
+        C: BoundedCurve,
+        C::Vector: InnerSpace<Scalar = f64>, {
+        match hint {
+            SPHint1D::Parameter(s) => SPHint1D::Parameter(self.curve_parameter(s)),
+            SPHint1D::Range(s0, s1) => {
+                SPHint1D::Range(self.curve_parameter(s0), self.curve_parameter(s1))
+            }
+            SPHint1D::None => SPHint1D::None,
+        }
+    }
+}
+
+impl<C> ParametricCurve for ArcLengthReparametrized<C>
+where
+    C: BoundedCurve,
+    C::Vector: InnerSpace<Scalar = f64>,
+{
+    type Point = C::Point;
+    type Vector = C::Vector;
+    #[inline(always)]
+    fn subs(&self, s: f64) -> Self::Point { self.curve.subs(self.curve_parameter(s)) }
+    #[inline(always)]
+    fn der(&self, s: f64) -> Self::Vector { self.ders(1, s)[1] }
+    #[inline(always)]
+    fn der2(&self, s: f64) -> Self::Vector { self.ders(2, s)[2] }
+    #[inline(always)]
+    fn der_n(&self, n: usize, s: f64) -> Self::Vector { self.ders(n, s)[n] }
+    fn ders(&self, n: usize, s: f64) -> CurveDers<Self::Vector> {
+        let cders = self.curve.ders(n, self.curve_parameter(s));
+        if n == 0 {
+            return cders;
+        }
+        // the derivations of h(t) = dt/ds by t, from h(t) * |c'(t)| = 1 / scalar.
+        let speed = cders.der().abs_ders();
+        let mut h = CurveDers::new(n - 1);
+        h[0] = 1.0 / (self.scalar * speed[0]);
+        (1..n).for_each(|m| {
+            let mut c = 1.0;
+            let sum = (1..=m).fold(0.0, |sum, l| {
+                c = c * (m + 1 - l) as f64 / l as f64;
+                sum + c * speed[l] * h[m - l]
+            });
+            h[m] = -sum / speed[0];
+        });
+        // d/ds = h(t) d/dt
+        let mut res = CurveDers::new(n);
+        res[0] = cders[0];
+        let mut f = cders;
+        (1..=n).for_each(|k| {
+            f = h.combinatorial_ders(&f.der(), |h, v| v * h);
+            res[k] = f[0];
+        });
+        res
+    }
+    #[inline(always)]
+    fn parameter_range(&self) -> ParameterRange {
+        let (s0, s1) = (self.table[0].1, self.table[self.table.len() - 1].1);
+        (Bound::Included(s0), Bound::Included(s1))
+    }
+}
+
+impl<C> BoundedCurve for ArcLengthReparametrized<C>
+where
+    C: BoundedCurve,
+    C::Vector: InnerSpace<Scalar = f64>,
+{
+}
+
+impl<C> ParameterTransform for ArcLengthReparametrized<C>
+where
+    C: BoundedCurve,
+    C::Vector: InnerSpace<Scalar = f64>,
+{
+    fn parameter_transform(&mut self, scalar: f64, r#move: f64) -> &mut Self {
+        assert!(scalar > 0.0, "scalar must be positive.");
+        self.table
+            .iter_mut()
+            .for_each(|(_, s)| *s = *s * scalar + r#move);
+        self.scalar *= scalar;
+        self
+    }
+}
+
+impl<C> SearchNearestParameter<D1> for ArcLengthReparametrized<C>
+where
+    C: BoundedCurve + SearchNearestParameter<D1>,
+    C::Vector: InnerSpace<Scalar = f64>,
+{
+    type Point = <C as SearchNearestParameter<D1>>::Point;
+    fn search_nearest_parameter<H: Into<SPHint1D>>(
+        &self,
+        pt: Self::Point,
+        hint: H,
+        trials: usize,
+    ) -> Option<f64> {
+        let hint = self.map_hint(hint.into());
+        let t = self.curve.search_nearest_parameter(pt, hint, trials)?;
+        Some(self.arc_length_parameter(t))
+    }
+}
+
+impl<C> SearchParameter<D1> for ArcLengthReparametrized<C>
+where
+    C: BoundedCurve + SearchParameter<D1>,
+    C::Vector: InnerSpace<Scalar = f64>,
+{
+    type Point = <C as SearchParameter<D1>>::Point;
+    fn search_parameter<H: Into<SPHint1D>>(
+        &self,
+        pt: Self::Point,
+        hint: H,
+        trials: usize,
+    ) -> Option<f64> {
+        let hint = self.map_hint(hint.into());
+        let t = self.curve.search_parameter(pt, hint, trials)?;
+        Some(self.arc_length_parameter(t))
+    }
+}
+
+impl<C> ParameterDivision1D for ArcLengthReparametrized<C>
+where
+    C: BoundedCurve + ParameterDivision1D,
+    C::Vector: InnerSpace<Scalar = f64>,
+{
+    type Point = <C as ParameterDivision1D>::Point;
+    fn parameter_division(&self, (s0, s1): (f64, f64), tol: f64) -> (Vec<f64>, Vec<Self::Point>) {
+        let range = (self.curve_parameter(s0), self.curve_parameter(s1));
+        let (params, pts) = self.curve.parameter_division(range, tol);
+        let params = params
+            .into_iter()
+            .map(|t| self.arc_length_parameter(t))
+            .collect();
+        (params, pts)
+    }
+}
@@ -175,6 +175,51 @@ impl ToSameGeometry<Plane> for ExtrudedCurve<Line<Point3>, Vector3> {
     fn to_same_geometry(&self) -> Plane { (*self).into() }
 }
 
+fn extrude_nurbs(
+    curve: NurbsCurve<Vector4>,
+    vector: Vector3,
+    (v0, v1): (f64, f64),
+) -> Option<NurbsSurface<Vector4>> {
+    if v1 - v0 < TOLERANCE {
+        return None;
+    }
+    let mut vknot_vec = KnotVec::bezier_knot(1);
+    vknot_vec.transform(v1 - v0, v0);
+    let control_points = curve
+        .control_points()
+        .iter()
+        .map(|p| {
+            vec![
+                *p + (vector * (v0 * p.w)).extend(0.0),
+                *p + (vector * (v1 * p.w)).extend(0.0),
+            ]
+        })
+        .collect();
+    let knot_vecs = (curve.knot_vec().clone(), vknot_vec);
+    Some(NurbsSurface::new(BSplineSurface::new_unchecked(
+        knot_vecs,
+        control_points,
+    )))
+}
+
+impl<C> ToNurbs<D2> for ExtrudedCurve<C, Vector3>
+where C: ToNurbs<D1, Range = (f64, f64), Nurbs = NurbsCurve<Vector4>>
+{
+    type Range = ((f64, f64), (f64, f64));
+    type Nurbs = NurbsSurface<Vector4>;
+    #[inline(always)]
+    fn to_nurbs_in(
+        &self,
+        (urange, vrange): ((f64, f64), (f64, f64)),
+    ) -> Option<NurbsSurface<Vector4>> {
+        extrude_nurbs(self.curve.to_nurbs_in(urange)?, self.vector, vrange)
+    }
+    #[inline(always)]
+    fn to_nurbs(&self) -> Option<NurbsSurface<Vector4>> {
+        extrude_nurbs(self.curve.to_nurbs()?, self.vector, (0.0, 1.0))
+    }
+}
+
 #[test]
 fn extrude_line() {
     let p = Point3::new(1.0, 2.0, 3.0);
@@ -191,6 +191,118 @@ where P: ControlPoint<f64> + Tolerance
     }
 }
 
+/// The product of the homogeneous curve `curve` and the weight of `weight`,
+/// whose degrees and knot vectors are the same.
+/// The product is computed exactly on each Bezier segment and the removable knots are removed.
+fn multiply_weight(
+    curve: &BSplineCurve<Vector4>,
+    weight: &BSplineCurve<Vector4>,
+) -> BSplineCurve<Vector4> {
+    let degree = curve.degree();
+    let binomial =
+        |n: usize, k: usize| (0..k).fold(1.0, |c, i| c * (n - i) as f64 / (i + 1) as f64);
+    let mut control_points = Vec::new();
+    curve
+        .bezier_decomposition()
+        .iter()
+        .zip(weight.bezier_decomposition())
+        .for_each(|(bezier0, bezier1)| {
+            let (a, b) = (bezier0.control_points(), bezier1.control_points());
+            let product = (0..=2 * degree).map(|k| {
+                let sum = (k.saturating_sub(degree)..=usize::min(k, degree)).fold(
+                    Vector4::zero(),
+                    |sum, i| {
+                        let coef = binomial(degree, i) * binomial(degree, k - i);
+                        sum + a[i] * (b[k - i].w * coef)
+                    },
+                );
+                sum / binomial(2 * degree, k)
+            });
+            // the first point of a segment is the last one of the previous segment.
+            let skip = usize::from(!control_points.is_empty());
+            control_points.extend(product.skip(skip));
+        });
+    let (knots, _) = curve.knot_vec().to_single_multi();
+    let mults = (0..knots.len())
+        .map(|i| match i == 0 || i == knots.len() - 1 {
+            true => 2 * degree + 1,
+            false => 2 * degree,
+        })
+        .collect();
+    let knot_vec = KnotVec::from_single_multi(knots, mults).unwrap();
+    let mut product = BSplineCurve::new_unchecked(knot_vec, control_points);
+    product.optimize();
+    product
+}
+
+/// The ruled surface between two NURBS curves.
+///
+/// If the weights of the curves are different, the homogeneous coordinates of each curve are
+/// multiplied by the weight of the other curve, so that the ruled lines are kept in the model space.
+fn ruled_nurbs(
+    curve0: NurbsCurve<Vector4>,
+    curve1: NurbsCurve<Vector4>,
+    (v0, v1): (f64, f64),
+) -> Option<NurbsSurface<Vector4>> {
+    if v1 - v0 < TOLERANCE {
+        return None;
+    }
+    let (t0, t1) = curve0.range_tuple();
+    let mut bspcurve0 = curve0.into_non_rationalized();
+    let mut bspcurve1 = curve1.into_non_rationalized();
+    bspcurve0.syncro_degree(&mut bspcurve1);
+    bspcurve0.syncro_knots(&mut bspcurve1);
+    let (points0, points1) = (bspcurve0.control_points(), bspcurve1.control_points());
+    if !points0.iter().zip(points1).all(|(p, q)| p.w.near(&q.w)) {
+        let product0 = multiply_weight(&bspcurve0, &bspcurve1);
+        let mut product1 = multiply_weight(&bspcurve1, &bspcurve0);
+        bspcurve0 = product0;
+        bspcurve0.syncro_knots(&mut product1);
+        bspcurve1 = product1;
+    }
+    let (points0, points1) = (bspcurve0.control_points(), bspcurve1.control_points());
+    let control_points = points0
+        .iter()
+        .zip(points1)
+        .map(|(p, q)| vec![*p + (*q - *p) * v0, *p + (*q - *p) * v1])
+        .collect();
+    let mut vknot_vec = KnotVec::bezier_knot(1);
+    vknot_vec.transform(v1 - v0, v0);
+    // `syncro_knots` normalizes the knot vectors.
+    let mut uknot_vec = bspcurve0.knot_vec().clone();
+    uknot_vec.transform(t1 - t0, t0);
+    Some(NurbsSurface::new(BSplineSurface::new_unchecked(
+        (uknot_vec, vknot_vec),
+        control_points,
+    )))
+}
+
+/// The curves are converted over the common part of their parameter ranges.
+impl<C0, C1> ToNurbs<D2> for HomotopySurface<C0, C1>
+where
+    C0: ToNurbs<D1, Range = (f64, f64), Nurbs = NurbsCurve<Vector4>>,
+    C1: ToNurbs<D1, Range = (f64, f64), Nurbs = NurbsCurve<Vector4>>,
+{
+    type Range = ((f64, f64), (f64, f64));
+    type Nurbs = NurbsSurface<Vector4>;
+    #[inline(always)]
+    fn to_nurbs_in(
+        &self,
+        (urange, vrange): ((f64, f64), (f64, f64)),
+    ) -> Option<NurbsSurface<Vector4>> {
+        let curve0 = self.curve0.to_nurbs_in(urange)?;
+        let curve1 = self.curve1.to_nurbs_in(urange)?;
+        ruled_nurbs(curve0, curve1, vrange)
+    }
+    fn to_nurbs(&self) -> Option<NurbsSurface<Vector4>> {
+        let (curve0, curve1) = (self.curve0.to_nurbs()?, self.curve1.to_nurbs()?);
+        let ((t00, t01), (t10, t11)) = (curve0.range_tuple(), curve1.range_tuple());
+        let urange = (f64::max(t00, t10), f64::min(t01, t11));
+        // The original curves are trimmed, since the parameters of NURBS may differ from them.
+        self.to_nurbs_in((urange, (0.0, 1.0)))
+    }
+}
+
 fn bound2opt<T>(x: Bound<T>) -> Option<T> {
     match x {
         Bound::Included(x) => Some(x),
@@ -278,6 +278,45 @@ pub struct TrimmedCurve<C> {
     range: (f64, f64),
 }
 
+/// curve reparametrized by the arc length
+///
+/// The parameter of the curve is proportional to the arc length of the entity curve,
+/// so the points at the equal parameter intervals are placed at the equal spacing.
+///
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+///
+/// let bsp = BSplineCurve::new(
+///     KnotVec::bezier_knot(3),
+///     vec![
+///         Point2::new(0.0, 0.0),
+///         Point2::new(1.0, 2.0),
+///         Point2::new(2.0, -1.0),
+///         Point2::new(4.0, 0.0),
+///     ],
+/// );
+/// let length = bsp.length((0.0, 1.0), TOLERANCE);
+/// let curve = ArcLengthReparametrized::new(bsp.clone(), TOLERANCE);
+/// assert_near!(curve.range_tuple().1, length);
+///
+/// // unit speed
+/// for i in 0..=10 {
+///     let s = length * i as f64 / 10.0;
+///     assert_near!(curve.der(s).magnitude(), 1.0);
+///     assert_near!(curve.subs(s), bsp.subs(curve.curve_parameter(s)));
+///     assert_near!(bsp.length((0.0, curve.curve_parameter(s)), TOLERANCE), s);
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, SelfSameGeometry)]
+pub struct ArcLengthReparametrized<C> {
+    curve: C,
+    // the pairs of the parameters of the entity curve and the new parameters
+    table: Vec<(f64, f64)>,
+    // the derivation of the new parameter by the arc length
+    scalar: f64,
+}
+
 /// homotopy surface connecting two curves.
 ///
 /// # Examples
@@ -346,6 +385,7 @@ pub struct ApproxFilletSurface<S0, S1> {
 }
 
 mod af_surface;
+mod arc_length;
 mod extruded_curve;
 mod homotopy;
 mod intersection_curve;
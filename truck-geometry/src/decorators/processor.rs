@@ -540,6 +540,67 @@ where
     }
 }
 
+impl<E, T> ToNurbs<D1> for Processor<E, T>
+where
+    E: ToNurbs<D1, Range = (f64, f64)> + BoundedCurve,
+    E::Nurbs: Transformed<T> + Invertible + ParameterTransform,
+    T: Copy,
+{
+    type Range = (f64, f64);
+    type Nurbs = E::Nurbs;
+    fn to_nurbs_in(&self, (t0, t1): (f64, f64)) -> Option<E::Nurbs> {
+        let mut nurbs = match self.orientation {
+            true => self.entity.to_nurbs_in((t0, t1))?,
+            false => {
+                let (a, b) = self.entity.range_tuple();
+                let mut nurbs = self.entity.to_nurbs_in((a + b - t1, a + b - t0))?;
+                nurbs.invert();
+                nurbs.parameter_transform(1.0, t0 + t1 - a - b);
+                nurbs
+            }
+        };
+        nurbs.transform_by(self.transform);
+        Some(nurbs)
+    }
+    fn to_nurbs(&self) -> Option<E::Nurbs> {
+        let mut nurbs = self.entity.to_nurbs()?;
+        nurbs.transform_by(self.transform);
+        if !self.orientation {
+            nurbs.invert();
+        }
+        Some(nurbs)
+    }
+}
+
+impl<E, T> ToNurbs<D2> for Processor<E, T>
+where
+    E: ToNurbs<D2, Range = ((f64, f64), (f64, f64))>,
+    E::Nurbs: Transformed<T> + Invertible,
+    T: Copy,
+{
+    type Range = ((f64, f64), (f64, f64));
+    type Nurbs = E::Nurbs;
+    fn to_nurbs_in(&self, (urange, vrange): ((f64, f64), (f64, f64))) -> Option<E::Nurbs> {
+        let mut nurbs = match self.orientation {
+            true => self.entity.to_nurbs_in((urange, vrange))?,
+            false => {
+                let mut nurbs = self.entity.to_nurbs_in((vrange, urange))?;
+                nurbs.invert();
+                nurbs
+            }
+        };
+        nurbs.transform_by(self.transform);
+        Some(nurbs)
+    }
+    fn to_nurbs(&self) -> Option<E::Nurbs> {
+        let mut nurbs = self.entity.to_nurbs()?;
+        nurbs.transform_by(self.transform);
+        if !self.orientation {
+            nurbs.invert();
+        }
+        Some(nurbs)
+    }
+}
 impl<E, T, U> ToSameGeometry<U> for Processor<E, T>
 where
     E: ToSameGeometry<U>,
@@ -481,6 +481,54 @@ impl IncludeCurve<NurbsCurve<Vector4>> for RevolutedCurve<NurbsCurve<Vector4>> {
     }
 }
 
+impl<C> RevolutedCurve<C> {
+    /// Revolves the NURBS curve along the circle arc over `vrange`.
+    pub(crate) fn revolve_nurbs(
+        &self,
+        curve: NurbsCurve<Vector4>,
+        vrange: (f64, f64),
+    ) -> Option<NurbsSurface<Vector4>> {
+        let arc = UnitCircle::<Point2>::new().to_nurbs_in(vrange)?;
+        let (origin, axis) = (self.origin().to_vec(), self.axis());
+        let control_points = curve
+            .control_points()
+            .iter()
+            .map(|v| {
+                let (xyz, w) = (v.truncate(), v.w);
+                let center = origin * w + axis * axis.dot(xyz - origin * w);
+                let x = xyz - center;
+                let y = axis.cross(x);
+                arc.control_points()
+                    .iter()
+                    .map(|a| (center * a.z + x * a.x + y * a.y).extend(w * a.z))
+                    .collect()
+            })
+            .collect();
+        let knot_vecs = (curve.knot_vec().clone(), arc.knot_vec().clone());
+        Some(NurbsSurface::new(BSplineSurface::new_unchecked(
+            knot_vecs,
+            control_points,
+        )))
+    }
+}
+
+impl<C> ToNurbs<D2> for RevolutedCurve<C>
+where C: ToNurbs<D1, Range = (f64, f64), Nurbs = NurbsCurve<Vector4>>
+{
+    type Range = ((f64, f64), (f64, f64));
+    type Nurbs = NurbsSurface<Vector4>;
+    #[inline(always)]
+    fn to_nurbs_in(
+        &self,
+        (urange, vrange): ((f64, f64), (f64, f64)),
+    ) -> Option<NurbsSurface<Vector4>> {
+        self.revolve_nurbs(self.curve.to_nurbs_in(urange)?, vrange)
+    }
+    #[inline(always)]
+    fn to_nurbs(&self) -> Option<NurbsSurface<Vector4>> {
+        self.revolve_nurbs(self.curve.to_nurbs()?, (0.0, 2.0 * PI))
+    }
+}
 impl<C> ParameterDivision2D for RevolutedCurve<C>
 where C: ParametricCurve3D + ParameterDivision1D<Point = Point3>
 {
@@ -73,3 +73,12 @@ impl<C: ParameterDivision1D> ParameterDivision1D for TrimmedCurve<C> {
         self.curve.parameter_division(range, tol)
     }
 }
+
+impl<C: ToNurbs<D1, Range = (f64, f64)>> ToNurbs<D1> for TrimmedCurve<C> {
+    type Range = (f64, f64);
+    type Nurbs = C::Nurbs;
+    #[inline(always)]
+    fn to_nurbs_in(&self, range: (f64, f64)) -> Option<C::Nurbs> { self.curve.to_nurbs_in(range) }
+    #[inline(always)]
+    fn to_nurbs(&self) -> Option<C::Nurbs> { self.curve.to_nurbs_in(self.range) }
+}
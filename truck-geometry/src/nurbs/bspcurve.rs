@@ -2,6 +2,9 @@ use super::*;
 use crate::errors::Error;
 use std::ops::*;
 
+/// the number of the parameter corrections for each knot vector in fitting
+const FITTING_CORRECTIONS: usize = 5;
+
 impl<P> BSplineCurve<P> {
     /// constructor.
     /// # Arguments
@@ -340,6 +343,203 @@ where P: ControlPoint<f64> + MetricSpace<Metric = f64> + HashGen<f64>
     }
 }
 
+impl<P> BSplineCurve<P>
+where
+    P: ControlPoint<f64> + MetricSpace<Metric = f64>,
+    P::Diff: InnerSpace<Scalar = f64>,
+{
+    /// Fits a B-spline curve of `degree` to `points` by the least squares method.
+    ///
+    /// The points are parametrized by the chord lengths, and the parameter range of the result is `[0, 1]`.
+    /// The front and back points are interpolated, and the knots are added
+    /// until the distances between the points and the curve are less than `tol`.
+    /// cf) [`BSplineCurve::fit_with_options`].
+    ///
+    /// Returns `None` if there are less than two points, all points are the same, or `degree` is zero.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let points = (0..=100)
+    ///     .map(|i| {
+    ///         let t = i as f64 / 100.0;
+    ///         Point2::new(t, f64::sin(6.0 * t))
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// let curve = BSplineCurve::fit(&points, 3, 1.0e-3).unwrap();
+    ///
+    /// // fewer control points than the points
+    /// assert!(curve.control_points().len() < points.len() / 2);
+    /// assert_near!(curve.front(), points[0]);
+    /// assert_near!(curve.back(), points[100]);
+    /// points.iter().for_each(|p| {
+    ///     let t = curve.search_nearest_parameter(*p, None, 100).unwrap();
+    ///     assert!(curve.subs(t).distance(*p) < 1.0e-3);
+    /// });
+    /// ```
+    #[inline(always)]
+    pub fn fit(points: &[P], degree: usize, tol: f64) -> Option<Self> {
+        Self::fit_with_options(points, degree, tol, FittingOptions::default())
+    }
+
+    /// Fits a B-spline curve of `degree` to `points` by the least squares method with `options`.
+    ///
+    /// The number of the control points is the least one, found by the geometric growth and bisection,
+    /// such that the distances between the points and the curve are less than `tol`.
+    /// The knots are placed so that every knot span contains some parameters,
+    /// and the parameters are corrected by the projections of the points a few times for each knot vector.
+    /// The derivations in `options` are the ones with respect to the parameter in `[0, 1]`.
+    /// If only the directions are known, the unit tangents multiplied by the total chord length are good choices.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let points = (0..=50)
+    ///     .map(|i| {
+    ///         let t = i as f64 / 50.0;
+    ///         Point2::new(f64::cos(t), f64::sin(t))
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// let options = FittingOptions {
+    ///     parametrization: Parametrization::Centripetal,
+    ///     front_derivation: Some(Vector2::new(0.0, 1.0)),
+    ///     back_derivation: Some(Vector2::new(-f64::sin(1.0), f64::cos(1.0))),
+    /// };
+    /// let curve = BSplineCurve::fit_with_options(&points, 3, 1.0e-4, options).unwrap();
+    ///
+    /// let der = curve.derivation();
+    /// assert_near!(der.front(), Vector2::new(0.0, 1.0));
+    /// assert_near!(der.back(), Vector2::new(-f64::sin(1.0), f64::cos(1.0)));
+    /// ```
+    pub fn fit_with_options(
+        points: &[P],
+        degree: usize,
+        tol: f64,
+        options: FittingOptions<P::Diff>,
+    ) -> Option<Self> {
+        if points.len() < 2 || degree == 0 {
+            return None;
+        }
+        let params = options.parametrization.parameters(points)?;
+        let ders = (options.front_derivation, options.back_derivation);
+        let constraints = ders.0.is_some() as usize + ders.1.is_some() as usize;
+        let min_len = usize::max(degree + 1, constraints + 2);
+        let max_len = points.len() + constraints;
+        if min_len > max_len {
+            return None;
+        }
+        let fit = |len: usize| {
+            let knot_vec = averaging_knot(&params, degree, len);
+            let mut params = params.clone();
+            for _ in 0..FITTING_CORRECTIONS {
+                let curve = Self::least_squares(points, &params, degree, knot_vec.clone(), ders)?;
+                let mut is_fitted = true;
+                points.iter().zip(&mut params).for_each(|(p, t)| {
+                    *t = curve.correct_parameter(*p, *t);
+                    is_fitted &= p.distance(curve.subs(*t)) <= tol;
+                });
+                if is_fitted {
+                    return Some(curve);
+                }
+            }
+            None
+        };
+
+        let (mut failed, mut len) = (min_len - 1, min_len);
+        let mut curve = loop {
+            match fit(len) {
+                Some(curve) => break curve,
+                None if len == max_len => return None,
+                None => {
+                    failed = len;
+                    len = usize::min(len + (len - degree).div_ceil(2), max_len);
+                }
+            }
+        };
+        while failed + 1 < len {
+            let mid = (failed + len) / 2;
+            match fit(mid) {
+                Some(res) => (curve, len) = (res, mid),
+                None => failed = mid,
+            }
+        }
+        Some(curve)
+    }
+
+    /// Moves the parameter `t` toward the foot of the perpendicular from `point` by Newton's method.
+    fn correct_parameter(&self, point: P, mut t: f64) -> f64 {
+        for _ in 0..3 {
+            let (diff, der, der2) = (self.subs(t) - point, self.der(t), self.der2(t));
+            let denom = der.dot(der) + diff.dot(der2);
+            if denom.so_small() {
+                break;
+            }
+            t = f64::clamp(t - diff.dot(der) / denom, 0.0, 1.0);
+        }
+        t
+    }
+
+    /// The least squares fitting with `knot_vec`, interpolating the front and back points.
+    pub(super) fn least_squares(
+        points: &[P],
+        params: &[f64],
+        degree: usize,
+        knot_vec: KnotVec,
+        ders: (Option<P::Diff>, Option<P::Diff>),
+    ) -> Option<Self> {
+        let len = knot_vec.len() - degree - 1;
+        let (front, back) = (points[0], points[points.len() - 1]);
+        let mut control_points = vec![front; len];
+        control_points[len - 1] = back;
+        let mut range = 1..len - 1;
+        if let Some(der) = ders.0 {
+            control_points[1] = front + der * (knot_vec[degree + 1] / degree as f64);
+            range.start += 1;
+        }
+        if let Some(der) = ders.1 {
+            control_points[len - 2] = back - der * ((1.0 - knot_vec[len - 1]) / degree as f64);
+            range.end -= 1;
+        }
+        if !range.is_empty() {
+            let bases = params[1..params.len() - 1]
+                .iter()
+                .map(|t| knot_vec.try_bspline_basis_functions(degree, 0, *t))
+                .collect::<Result<Vec<_>>>()
+                .ok()?;
+            let matrix = range
+                .clone()
+                .map(|i| {
+                    range
+                        .clone()
+                        .map(|j| bases.iter().map(|basis| basis[i] * basis[j]).sum::<f64>())
+                        .collect::<Vec<_>>()
+                })
+                .collect::<Vec<_>>();
+            for k in 0..P::DIM {
+                let residuals = bases
+                    .iter()
+                    .zip(&points[1..points.len() - 1])
+                    .map(|(basis, p)| {
+                        let fixed = (0..len)
+                            .filter(|i| !range.contains(i))
+                            .map(|i| basis[i] * control_points[i][k])
+                            .sum::<f64>();
+                        p[k] - fixed
+                    })
+                    .collect::<Vec<_>>();
+                let mut rows = matrix.clone();
+                rows.iter_mut().zip(range.clone()).for_each(|(row, i)| {
+                    let rhs = bases.iter().zip(&residuals).map(|(basis, r)| basis[i] * r);
+                    row.push(rhs.sum::<f64>());
+                });
+                gaussian_elimination::gaussian_elimination(&mut rows)?
+                    .into_iter()
+                    .zip(range.clone())
+                    .for_each(|(res, i)| control_points[i][k] = res);
+            }
+        }
+        Self::try_new(knot_vec, control_points).ok()
+    }
+}
+
 impl<V: Homogeneous> BSplineCurve<V> {
     /// lift up control points to homogeneous coordinate.
     pub fn lift_up(curve: BSplineCurve<V::Point>) -> Self {
@@ -896,6 +1096,220 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineCurve<P> {
     }
 }
 
+impl<P> BSplineCurve<P>
+where P: ControlPoint<f64> + Tolerance + MetricSpace<Metric = f64>
+{
+    /// Reduces the degree by one if the reduced curve is within the distance `tol` from `self`.
+    /// Returns the achieved error, or returns `None` and does not change `self` if the degree
+    /// cannot be reduced within `tol` or the degree is less than two.
+    ///
+    /// Each Bezier segment is reduced independently, and then the knots are removed within `tol`.
+    /// The error is an upper bound of the distance between the points at the same parameters.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let line = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(1),
+    ///     vec![Point2::new(0.0, 0.0), Point2::new(3.0, 1.0)],
+    /// );
+    /// let mut bspcurve = line.clone();
+    /// bspcurve.elevate_degree().elevate_degree().add_knot(0.3).add_knot(0.6);
+    /// assert_eq!(bspcurve.degree(), 3);
+    ///
+    /// let error = bspcurve.reduce_degree(1.0e-6).unwrap();
+    /// assert!(error < 1.0e-6);
+    /// assert_eq!(bspcurve.degree(), 2);
+    /// // The redundant knots are also removed.
+    /// assert_eq!(bspcurve.knot_vec(), &KnotVec::bezier_knot(2));
+    /// assert!(bspcurve.near_as_curve(&line));
+    ///
+    /// // The quadratic curve cannot be a line.
+    /// let mut parabola = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(2),
+    ///     vec![Point2::new(0.0, 0.0), Point2::new(0.5, 1.0), Point2::new(1.0, 0.0)],
+    /// );
+    /// assert!(parabola.reduce_degree(0.1).is_none());
+    /// assert_eq!(parabola.degree(), 2);
+    /// ```
+    pub fn reduce_degree(&mut self, tol: f64) -> Option<f64> {
+        let curve = self.degree_reduced()?;
+        if self.deviation_bound(&curve) > tol {
+            return None;
+        }
+        let mut curves = vec![curve];
+        let error = remove_knots_simultaneously(&mut curves, std::slice::from_ref(self), tol);
+        *self = curves.pop().unwrap();
+        Some(error)
+    }
+
+    /// Removes the knots as long as the curve is within the distance `tol` from the original one.
+    /// Returns the achieved error, an upper bound of the distance between the points at the same parameters.
+    ///
+    /// Different from [`BSplineCurve::optimize`], the shape of curve may be changed within `tol`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let mut bspcurve = BSplineCurve::new(
+    ///     KnotVec::bezier_knot(3),
+    ///     vec![
+    ///         Point2::new(0.0, 0.0),
+    ///         Point2::new(1.0, 2.0),
+    ///         Point2::new(2.0, -1.0),
+    ///         Point2::new(3.0, 0.0),
+    ///     ],
+    /// );
+    /// bspcurve.add_knot(0.25).add_knot(0.5).add_knot(0.75);
+    /// // a small perturbation
+    /// *bspcurve.control_point_mut(3) += Vector2::new(0.0, 0.01);
+    /// let org_curve = bspcurve.clone();
+    ///
+    /// // nothing is removed within the small tolerance.
+    /// assert_eq!(bspcurve.remove_knots_within(1.0e-6), 0.0);
+    /// assert_eq!(bspcurve.knot_vec().len(), 11);
+    ///
+    /// let error = bspcurve.remove_knots_within(0.1);
+    /// assert!(0.0 < error && error <= 0.1);
+    /// assert_eq!(bspcurve.knot_vec(), &KnotVec::bezier_knot(3));
+    /// for i in 0..=100 {
+    ///     let t = i as f64 / 100.0;
+    ///     assert!(bspcurve.subs(t).distance(org_curve.subs(t)) <= error + TOLERANCE);
+    /// }
+    /// ```
+    pub fn remove_knots_within(&mut self, tol: f64) -> f64 {
+        let mut curves = vec![self.clone()];
+        let error = remove_knots_simultaneously(&mut curves, std::slice::from_ref(self), tol);
+        *self = curves.pop().unwrap();
+        error
+    }
+
+    /// An upper bound of the distance between `self` and `other` at the same parameters,
+    /// the maximum distance between the control points on the common refined knot vector.
+    pub(super) fn deviation_bound(&self, other: &Self) -> f64 {
+        let (mut curve0, mut curve1) = (self.clone(), other.clone());
+        curve0.syncro_degree(&mut curve1);
+        curve0.syncro_knots(&mut curve1);
+        let (knots, _) = curve0.knot_vec.to_single_multi();
+        knots.windows(2).for_each(|span| {
+            let t = (span[0] + span[1]) / 2.0;
+            curve0.add_knot(t);
+            curve1.add_knot(t);
+        });
+        let points = curve0.control_points.iter().zip(&curve1.control_points);
+        points.fold(0.0, |max, (p, q)| f64::max(max, p.distance(*q)))
+    }
+
+    /// Returns the curve whose knot `self.knot(idx)` is removed once, which is not the same as `self` in general.
+    /// `idx` must be the last index of the interior knot.
+    fn knot_removed(&self, idx: usize) -> Option<Self> {
+        let (p, n) = (self.degree(), self.control_points.len());
+        let knots = &self.knot_vec;
+        if idx >= n || knots[idx].near(&knots[idx + 1]) {
+            return None;
+        }
+        let s = knots.multiplicity(idx);
+        if s > p + 1 || idx < p + s {
+            return None;
+        }
+        let points = &self.control_points;
+        let mut knot_vec = self.knot_vec.clone();
+        knot_vec.remove(idx);
+        if s == p + 1 {
+            // The curve is separated at the knot, and the two end points are merged.
+            let (p0, p1) = (points[idx - p - 1], points[idx - p]);
+            let mut new_points = points[..idx - p - 1].to_vec();
+            new_points.push(p0 + (p1 - p0) / 2.0);
+            new_points.extend_from_slice(&points[idx - p + 1..]);
+            return Some(BSplineCurve::new_unchecked(knot_vec, new_points));
+        }
+        let (u, first, last) = (knots[idx], idx - p, idx - s);
+        let alpha = |i: usize| (u - knots[i]) / (knots[i + p + 1] - knots[i]);
+        let mut new_points = points[..first].to_vec();
+        new_points.resize(last, P::origin());
+        new_points.extend_from_slice(&points[last + 1..]);
+        let m = last - first;
+        // Solves the relations of knot insertion from both sides.
+        (first..first + m.div_ceil(2)).for_each(|i| {
+            let q = new_points[i - 1];
+            new_points[i] = q + (points[i] - q) / alpha(i);
+        });
+        (last - m / 2 + 1..=last).rev().for_each(|j| {
+            let q = new_points[j];
+            new_points[j - 1] = q + (points[j] - q) / (1.0 - alpha(j));
+        });
+        Some(BSplineCurve::new_unchecked(knot_vec, new_points))
+    }
+
+    /// Returns the curve whose Bezier segments are reduced their degrees, which is not the same as `self` in general.
+    pub(super) fn degree_reduced(&self) -> Option<Self> {
+        if self.degree() < 2 {
+            return None;
+        }
+        let mut collector = CurveCollector::Singleton;
+        self.bezier_decomposition().iter().for_each(|bezier| {
+            collector.concat(&bezier.bezier_degree_reduced());
+        });
+        Some(collector.unwrap())
+    }
+
+    /// Reduces the degree of the Bezier curve by solving the relations of degree elevation from both sides.
+    fn bezier_degree_reduced(&self) -> Self {
+        let p = self.degree();
+        let points = &self.control_points;
+        let alpha = |i: usize| i as f64 / p as f64;
+        let r = (p - 1) / 2;
+        let mut new_points = vec![points[0]; p];
+        new_points[p - 1] = points[p];
+        (1..=r).for_each(|i| {
+            let q = new_points[i - 1];
+            new_points[i] = q + (points[i] - q) / (1.0 - alpha(i));
+        });
+        (r + 1..p - 1).rev().for_each(|i| {
+            let q = new_points[i + 1];
+            new_points[i] = q + (points[i + 1] - q) / alpha(i + 1);
+        });
+        if p % 2 == 1 {
+            let q = new_points[r + 1];
+            let right = q + (points[r + 1] - q) / alpha(r + 1);
+            let left = new_points[r];
+            new_points[r] = left + (right - left) / 2.0;
+        }
+        let (t0, t1) = (self.knot_vec[0], self.knot_vec[self.knot_vec.len() - 1]);
+        let mut knot_vec = KnotVec::bezier_knot(p - 1);
+        knot_vec.transform(t1 - t0, t0);
+        BSplineCurve::new_unchecked(knot_vec, new_points)
+    }
+}
+
+/// Removes the common knots of `curves` as long as each curve is within the distance `tol` from
+/// the corresponding one in `originals`. Returns the maximum distance.
+pub(super) fn remove_knots_simultaneously<P>(
+    curves: &mut Vec<BSplineCurve<P>>,
+    originals: &[BSplineCurve<P>],
+    tol: f64,
+) -> f64
+where
+    P: ControlPoint<f64> + Tolerance + MetricSpace<Metric = f64>,
+{
+    let deviation = |curves: &[BSplineCurve<P>]| {
+        originals.iter().zip(curves).fold(0.0, |max, (org, curve)| {
+            f64::max(max, org.deviation_bound(curve))
+        })
+    };
+    loop {
+        let removed = (0..curves[0].knot_vec.len()).rev().find_map(|idx| {
+            let removed = curves
+                .iter()
+                .map(|curve| curve.knot_removed(idx))
+                .collect::<Option<Vec<_>>>()?;
+            Some(removed).filter(|removed| deviation(removed) <= tol)
+        });
+        match removed {
+            Some(removed) => *curves = removed,
+            None => return deviation(curves),
+        }
+    }
+}
+
 impl<P: ControlPoint<f64>> ParameterTransform for BSplineCurve<P> {
     #[inline(always)]
     fn parameter_transform(&mut self, scalar: f64, r#move: f64) -> &mut Self {
@@ -1330,3 +1744,77 @@ fn cubic_bezier_interpolation_test() {
     assert_near!(der.front(), der0);
     assert_near!(der.back(), der1);
 }
+
+#[test]
+fn fit_test() {
+    let points = (0..=20)
+        .map(|i| {
+            let t = i as f64 / 20.0;
+            Point3::new(t * t, 2.0 * t * t, 3.0 * t * t)
+        })
+        .collect::<Vec<_>>();
+    // The segment is fitted by a Bézier curve.
+    let curve = BSplineCurve::fit(&points, 3, 1.0e-6).unwrap();
+    assert_eq!(curve.control_points().len(), 4);
+
+    // The interpolation is the last resort.
+    let zigzag = (0..10)
+        .map(|i| Point2::new(i as f64, (i % 2) as f64))
+        .collect::<Vec<_>>();
+    let curve = BSplineCurve::fit(&zigzag, 2, 1.0e-6).unwrap();
+    assert_eq!(curve.control_points().len(), zigzag.len());
+
+    assert!(BSplineCurve::fit(&points[..1], 3, 1.0e-3).is_none());
+    assert!(BSplineCurve::fit(&[Point2::new(1.0, 2.0); 4], 3, 1.0e-3).is_none());
+    assert!(BSplineCurve::fit(&points, 0, 1.0e-3).is_none());
+}
+
+#[test]
+fn reduce_degree_test() {
+    let org_curve = BSplineCurve::new(
+        KnotVec::uniform_knot(2, 3),
+        vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 2.0, 0.0),
+            Point3::new(2.0, -1.0, 1.0),
+            Point3::new(3.0, 0.0, 2.0),
+            Point3::new(4.0, 1.0, 0.0),
+        ],
+    );
+    let mut bspcurve = org_curve.clone();
+    bspcurve.elevate_degree().elevate_degree();
+    assert_eq!(bspcurve.degree(), 4);
+    assert!(bspcurve.reduce_degree(1.0e-6).unwrap() < 1.0e-6);
+    assert!(bspcurve.reduce_degree(1.0e-6).unwrap() < 1.0e-6);
+    assert_eq!(bspcurve.degree(), 2);
+    assert_eq!(bspcurve.knot_vec(), org_curve.knot_vec());
+    assert!(bspcurve.near_as_curve(&org_curve));
+    assert!(bspcurve.reduce_degree(1.0e-6).is_none());
+
+    let mut line = bspcurve.clone();
+    let error = line.reduce_degree(10.0).unwrap();
+    assert_eq!(line.degree(), 1);
+    (0..=100).for_each(|i| {
+        let t = i as f64 / 100.0;
+        assert!(line.subs(t).distance(org_curve.subs(t)) <= error + TOLERANCE);
+    });
+}
+
+/// Makes `curves` have the same degree and the same normalized knot vector.
+pub(super) fn syncro_curves<P: ControlPoint<f64> + Tolerance>(curves: &mut [BSplineCurve<P>]) {
+    let degree = curves.iter().map(|curve| curve.degree()).max().unwrap_or(0);
+    curves.iter_mut().for_each(|curve| {
+        (curve.degree()..degree).for_each(|_| {
+            curve.elevate_degree();
+        });
+    });
+    if let Some((first, others)) = curves.split_first_mut() {
+        first.knot_normalize();
+        // the first curve gets all knots in the first loop, and gives them in the second one.
+        (0..2).for_each(|_| {
+            others
+                .iter_mut()
+                .for_each(|curve| first.syncro_knots(curve))
+        });
+    }
+}
@@ -1,4 +1,8 @@
-use super::*;
+use super::{
+    band_matrix::SymmetricBandMatrix,
+    bspcurve::{remove_knots_simultaneously, syncro_curves},
+    *,
+};
 use crate::errors::Error;
 use algo::surface::{SsnpVector, SspVector};
 use std::iter::FusedIterator;
@@ -513,6 +517,545 @@ impl<P: ControlPoint<f64>> BSplineSurface<P> {
     }
 }
 
+/// the nodes and weights of Gauss-Legendre quadrature on `[-1, 1]`
+const GAUSS_LEGENDRE3: [(f64, f64); 3] = [
+    (-0.774_596_669_241_483_4, 5.0 / 9.0),
+    (0.0, 8.0 / 9.0),
+    (0.774_596_669_241_483_4, 5.0 / 9.0),
+];
+
+/// The weights and the basis functions with the derivations up to second order
+/// at the quadrature points in every knot span.
+fn quadrature_bases(knot_vec: &KnotVec, degree: usize) -> Option<Vec<(f64, [Vec<f64>; 3])>> {
+    let mut res = Vec::new();
+    for span in knot_vec.windows(2) {
+        let width = span[1] - span[0];
+        if width.so_small() {
+            continue;
+        }
+        for (node, weight) in GAUSS_LEGENDRE3 {
+            let t = span[0] + width * (node + 1.0) / 2.0;
+            let bases = [0, 1, 2].map(|der| knot_vec.try_bspline_basis_functions(degree, der, t));
+            let [b0, b1, b2] = bases;
+            res.push((weight * width / 2.0, [b0.ok()?, b1.ok()?, b2.ok()?]));
+        }
+    }
+    Some(res)
+}
+
+impl<P> BSplineSurface<P>
+where P: ControlPoint<f64> + MetricSpace<Metric = f64>
+{
+    /// Fits a B-spline surface of `degrees` to the grid of `points` by the least squares method.
+    ///
+    /// The point `points[i][j]` corresponds to the `i`th parameter in the u-direction and the `j`th one in the v-direction.
+    /// The parameters are averages of the ones of the rows and columns, and the parameter range of the result is `[0, 1] x [0, 1]`.
+    /// The knots are added until the maximum distance between the points and the surface at their parameters is less than `tol`.
+    /// The thin-plate energy is added to the least squares if `options.smoothing` is positive.
+    ///
+    /// Returns `None` if the grid is irregular, too small for `degrees` or the tolerance cannot be attained.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let points = (0..=20)
+    ///     .map(|i| {
+    ///         (0..=20)
+    ///             .map(|j| {
+    ///                 let (x, y) = (i as f64 / 20.0, j as f64 / 20.0);
+    ///                 Point3::new(x, y, f64::sin(x) * f64::cos(y))
+    ///             })
+    ///             .collect()
+    ///     })
+    ///     .collect::<Vec<Vec<_>>>();
+    /// let (surface, deviation) =
+    ///     BSplineSurface::fit_grid(&points, (3, 3), 1.0e-3, Default::default()).unwrap();
+    /// assert!(deviation.max < 1.0e-3);
+    /// assert!(deviation.rms <= deviation.max);
+    /// assert!(surface.control_points().len() < 21);
+    /// assert!(surface.subs(1.0, 1.0).distance(points[20][20]) < 1.0e-3);
+    /// ```
+    pub fn fit_grid(
+        points: &[Vec<P>],
+        degrees: (usize, usize),
+        tol: f64,
+        options: SurfaceFittingOptions,
+    ) -> Option<(Self, FittingDeviation)> {
+        let (rows, columns) = (points.len(), points.first()?.len());
+        if degrees.0 == 0 || degrees.1 == 0 || points.iter().any(|row| row.len() != columns) {
+            return None;
+        }
+        let (min_lens, max_lens) = ((degrees.0 + 1, degrees.1 + 1), (rows, columns));
+        if min_lens.0 > max_lens.0 || min_lens.1 > max_lens.1 {
+            return None;
+        }
+        let parametrization = options.parametrization;
+        let uparams = average_parameters((0..columns).map(|j| {
+            let column = points.iter().map(|row| row[j]).collect::<Vec<_>>();
+            parametrization.parameters(&column)
+        }))?;
+        let vparams = average_parameters(points.iter().map(|row| parametrization.parameters(row)))?;
+        let parameter_points = points
+            .iter()
+            .zip(&uparams)
+            .flat_map(|(row, u)| row.iter().zip(&vparams).map(move |(p, v)| ((*u, *v), *p)))
+            .collect::<Vec<_>>();
+
+        let mut lens = min_lens;
+        loop {
+            let knot_vecs = (
+                averaging_knot(&uparams, degrees.0, lens.0),
+                averaging_knot(&vparams, degrees.1, lens.1),
+            );
+            let res =
+                Self::least_squares(&parameter_points, knot_vecs, degrees, options.smoothing)?;
+            if res.1.max <= tol {
+                return Some(res);
+            } else if lens == max_lens {
+                return None;
+            }
+            let grow = |len: usize, degree: usize, max: usize| {
+                usize::min(len + (len - degree).div_ceil(2), max)
+            };
+            lens = (
+                grow(lens.0, degrees.0, max_lens.0),
+                grow(lens.1, degrees.1, max_lens.1),
+            );
+        }
+    }
+
+    /// Fits a B-spline surface of `degrees` to `parameter_points` by the least squares method.
+    ///
+    /// The knot vectors are uniform on the bounding box of the parameters,
+    /// and the knots are added until the maximum distance between the points and the surface
+    /// at their parameters is less than `tol`. The parametrization of `options` is not used.
+    /// Since the control points far from the parameters are not determined only by the points,
+    /// a positive `options.smoothing` is recommended for the scattered parameters.
+    ///
+    /// Returns `None` if the tolerance cannot be attained before the number of the control points
+    /// exceeds the number of the points.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let parameter_points = (0..200)
+    ///     .map(|i| {
+    ///         // quasi-random parameters
+    ///         let (u, v) = ((i as f64 * 0.618034) % 1.0, (i as f64 * 0.754878) % 1.0);
+    ///         ((u, v), Point3::new(u, v, u * u - v * v))
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// let options = SurfaceFittingOptions {
+    ///     smoothing: 1.0e-8,
+    ///     ..Default::default()
+    /// };
+    /// let (surface, deviation) =
+    ///     BSplineSurface::fit_parameter_points(&parameter_points, (2, 2), 1.0e-3, options).unwrap();
+    /// assert!(deviation.max < 1.0e-3);
+    /// parameter_points.iter().for_each(|((u, v), p)| {
+    ///     assert!(surface.subs(*u, *v).distance(*p) < 1.0e-3);
+    /// });
+    /// ```
+    pub fn fit_parameter_points(
+        parameter_points: &[((f64, f64), P)],
+        degrees: (usize, usize),
+        tol: f64,
+        options: SurfaceFittingOptions,
+    ) -> Option<(Self, FittingDeviation)> {
+        if degrees.0 == 0 || degrees.1 == 0 {
+            return None;
+        }
+        let range = parameter_points.iter().fold(
+            (
+                (f64::INFINITY, f64::NEG_INFINITY),
+                (f64::INFINITY, f64::NEG_INFINITY),
+            ),
+            |(urange, vrange), ((u, v), _)| {
+                (
+                    (f64::min(urange.0, *u), f64::max(urange.1, *u)),
+                    (f64::min(vrange.0, *v), f64::max(vrange.1, *v)),
+                )
+            },
+        );
+        let knot_vec = |degree: usize, len: usize, (t0, t1): (f64, f64)| {
+            let mut knot_vec = KnotVec::uniform_knot(degree, len - degree);
+            knot_vec.transform(t1 - t0, t0);
+            knot_vec
+        };
+        let mut lens = (degrees.0 + 1, degrees.1 + 1);
+        while lens.0 * lens.1 <= parameter_points.len() {
+            let knot_vecs = (
+                knot_vec(degrees.0, lens.0, range.0),
+                knot_vec(degrees.1, lens.1, range.1),
+            );
+            // The system may be singular if some control points are not determined by the points.
+            let res = Self::least_squares(parameter_points, knot_vecs, degrees, options.smoothing);
+            if let Some(res) = res.filter(|res| res.1.max <= tol) {
+                return Some(res);
+            }
+            lens = (
+                lens.0 + (lens.0 - degrees.0).div_ceil(2),
+                lens.1 + (lens.1 - degrees.1).div_ceil(2),
+            );
+        }
+        None
+    }
+
+    /// The least squares fitting with `knot_vecs` and the thin-plate energy weighted by `smoothing`.
+    fn least_squares(
+        parameter_points: &[((f64, f64), P)],
+        knot_vecs: (KnotVec, KnotVec),
+        degrees: (usize, usize),
+        smoothing: f64,
+    ) -> Option<(Self, FittingDeviation)> {
+        let lens = (
+            knot_vecs.0.len() - degrees.0 - 1,
+            knot_vecs.1.len() - degrees.1 - 1,
+        );
+        let len = lens.0 * lens.1;
+        // The matrix is banded, whose bandwidth is smaller if the longer direction is the outer index.
+        let (bandwidth0, bandwidth1) = (
+            degrees.0 * lens.1 + degrees.1,
+            degrees.1 * lens.0 + degrees.0,
+        );
+        let index = |i: usize, j: usize| match bandwidth0 <= bandwidth1 {
+            true => i * lens.1 + j,
+            false => j * lens.0 + i,
+        };
+        let nonzeros = |ubasis: &[f64], vbasis: &[f64]| {
+            let mut res = Vec::new();
+            ubasis
+                .iter()
+                .enumerate()
+                .filter(|(_, x)| **x != 0.0)
+                .for_each(|(i, x)| {
+                    vbasis
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, y)| **y != 0.0)
+                        .for_each(|(j, y)| {
+                            res.push(((i, j), x * y));
+                        })
+                });
+            res
+        };
+        let bases = parameter_points
+            .iter()
+            .map(|((u, v), _)| {
+                let ubasis = knot_vecs
+                    .0
+                    .try_bspline_basis_functions(degrees.0, 0, *u)
+                    .ok()?;
+                let vbasis = knot_vecs
+                    .1
+                    .try_bspline_basis_functions(degrees.1, 0, *v)
+                    .ok()?;
+                Some(nonzeros(&ubasis, &vbasis))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        let mut matrix = SymmetricBandMatrix::new(len, usize::min(bandwidth0, bandwidth1));
+        let mut add_products = |basis: &[((usize, usize), f64)], weight: f64| {
+            basis.iter().for_each(|((i0, j0), x)| {
+                basis.iter().for_each(|((i1, j1), y)| {
+                    matrix.add(index(*i0, *j0), index(*i1, *j1), weight * x * y);
+                })
+            })
+        };
+        bases.iter().for_each(|basis| add_products(basis, 1.0));
+        if smoothing > 0.0 {
+            let uquad = quadrature_bases(&knot_vecs.0, degrees.0)?;
+            let vquad = quadrature_bases(&knot_vecs.1, degrees.1)?;
+            uquad.iter().for_each(|(uweight, [u0, u1, u2])| {
+                vquad.iter().for_each(|(vweight, [v0, v1, v2])| {
+                    let weight = smoothing * uweight * vweight;
+                    let (uu, uv, vv) = (nonzeros(u2, v0), nonzeros(u1, v1), nonzeros(u0, v2));
+                    add_products(&uu, weight);
+                    add_products(&uv, 2.0 * weight);
+                    add_products(&vv, weight);
+                })
+            });
+        }
+        let factor = matrix.cholesky()?;
+
+        let mut control_points = vec![vec![parameter_points.first()?.1; lens.1]; lens.0];
+        for k in 0..P::DIM {
+            let mut rhs = vec![0.0; len];
+            bases
+                .iter()
+                .zip(parameter_points)
+                .for_each(|(basis, (_, p))| {
+                    basis
+                        .iter()
+                        .for_each(|((i, j), x)| rhs[index(*i, *j)] += x * p[k]);
+                });
+            let res = factor.solve(rhs);
+            control_points.iter_mut().enumerate().for_each(|(i, row)| {
+                row.iter_mut()
+                    .enumerate()
+                    .for_each(|(j, p)| p[k] = res[index(i, j)]);
+            });
+        }
+        let surface = Self::try_new(knot_vecs, control_points).ok()?;
+
+        let (max, sum2) = parameter_points
+            .iter()
+            .fold((0.0, 0.0), |(max, sum2), ((u, v), p)| {
+                let dist = surface.subs(*u, *v).distance(*p);
+                (f64::max(max, dist), sum2 + dist * dist)
+            });
+        let rms = f64::sqrt(sum2 / parameter_points.len() as f64);
+        Some((surface, FittingDeviation { max, rms }))
+    }
+}
+
+impl BSplineSurface<Point3> {
+    /// Fits a B-spline surface of `degrees` to the unorganized `points` by the least squares method.
+    ///
+    /// The points are parametrized by the projection to the plane spanned by the two principal axes,
+    /// so the point cloud should be a graph over the plane. See [`BSplineSurface::fit_parameter_points`]
+    /// for the fitting and the returned value.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let points = (0..300)
+    ///     .map(|i| {
+    ///         let (x, y) = ((i as f64 * 0.618034) % 1.0, (i as f64 * 0.754878) % 1.0);
+    ///         Point3::new(x + y, x - y, 0.1 * x * y)
+    ///     })
+    ///     .collect::<Vec<_>>();
+    /// let options = SurfaceFittingOptions {
+    ///     smoothing: 1.0e-8,
+    ///     ..Default::default()
+    /// };
+    /// let (surface, deviation) = BSplineSurface::fit_scattered(&points, (2, 2), 1.0e-3, options).unwrap();
+    /// assert!(deviation.max < 1.0e-3);
+    /// points.iter().for_each(|p| {
+    ///     let (u, v) = surface.search_nearest_parameter(*p, None, 100).unwrap();
+    ///     assert!(surface.subs(u, v).distance(*p) < 1.0e-3);
+    /// });
+    /// ```
+    pub fn fit_scattered(
+        points: &[Point3],
+        degrees: (usize, usize),
+        tol: f64,
+        options: SurfaceFittingOptions,
+    ) -> Option<(Self, FittingDeviation)> {
+        let center = Point3::centroid(points);
+        let covariance = points.iter().fold(Matrix3::zero(), |sum, p| {
+            let d = p - center;
+            sum + Matrix3::from_cols(d * d.x, d * d.y, d * d.z)
+        });
+        let dominant_axis = |matrix: Matrix3| {
+            let cols = [matrix.x, matrix.y, matrix.z];
+            let mut axis = cols
+                .into_iter()
+                .max_by(|x, y| x.magnitude2().total_cmp(&y.magnitude2()))?;
+            for _ in 0..100 {
+                axis = matrix * axis;
+                if axis.so_small() {
+                    return None;
+                }
+                axis = axis.normalize();
+            }
+            Some(axis)
+        };
+        let uaxis = dominant_axis(covariance)?;
+        let eigen = uaxis.dot(covariance * uaxis);
+        let deflated = covariance
+            - Matrix3::from_cols(uaxis * uaxis.x, uaxis * uaxis.y, uaxis * uaxis.z) * eigen;
+        let vaxis = dominant_axis(deflated)?;
+        let vaxis = (vaxis - uaxis * uaxis.dot(vaxis)).normalize();
+        let parameter_points = points
+            .iter()
+            .map(|p| ((uaxis.dot(p - center), vaxis.dot(p - center)), *p))
+            .collect::<Vec<_>>();
+        Self::fit_parameter_points(&parameter_points, degrees, tol, options)
+    }
+
+    /// Creates the Gordon surface interpolating the network of two families of curves.
+    ///
+    /// The knot vectors of the curves are normalized. `u_curves` are the curves `S(u, v_j)`
+    /// and `v_curves` are the curves `S(u_i, v)`, where `S` is the resulting surface
+    /// with the parameter range `[0, 1] x [0, 1]`. Each curve of a family has to cross each curve
+    /// of the other family just once, in the same order along all the curves of the family.
+    /// The common parameter `u_i` is the average of the parameters of `u_curves` at the intersections
+    /// with `v_curves[i]`, and each curve is reparametrized piecewise linearly so that it meets
+    /// the other family at the common parameters. `v_j` is determined in the same way.
+    /// The curves may be given in any order, they are sorted by the parameters `u_i` and `v_j`.
+    ///
+    /// The surface is the sum of the surfaces lofted through the two families,
+    /// minus the tensor product surface interpolating the intersection points.
+    /// The lofting is the interpolation by cubic B-splines, or by ones of lower degree if
+    /// a family has less than four curves.
+    ///
+    /// Returns `None` if a family has less than two curves, or the curves do not form
+    /// a network within `tol`.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let surface = BSplineSurface::new(
+    ///     (KnotVec::bezier_knot(3), KnotVec::bezier_knot(2)),
+    ///     (0..4)
+    ///         .map(|i| {
+    ///             (0..3)
+    ///                 .map(|j| Point3::new(i as f64, j as f64, ((i + j) % 2) as f64))
+    ///                 .collect()
+    ///         })
+    ///         .collect(),
+    /// );
+    /// // the iso-parametric curves of the surface
+    /// let u_curves = [0.0, 0.4, 1.0]
+    ///     .map(|v| {
+    ///         let points = (0..=3).map(|i| i as f64 / 3.0).map(|u| (u, surface.subs(u, v)));
+    ///         BSplineCurve::interpole(KnotVec::bezier_knot(3), points.collect::<Vec<_>>())
+    ///     })
+    ///     .to_vec();
+    /// let v_curves = [0.0, 0.3, 0.7, 1.0]
+    ///     .map(|u| {
+    ///         let points = (0..=2).map(|j| j as f64 / 2.0).map(|v| (v, surface.subs(u, v)));
+    ///         BSplineCurve::interpole(KnotVec::bezier_knot(2), points.collect::<Vec<_>>())
+    ///     })
+    ///     .to_vec();
+    /// let gordon = BSplineSurface::gordon(u_curves, v_curves, TOLERANCE).unwrap();
+    /// // The surface is reconstructed since it is polynomial of low degrees.
+    /// (0..=10).for_each(|i| {
+    ///     (0..=10).for_each(|j| {
+    ///         let (u, v) = (i as f64 / 10.0, j as f64 / 10.0);
+    ///         assert_near!(gordon.subs(u, v), surface.subs(u, v));
+    ///     })
+    /// });
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// `tol` must be greater than or equal to `TOLERANCE`.
+    pub fn gordon(
+        mut u_curves: Vec<BSplineCurve<Point3>>,
+        mut v_curves: Vec<BSplineCurve<Point3>>,
+        tol: f64,
+    ) -> Option<Self> {
+        nonpositive_tolerance!(tol);
+        let (n, m) = (v_curves.len(), u_curves.len());
+        if n < 2 || m < 2 {
+            return None;
+        }
+        u_curves.iter_mut().chain(&mut v_curves).for_each(|curve| {
+            curve.knot_normalize();
+        });
+        // intersections[j][i]: the parameters of the intersection of `u_curves[j]` and `v_curves[i]`
+        let intersections = u_curves
+            .iter()
+            .map(|u_curve| {
+                v_curves
+                    .iter()
+                    .map(
+                        |v_curve| match intersect_curves(u_curve, v_curve, tol).as_slice() {
+                            [x] => Some(*x),
+                            _ => None,
+                        },
+                    )
+                    .collect::<Option<Vec<_>>>()
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let u_params = (0..n)
+            .map(|i| intersections.iter().map(|row| row[i].0).sum::<f64>() / m as f64)
+            .collect::<Vec<_>>();
+        let v_params = intersections
+            .iter()
+            .map(|row| row.iter().map(|x| x.1).sum::<f64>() / n as f64)
+            .collect::<Vec<_>>();
+        let sorted_indices = |params: &[f64]| {
+            let mut indices = (0..params.len()).collect::<Vec<_>>();
+            indices.sort_by(|i, j| params[*i].total_cmp(&params[*j]));
+            indices
+        };
+        let (uindices, vindices) = (sorted_indices(&u_params), sorted_indices(&v_params));
+        let u_params = uindices.iter().map(|&i| u_params[i]).collect::<Vec<_>>();
+        let v_params = vindices.iter().map(|&j| v_params[j]).collect::<Vec<_>>();
+        // points[i][j]: the intersection of `u_curves[j]` and `v_curves[i]` after sorting
+        let points = uindices
+            .iter()
+            .map(|&i| {
+                vindices
+                    .iter()
+                    .map(|&j| {
+                        let (u, v) = intersections[j][i];
+                        u_curves[j].subs(u).midpoint(v_curves[i].subs(v))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        // The curves are reparametrized so that they meet at the common parameters.
+        let mut u_curves = vindices
+            .iter()
+            .map(|&j| {
+                let params = uindices.iter().map(|&i| intersections[j][i].0);
+                linear_reparametrization(&u_curves[j], params.collect(), &u_params)
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let mut v_curves = uindices
+            .iter()
+            .map(|&i| {
+                let params = vindices.iter().map(|&j| intersections[j][i].1);
+                linear_reparametrization(&v_curves[i], params.collect(), &v_params)
+            })
+            .collect::<Option<Vec<_>>>()?;
+        syncro_curves(&mut u_curves);
+        syncro_curves(&mut v_curves);
+
+        let rows = u_curves
+            .iter()
+            .map(|curve| curve.control_points().clone())
+            .collect::<Vec<_>>();
+        let (vknot_vec, control_points) = lofting(&v_params, &rows)?;
+        let uknot_vec = u_curves[0].knot_vec().clone();
+        let ulofted = BSplineSurface::new_unchecked((uknot_vec, vknot_vec), control_points);
+
+        let rows = v_curves
+            .iter()
+            .map(|curve| curve.control_points().clone())
+            .collect::<Vec<_>>();
+        let (uknot_vec, control_points) = lofting(&u_params, &rows)?;
+        let vknot_vec = v_curves[0].knot_vec().clone();
+        let vlofted =
+            BSplineSurface::new_unchecked((uknot_vec, vknot_vec), transpose(control_points));
+
+        let (uknot_vec, control_points) = lofting(&u_params, &points)?;
+        let (vknot_vec, control_points) = lofting(&v_params, &control_points)?;
+        let tensor = BSplineSurface::new_unchecked((uknot_vec, vknot_vec), control_points);
+
+        let mut surfaces = [ulofted, vlofted, tensor];
+        syncro_surfaces(&mut surfaces);
+        let [mut surface, vlofted, tensor] = surfaces;
+        surface
+            .control_points
+            .iter_mut()
+            .flatten()
+            .zip(vlofted.control_points.iter().flatten())
+            .zip(tensor.control_points.iter().flatten())
+            .for_each(|((p, q), r)| *p += *q - *r);
+
+        let interpolated = |curve: &BSplineCurve<Point3>, subs: &dyn Fn(f64) -> Point3| {
+            let (params, pts) = curve.parameter_division(curve.range_tuple(), tol);
+            params
+                .into_iter()
+                .zip(pts)
+                .all(|(t, p)| subs(t).distance(p) <= tol)
+        };
+        let u_interpolated = u_curves
+            .iter()
+            .zip(&v_params)
+            .all(|(curve, &v)| interpolated(curve, &|u| surface.subs(u, v)));
+        let v_interpolated = v_curves
+            .iter()
+            .zip(&u_params)
+            .all(|(curve, &u)| interpolated(curve, &|v| surface.subs(u, v)));
+        match u_interpolated && v_interpolated {
+            true => Some(surface),
+            false => None,
+        }
+    }
+}
+
 impl<V: Homogeneous> BSplineSurface<V> {
     /// lift up control points to homogeneous coordinate.
     pub fn lift_up(surface: BSplineSurface<V::Point>) -> Self {
@@ -1415,6 +1958,146 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
         BSplineSurface::new(knot_vecs, control_points)
     }
 
+    /// Creates the bicubically blended Coons patch by the boundary curves and the cross-boundary derivations.
+    ///
+    /// The parameter range of the surface is `[0, 1] x [0, 1]`, and the knot vectors of the curves are normalized.
+    /// The boundary curves are `[S(u, 0), S(u, 1), S(0, v), S(1, v)]`,
+    /// and the cross-boundary derivations are `[S_v(u, 0), S_v(u, 1), S_u(0, v), S_u(1, v)]`
+    /// with respect to the normalized parameters, where `S` is the resulting surface.
+    /// The twist vectors at the corners are the averages of the ones given by the derivations.
+    /// The surface has the boundary and the derivations if they are compatible at the corners,
+    /// so the adjacent surfaces with the same derivations are connected with G1 continuity.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let surface = BSplineSurface::new(
+    ///     (KnotVec::uniform_knot(3, 2), KnotVec::bezier_knot(3)),
+    ///     (0..5)
+    ///         .map(|i| {
+    ///             (0..4)
+    ///                 .map(|j| Point3::new(i as f64, j as f64, ((i * j) % 3) as f64))
+    ///                 .collect()
+    ///         })
+    ///         .collect(),
+    /// );
+    /// let cps = surface.control_points();
+    /// let (uknot_vec, vknot_vec) = surface.knot_vecs().clone();
+    /// let boundary = [
+    ///     surface.row_curve(0),
+    ///     surface.row_curve(3),
+    ///     surface.column_curve(0),
+    ///     surface.column_curve(4),
+    /// ];
+    /// // The cross-boundary derivations of the Bezier ends, e.g. S_v(u, 0) = 3 (P[i][1] - P[i][0]).
+    /// let derivations = [
+    ///     BSplineCurve::new(uknot_vec.clone(), (0..5).map(|i| (cps[i][1] - cps[i][0]) * 3.0).collect()),
+    ///     BSplineCurve::new(uknot_vec, (0..5).map(|i| (cps[i][3] - cps[i][2]) * 3.0).collect()),
+    ///     BSplineCurve::new(vknot_vec.clone(), (0..4).map(|j| (cps[1][j] - cps[0][j]) * 6.0).collect()),
+    ///     BSplineCurve::new(vknot_vec, (0..4).map(|j| (cps[4][j] - cps[3][j]) * 6.0).collect()),
+    /// ];
+    /// let coons = BSplineSurface::bicubic_coons(boundary, derivations);
+    /// // The surface is reconstructed since it is bicubic.
+    /// (0..=10).for_each(|i| {
+    ///     (0..=10).for_each(|j| {
+    ///         let (u, v) = (i as f64 / 10.0, j as f64 / 10.0);
+    ///         assert_near!(coons.subs(u, v), surface.subs(u, v));
+    ///     })
+    /// });
+    /// ```
+    pub fn bicubic_coons(
+        boundary: [BSplineCurve<P>; 4],
+        derivations: [BSplineCurve<P::Diff>; 4],
+    ) -> BSplineSurface<P> {
+        let into_points = |curve: BSplineCurve<P::Diff>| {
+            let control_points = curve.control_points.into_iter().map(P::from_vec).collect();
+            BSplineCurve::new_unchecked(curve.knot_vec, control_points)
+        };
+        let [c0, c1, d0, d1] = boundary;
+        let [t0, t1, s0, s1] = derivations.map(into_points);
+        let mut curves = [c0, c1, t0, t1];
+        syncro_curves(&mut curves);
+        let mut rails = [d0, d1, s0, s1];
+        syncro_curves(&mut rails);
+
+        // The cubic Hermite interpolation in the Bezier form.
+        let hermite =
+            |[x0, x1, x2, x3]: [P; 4]| [x0, x0 + x1.to_vec() / 3.0, x3 - x2.to_vec() / 3.0, x3];
+        let [c0, c1, t0, t1] = &curves;
+        let control_points = (0..c0.control_points.len())
+            .map(|k| {
+                let row = [
+                    c0.control_points[k],
+                    t0.control_points[k],
+                    t1.control_points[k],
+                    c1.control_points[k],
+                ];
+                hermite(row).to_vec()
+            })
+            .collect();
+        let knot_vecs = (c0.knot_vec.clone(), KnotVec::bezier_knot(3));
+        let vlofted = BSplineSurface::new_unchecked(knot_vecs, control_points);
+
+        let [d0, d1, s0, s1] = &rails;
+        let columns = (0..d0.control_points.len())
+            .map(|k| {
+                let column = [
+                    d0.control_points[k],
+                    s0.control_points[k],
+                    s1.control_points[k],
+                    d1.control_points[k],
+                ];
+                hermite(column)
+            })
+            .collect::<Vec<_>>();
+        let control_points = (0..4)
+            .map(|i| columns.iter().map(|column| column[i]).collect())
+            .collect();
+        let knot_vecs = (KnotVec::bezier_knot(3), d0.knot_vec.clone());
+        let ulofted = BSplineSurface::new_unchecked(knot_vecs, control_points);
+
+        // the tensor product of the cubic Hermite interpolation at the corners
+        let twist = |t: &BSplineCurve<P>, s: &BSplineCurve<P>, u: f64, v: f64| {
+            P::from_vec((t.der(u) + s.der(v)) / 2.0)
+        };
+        let hermite_data = [
+            [c0.front(), t0.front(), t1.front(), c1.front()],
+            [
+                s0.front(),
+                twist(t0, s0, 0.0, 0.0),
+                twist(t1, s0, 0.0, 1.0),
+                s0.back(),
+            ],
+            [
+                s1.front(),
+                twist(t0, s1, 1.0, 0.0),
+                twist(t1, s1, 1.0, 1.0),
+                s1.back(),
+            ],
+            [c0.back(), t0.back(), t1.back(), c1.back()],
+        ];
+        let rows = hermite_data.map(hermite);
+        let control_points = (0..4)
+            .map(|j| hermite(rows.map(|row| row[j])))
+            .collect::<Vec<_>>();
+        let control_points = (0..4)
+            .map(|i| control_points.iter().map(|column| column[i]).collect())
+            .collect();
+        let knot_vecs = (KnotVec::bezier_knot(3), KnotVec::bezier_knot(3));
+        let corners = BSplineSurface::new_unchecked(knot_vecs, control_points);
+
+        let mut surfaces = [vlofted, ulofted, corners];
+        syncro_surfaces(&mut surfaces);
+        let [mut surface, ulofted, corners] = surfaces;
+        surface
+            .control_points
+            .iter_mut()
+            .flatten()
+            .zip(ulofted.control_points.iter().flatten())
+            .zip(corners.control_points.iter().flatten())
+            .for_each(|((p, q), r)| *p += *q - *r);
+        surface
+    }
+
     /// Normalizes the knot vectors
     #[inline(always)]
     pub fn knot_normalize(&mut self) -> &mut Self {
@@ -1564,6 +2247,121 @@ impl<P: ControlPoint<f64> + Tolerance> BSplineSurface<P> {
     }
 }
 
+/// The errors of the surfaces are bounded by the ones of the column curves, since each point of
+/// the surface is a convex combination of the points of the column curves at the same parameter.
+impl<P> BSplineSurface<P>
+where P: ControlPoint<f64> + Tolerance + MetricSpace<Metric = f64>
+{
+    fn column_curves(&self) -> Vec<BSplineCurve<P>> {
+        (0..self.control_points.len())
+            .map(|i| self.column_curve(i))
+            .collect()
+    }
+
+    fn set_column_curves(&mut self, curves: Vec<BSplineCurve<P>>) {
+        self.knot_vecs.1 = curves[0].knot_vec.clone();
+        self.control_points = curves
+            .into_iter()
+            .map(|curve| curve.control_points)
+            .collect();
+    }
+
+    /// Reduces the vdegree by one if the reduced surface is within the distance `tol` from `self`.
+    /// Returns the achieved error, or returns `None` and does not change `self` if the vdegree
+    /// cannot be reduced within `tol` or the vdegree is less than two.
+    ///
+    /// cf. [`BSplineCurve::reduce_degree`]
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(1), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 0.5, 1.0), Point3::new(0.0, 1.0, 0.0)],
+    ///     vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 0.5, 2.0), Point3::new(1.0, 1.0, 0.0)],
+    /// ];
+    /// let org_surface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let mut bspsurface = org_surface.clone();
+    /// bspsurface.elevate_udegree().elevate_vdegree().add_vknot(0.5);
+    ///
+    /// assert!(bspsurface.reduce_vdegree(1.0e-6).unwrap() < 1.0e-6);
+    /// assert!(bspsurface.reduce_udegree(1.0e-6).unwrap() < 1.0e-6);
+    /// assert_eq!(bspsurface.degrees(), (1, 2));
+    /// assert_eq!(bspsurface.knot_vecs(), org_surface.knot_vecs());
+    /// assert!(bspsurface.near_as_surface(&org_surface));
+    ///
+    /// // The surface is not flat.
+    /// assert!(bspsurface.reduce_vdegree(0.1).is_none());
+    /// ```
+    pub fn reduce_vdegree(&mut self, tol: f64) -> Option<f64> {
+        let originals = self.column_curves();
+        let mut curves = originals
+            .iter()
+            .map(BSplineCurve::degree_reduced)
+            .collect::<Option<Vec<_>>>()?;
+        let error = originals
+            .iter()
+            .zip(&curves)
+            .fold(0.0, |max, (org, curve)| {
+                f64::max(max, org.deviation_bound(curve))
+            });
+        if error > tol {
+            return None;
+        }
+        let error = remove_knots_simultaneously(&mut curves, &originals, tol);
+        self.set_column_curves(curves);
+        Some(error)
+    }
+
+    /// Reduces the udegree by one if the reduced surface is within the distance `tol` from `self`.
+    /// Returns the achieved error, or returns `None` and does not change `self` if the udegree
+    /// cannot be reduced within `tol` or the udegree is less than two.
+    ///
+    /// cf. [`BSplineSurface::reduce_vdegree`]
+    pub fn reduce_udegree(&mut self, tol: f64) -> Option<f64> {
+        self.swap_axes();
+        let res = self.reduce_vdegree(tol);
+        self.swap_axes();
+        res
+    }
+
+    fn remove_vknots_within(&mut self, tol: f64) -> f64 {
+        let originals = self.column_curves();
+        let mut curves = originals.clone();
+        let error = remove_knots_simultaneously(&mut curves, &originals, tol);
+        self.set_column_curves(curves);
+        error
+    }
+
+    /// Removes the knots as long as the surface is within the distance `tol` from the original one.
+    /// Returns the achieved error, an upper bound of the distance between the points at the same parameters.
+    ///
+    /// The uknots are removed first, and then the vknots are removed within the remaining tolerance.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2));
+    /// let ctrl_pts = vec![
+    ///     vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 0.5, 1.0), Point3::new(0.0, 1.0, 0.0)],
+    ///     vec![Point3::new(0.5, 0.0, 1.0), Point3::new(0.5, 0.5, 2.0), Point3::new(0.5, 1.0, 1.0)],
+    ///     vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 0.5, 1.0), Point3::new(1.0, 1.0, 0.0)],
+    /// ];
+    /// let org_surface = BSplineSurface::new(knot_vecs, ctrl_pts);
+    /// let mut bspsurface = org_surface.clone();
+    /// bspsurface.add_uknot(0.3).add_uknot(0.6).add_vknot(0.5);
+    /// *bspsurface.control_point_mut(2, 2) += Vector3::new(0.0, 0.0, 0.01);
+    ///
+    /// let error = bspsurface.remove_knots_within(0.05);
+    /// assert!(0.0 < error && error <= 0.05);
+    /// assert_eq!(bspsurface.knot_vecs(), org_surface.knot_vecs());
+    /// ```
+    pub fn remove_knots_within(&mut self, tol: f64) -> f64 {
+        self.swap_axes();
+        let uerror = self.remove_vknots_within(tol);
+        self.swap_axes();
+        uerror + self.remove_vknots_within(tol - uerror)
+    }
+}
+
 impl<V: Bounded> BSplineSurface<V> {
     /// Returns the bounding box including all control points.
     #[inline(always)]
@@ -1591,6 +2389,104 @@ impl ParametricSurface3D for BSplineSurface<Point3> {}
 
 impl<V> BoundedSurface for BSplineSurface<V> where BSplineSurface<V>: ParametricSurface {}
 
+impl<P: ControlPoint<f64> + Tolerance> ConcatSurface for BSplineSurface<P> {
+    /// Concats two B-spline surfaces whose control points coincide along the common boundary.
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let knot_vecs = (KnotVec::bezier_knot(1), KnotVec::bezier_knot(1));
+    /// let surface0 = BSplineSurface::new(
+    ///     knot_vecs.clone(),
+    ///     vec![
+    ///         vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)],
+    ///         vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 1.0, 0.0)],
+    ///     ],
+    /// );
+    /// let surface1 = BSplineSurface::new(
+    ///     knot_vecs,
+    ///     vec![
+    ///         vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 1.0, 0.0)],
+    ///         vec![Point3::new(2.0, 0.0, 1.0), Point3::new(2.0, 1.0, 1.0)],
+    ///     ],
+    /// );
+    /// let surface = surface0.try_concat_surface(&surface1).unwrap();
+    /// assert_eq!(surface.range_tuple(), ((0.0, 2.0), (0.0, 1.0)));
+    /// assert_near!(surface.subs(0.5, 0.5), surface0.subs(0.5, 0.5));
+    /// assert_near!(surface.subs(1.5, 0.5), surface1.subs(0.5, 0.5));
+    ///
+    /// // The boundaries are different.
+    /// assert!(surface1.try_concat_surface(&surface1).is_none());
+    /// ```
+    fn try_concat_surface(&self, rhs: &Self) -> Option<Self> {
+        fn concat_u<P: ControlPoint<f64> + Tolerance>(
+            surface0: &BSplineSurface<P>,
+            surface1: &BSplineSurface<P>,
+        ) -> Option<BSplineSurface<P>> {
+            let (vlength0, vlength1) = (
+                surface0.vknot_vec().range_length(),
+                surface1.vknot_vec().range_length(),
+            );
+            if !vlength0.near(&vlength1) {
+                return None;
+            }
+            let mut surfaces = [surface0.clone(), surface1.clone()];
+            let umove = surface0.uknot_vec()[surface0.uknot_vec().len() - 1] - surface1.uknot(0);
+            surfaces[1].knot_translate(umove, 0.0);
+            let vmove = surface0.vknot(0) - surface1.vknot(0);
+            surfaces[1].knot_translate(0.0, vmove);
+            let [mut surface0, surface1] = syncro_vknots(surfaces);
+            let back = surface0.control_points.last()?;
+            let front = surface1.control_points.first()?;
+            if back.len() != front.len() || !back.iter().zip(front).all(|(p, q)| p.near(q)) {
+                return None;
+            }
+            let degree = surface0.udegree();
+            surface0
+                .knot_vecs
+                .0
+                .try_concat(&surface1.knot_vecs.0, degree)
+                .ok()?;
+            surface0.control_points.extend(surface1.control_points);
+            Some(surface0)
+        }
+        concat_u(self, rhs).or_else(|| {
+            let (mut surface0, mut surface1) = (self.clone(), rhs.clone());
+            surface0.swap_axes();
+            surface1.swap_axes();
+            let mut surface = concat_u(&surface0, &surface1)?;
+            surface.swap_axes();
+            Some(surface)
+        })
+    }
+}
+
+/// Synchronizes the degrees of the surfaces and the `v` knot vectors with the same range.
+fn syncro_vknots<P: ControlPoint<f64> + Tolerance>(
+    mut surfaces: [BSplineSurface<P>; 2],
+) -> [BSplineSurface<P>; 2] {
+    let udegree = usize::max(surfaces[0].udegree(), surfaces[1].udegree());
+    let vdegree = usize::max(surfaces[0].vdegree(), surfaces[1].vdegree());
+    surfaces.iter_mut().for_each(|surface| {
+        (surface.udegree()..udegree).for_each(|_| {
+            surface.elevate_udegree();
+        });
+        (surface.vdegree()..vdegree).for_each(|_| {
+            surface.elevate_vdegree();
+        });
+    });
+    let multiplicity =
+        |knot_vec: &KnotVec, knot: f64| knot_vec.iter().filter(|x| f64::near(x, &knot)).count();
+    (0..2).for_each(|i| {
+        let (knots, mults) = surfaces[1 - i].vknot_vec().to_single_multi();
+        knots.into_iter().zip(mults).for_each(|(knot, mult)| {
+            (multiplicity(surfaces[i].vknot_vec(), knot)..mult).for_each(|_| {
+                surfaces[i].add_vknot(knot);
+            });
+        });
+    });
+    surfaces
+}
+
 impl<V: Clone> Invertible for BSplineSurface<V> {
     #[inline(always)]
     fn invert(&mut self) { self.swap_axes(); }
@@ -1835,6 +2731,148 @@ fn combinatorial(n: usize) -> Vec<usize> {
     res
 }
 
+/// Makes `surfaces` have the same degrees and the same normalized knot vectors.
+fn syncro_surfaces<P: ControlPoint<f64> + Tolerance>(surfaces: &mut [BSplineSurface<P>]) {
+    let udegree = surfaces.iter().map(|s| s.udegree()).max().unwrap_or(0);
+    let vdegree = surfaces.iter().map(|s| s.vdegree()).max().unwrap_or(0);
+    surfaces.iter_mut().for_each(|surface| {
+        (surface.udegree()..udegree).for_each(|_| {
+            surface.elevate_udegree();
+        });
+        (surface.vdegree()..vdegree).for_each(|_| {
+            surface.elevate_vdegree();
+        });
+        surface.knot_normalize();
+    });
+    // the union of the knots with the maximum multiplicities
+    let union = |knot_vecs: Vec<&KnotVec>| {
+        knot_vecs
+            .into_iter()
+            .fold(Vec::<(f64, usize)>::new(), |mut union, knot_vec| {
+                let (knots, mults) = knot_vec.to_single_multi();
+                knots.into_iter().zip(mults).for_each(|(knot, mult)| {
+                    match union.iter_mut().find(|(x, _)| x.near(&knot)) {
+                        Some((_, m)) => *m = usize::max(*m, mult),
+                        None => union.push((knot, mult)),
+                    }
+                });
+                union
+            })
+    };
+    let multiplicity =
+        |knot_vec: &KnotVec, knot: f64| knot_vec.iter().filter(|x| f64::near(x, &knot)).count();
+    let uknots = union(surfaces.iter().map(|s| s.uknot_vec()).collect());
+    let vknots = union(surfaces.iter().map(|s| s.vknot_vec()).collect());
+    surfaces.iter_mut().for_each(|surface| {
+        uknots.iter().for_each(|&(knot, mult)| {
+            (multiplicity(surface.uknot_vec(), knot)..mult).for_each(|_| {
+                surface.add_uknot(knot);
+            });
+        });
+        vknots.iter().for_each(|&(knot, mult)| {
+            (multiplicity(surface.vknot_vec(), knot)..mult).for_each(|_| {
+                surface.add_vknot(knot);
+            });
+        });
+    });
+}
+
+/// The clamped knot vector for the interpolation at `params` by the averaging. cf) The NURBS Book, (9.8).
+fn interpolation_knot(params: &[f64], degree: usize) -> KnotVec {
+    let len = params.len();
+    let mut knots = vec![0.0; degree + 1];
+    (1..len - degree).for_each(|j| {
+        knots.push(params[j..j + degree].iter().sum::<f64>() / degree as f64);
+    });
+    knots.extend(std::iter::repeat_n(1.0, degree + 1));
+    KnotVec(knots)
+}
+
+/// Reparametrizes the curve on `[0, 1]` piecewise linearly so that the parameters `from` are moved to `to`.
+/// Returns `None` if the orders of `from` and `to` are different.
+fn linear_reparametrization(
+    curve: &BSplineCurve<Point3>,
+    from: Vec<f64>,
+    to: &[f64],
+) -> Option<BSplineCurve<Point3>> {
+    // the pairs of the parameters (new, old)
+    let mut pairs = vec![(0.0, 0.0)];
+    for (&s, t) in to.iter().zip(from).chain([(&1.0, 1.0)]) {
+        let (s0, t0) = pairs[pairs.len() - 1];
+        match (s - s0 > TOLERANCE, t - t0 > TOLERANCE) {
+            (true, true) => pairs.push((s, t)),
+            // the intersections at the ends of the curve are moved to the ends.
+            (false, false) if pairs.len() > 1 => {
+                *pairs.last_mut()? = (f64::max(s, s0), f64::max(t, t0))
+            }
+            (false, false) => {}
+            _ => return None,
+        }
+    }
+    if pairs.iter().all(|(s, t)| s.near(t)) {
+        return Some(curve.clone());
+    }
+    let mut pieces = Vec::new();
+    let mut rest = curve.clone();
+    pairs[1..pairs.len() - 1].iter().for_each(|(_, t)| {
+        let latter = rest.cut(*t);
+        pieces.push(rest.clone());
+        rest = latter;
+    });
+    pieces.push(rest);
+    pieces
+        .into_iter()
+        .zip(pairs.windows(2))
+        .map(|(piece, pair)| {
+            let ((s0, t0), (s1, t1)) = (pair[0], pair[1]);
+            // the ends are set exactly for the concatenation
+            let knots = piece.knot_vec().iter().map(|&t| match t {
+                _ if t.near(&t0) => s0,
+                _ if t.near(&t1) => s1,
+                _ => s0 + (t - t0) * (s1 - s0) / (t1 - t0),
+            });
+            BSplineCurve::new_unchecked(
+                KnotVec::from(knots.collect::<Vec<_>>()),
+                piece.destruct().1,
+            )
+        })
+        .try_fold(
+            None,
+            |curve: Option<BSplineCurve<Point3>>, piece| match curve {
+                Some(curve) => curve.try_concat(&piece).ok().map(Some),
+                None => Some(Some(piece)),
+            },
+        )?
+}
+
+/// Interpolates the `rows` at `params` by the B-spline curves of degree up to three.
+/// Returns the common knot vector and the control points `control_points[k][l]`,
+/// where `k` is the index of the element of a row and `l` is the one of the control point of the curve.
+fn lofting(params: &[f64], rows: &[Vec<Point3>]) -> Option<(KnotVec, Vec<Vec<Point3>>)> {
+    let degree = usize::min(3, params.len() - 1);
+    let knot_vec = interpolation_knot(params, degree);
+    let control_points = (0..rows[0].len())
+        .map(|k| {
+            let parameter_points = params
+                .iter()
+                .zip(rows)
+                .map(|(t, row)| (*t, row[k]))
+                .collect::<Vec<_>>();
+            BSplineCurve::try_interpole(knot_vec.clone(), parameter_points)
+                .ok()
+                .map(BSplineCurve::destruct)
+                .map(|(_, control_points)| control_points)
+        })
+        .collect::<Option<Vec<_>>>()?;
+    Some((knot_vec, control_points))
+}
+
+fn transpose(matrix: Vec<Vec<Point3>>) -> Vec<Vec<Point3>> {
+    (0..matrix[0].len())
+        .map(|j| matrix.iter().map(|row| row[j]).collect())
+        .collect()
+}
+
 #[test]
 fn test_include_bspcurve2() {
     let knot_vec = KnotVec::uniform_knot(2, 3);
@@ -1932,3 +2970,42 @@ fn test_include_bspcurve3() {
     *curve.control_point_mut(2) += Vector3::new(0.0, 0.0, 0.001);
     assert!(!surface.include(&curve));
 }
+
+#[test]
+fn fit_smoothing_test() {
+    // The noisy samples of the plane z = 0.
+    let parameter_points = (0..=10)
+        .flat_map(|i| {
+            (0..=10).map(move |j| {
+                let (u, v) = (i as f64 / 10.0, j as f64 / 10.0);
+                let noise = if (i + j) % 2 == 0 { 1.0e-2 } else { -1.0e-2 };
+                ((u, v), Point3::new(u, v, noise))
+            })
+        })
+        .collect::<Vec<_>>();
+    let knot_vecs = (KnotVec::uniform_knot(3, 7), KnotVec::uniform_knot(3, 7));
+    let max_height = |surface: &BSplineSurface<Point3>| {
+        (0..=40)
+            .flat_map(|i| (0..=40).map(move |j| (i as f64 / 40.0, j as f64 / 40.0)))
+            .fold(0.0, |max, (u, v)| f64::max(max, surface.subs(u, v).z.abs()))
+    };
+
+    // The fitting with the same knot vectors follows the noise, but the smoothing suppresses it.
+    let (rough, rough_deviation) =
+        BSplineSurface::least_squares(&parameter_points, knot_vecs.clone(), (3, 3), 0.0).unwrap();
+    let (smooth, smooth_deviation) =
+        BSplineSurface::least_squares(&parameter_points, knot_vecs, (3, 3), 1.0e-3).unwrap();
+    assert!(rough_deviation.rms < smooth_deviation.rms);
+    assert!(smooth_deviation.max < 0.02);
+    assert!(max_height(&smooth) < 0.5 * max_height(&rough));
+
+    let points = (0..=10)
+        .map(|i| (0..=10).map(|j| parameter_points[i * 11 + j].1).collect())
+        .collect::<Vec<Vec<_>>>();
+    let (surface, deviation) =
+        BSplineSurface::fit_grid(&points, (3, 3), 0.5, Default::default()).unwrap();
+    assert_eq!(surface.control_points().len(), 4);
+    assert!(deviation.max < 0.02);
+    assert!(deviation.rms <= deviation.max);
+    assert!(BSplineSurface::fit_grid(&points[..2], (3, 3), 0.5, Default::default()).is_none());
+}
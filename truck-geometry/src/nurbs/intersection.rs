@@ -0,0 +1,647 @@
+use super::*;
+
+pub(super) const INTERSECTION_TRIALS: usize = 100;
+pub(super) const MAX_SUBDIVISION_DEPTH: usize = 40;
+pub(super) const BISECTION_TRIALS: usize = 60;
+// pieces are flat if the control points deviate from the chord within this ratio of its length.
+const FLATNESS_RATIO: f64 = 1.0e-2;
+// the ratio of the tolerance of the division of the geometries without NURBS representation to their sizes.
+const DIVISION_RATIO: f64 = 1.0e-3;
+
+/// Returns all the intersections of two curves by the pairs of parameters `(t0, t1)` sorted by `t0`.
+///
+/// The curves are subdivided into Bezier pieces of their NURBS representations, cf. [`ToNurbs`],
+/// the pairs of pieces whose control points have disjoint bounding boxes are pruned,
+/// and the remaining pairs are refined by Newton's method on the original curves.
+/// If a curve has no NURBS representation, the polyline of [`ParameterDivision1D`] is used instead.
+///
+/// Tangential intersections are also detected, as the closest points within `tol`.
+/// If the curves overlap on an interval, the ends of the interval are returned.
+///
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+/// let curve0 = BSplineCurve::new(
+///     KnotVec::bezier_knot(3),
+///     vec![
+///         Point3::new(0.0, 0.0, 0.0),
+///         Point3::new(1.0, 3.0, 0.0),
+///         Point3::new(2.0, -3.0, 0.0),
+///         Point3::new(3.0, 0.0, 0.0),
+///     ],
+/// );
+/// let curve1 = Line(Point3::new(0.0, 0.0, 0.0), Point3::new(3.0, 0.0, 0.0));
+/// let res = intersect_curves(&curve0, &curve1, TOLERANCE);
+/// // the cubic curve crosses the line at its ends and middle point.
+/// assert_eq!(res.len(), 3);
+/// res.iter()
+///     .for_each(|&(t0, t1)| assert_near!(curve0.subs(t0), curve1.subs(t1)));
+/// assert_near!(res[1].0, 0.5);
+/// ```
+///
+/// # Panics
+///
+/// `tol` must be greater than or equal to `TOLERANCE`.
+pub fn intersect_curves<C0, C1>(curve0: &C0, curve1: &C1, tol: f64) -> Vec<(f64, f64)>
+where
+    C0: IntersectableCurve,
+    C1: IntersectableCurve, {
+    nonpositive_tolerance!(tol);
+    let pieces0 = CurvePiece::decompose(curve0, tol);
+    let pieces1 = CurvePiece::decompose(curve1, tol);
+    let mut hints = Vec::new();
+    pieces0.iter().for_each(|piece0| {
+        pieces1
+            .iter()
+            .for_each(|piece1| sub_intersect(piece0, piece1, tol, 0, &mut hints))
+    });
+
+    let project = |t: f64, hint: f64| {
+        let pt = curve0.subs(t);
+        let t1 = curve1.search_nearest_parameter(pt, Some(hint), INTERSECTION_TRIALS)?;
+        let t1 = fit_into_range(t1, curve1.range_tuple(), curve1.period())?;
+        Some((t1, pt.distance(curve1.subs(t1))))
+    };
+    let candidates = hints
+        .into_iter()
+        .filter_map(|((t0, p0), (t1, p1))| {
+            let t0 = curve0
+                .search_nearest_parameter(p0, Some(t0), INTERSECTION_TRIALS)
+                .unwrap_or(t0);
+            let t1 = curve1
+                .search_nearest_parameter(p1, Some(t1), INTERSECTION_TRIALS)
+                .unwrap_or(t1);
+            let (t0, t1) = algo::curve::search_intersection_parameter(
+                curve0,
+                curve1,
+                (t0, t1),
+                INTERSECTION_TRIALS,
+            )
+            .or_else(|| {
+                algo::curve::search_closest_parameter(curve0, curve1, (t0, t1), INTERSECTION_TRIALS)
+            })
+            .unwrap_or((t0, t1));
+            let t0 = fit_into_range(t0, curve0.range_tuple(), curve0.period())?;
+            let t1 = fit_into_range(t1, curve1.range_tuple(), curve1.period())?;
+            let dist = curve0.subs(t0).distance(curve1.subs(t1));
+            match dist <= tol {
+                true => Some((t0, t1, dist)),
+                false => None,
+            }
+        })
+        .collect();
+    classify_candidates(candidates, curve0.range_tuple(), project, tol)
+}
+
+/// Returns all the intersections of a curve and a surface by the pairs of parameters `(t, (u, v))` sorted by `t`.
+///
+/// The algorithm is the same as [`intersect_curves`]: the surface is subdivided into Bezier patches
+/// of its NURBS representation, or into the grid of [`ParameterDivision2D`] if there is no NURBS representation.
+///
+/// Tangential intersections are also detected, as the closest points within `tol`.
+/// If the curve is on the surface on an interval, the ends of the interval are returned.
+///
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+/// let sphere = Sphere::new(Point3::origin(), 1.0);
+/// let line = Line(Point3::new(-2.0, 0.0, 0.5), Point3::new(2.0, 0.0, 0.5));
+/// let res = intersect_curve_surface(&line, &sphere, TOLERANCE);
+/// assert_eq!(res.len(), 2);
+/// res.iter().for_each(|&(t, (u, v))| assert_near!(line.subs(t), sphere.subs(u, v)));
+/// let x = f64::sqrt(0.75);
+/// assert_near!(line.subs(res[0].0), Point3::new(-x, 0.0, 0.5));
+/// assert_near!(line.subs(res[1].0), Point3::new(x, 0.0, 0.5));
+/// ```
+///
+/// # Panics
+///
+/// `tol` must be greater than or equal to `TOLERANCE`.
+pub fn intersect_curve_surface<C, S>(curve: &C, surface: &S, tol: f64) -> Vec<(f64, (f64, f64))>
+where
+    C: IntersectableCurve,
+    S: IntersectableSurface, {
+    nonpositive_tolerance!(tol);
+    let pieces0 = CurvePiece::decompose(curve, tol);
+    let pieces1 = SurfacePiece::decompose(surface, tol);
+    let mut hints = Vec::new();
+    pieces0.iter().for_each(|piece0| {
+        pieces1
+            .iter()
+            .for_each(|piece1| sub_intersect(piece0, piece1, tol, 0, &mut hints))
+    });
+
+    let (urange, vrange) = surface.range_tuple();
+    let fit_into_ranges = |(u, v): (f64, f64)| {
+        let u = fit_into_range(u, urange, surface.u_period())?;
+        let v = fit_into_range(v, vrange, surface.v_period())?;
+        Some((u, v))
+    };
+    let project = |t: f64, hint: (f64, f64)| {
+        let pt = curve.subs(t);
+        let uv = surface.search_nearest_parameter(pt, Some(hint), INTERSECTION_TRIALS)?;
+        let (u, v) = fit_into_ranges(uv)?;
+        Some(((u, v), pt.distance(surface.subs(u, v))))
+    };
+    let candidates = hints
+        .into_iter()
+        .filter_map(|((t, p0), (uv, p1))| {
+            let t = curve
+                .search_nearest_parameter(p0, Some(t), INTERSECTION_TRIALS)
+                .unwrap_or(t);
+            let uv = surface
+                .search_nearest_parameter(p1, Some(uv), INTERSECTION_TRIALS)
+                .unwrap_or(uv);
+            let (uv, t) = algo::surface::search_intersection_parameter(
+                surface,
+                uv,
+                curve,
+                t,
+                INTERSECTION_TRIALS,
+            )
+            .or_else(|| {
+                algo::surface::search_closest_parameter(surface, uv, curve, t, INTERSECTION_TRIALS)
+            })
+            .unwrap_or((uv, t));
+            let t = fit_into_range(t, curve.range_tuple(), curve.period())?;
+            let (u, v) = fit_into_ranges(uv)?;
+            let dist = curve.subs(t).distance(surface.subs(u, v));
+            match dist <= tol {
+                true => Some((t, (u, v), dist)),
+                false => None,
+            }
+        })
+        .collect();
+    classify_candidates(candidates, curve.range_tuple(), project, tol)
+}
+
+/// The curves which can be the arguments of [`intersect_curves`] and [`intersect_curve_surface`].
+pub trait IntersectableCurve:
+    ParametricCurve3D
+    + BoundedCurve
+    + ToNurbs<D1, Range = (f64, f64), Nurbs = NurbsCurve<Vector4>>
+    + ParameterDivision1D<Point = Point3>
+    + SearchNearestParameter<D1, Point = Point3> {
+}
+
+impl<C> IntersectableCurve for C where C: ParametricCurve3D
+        + BoundedCurve
+        + ToNurbs<D1, Range = (f64, f64), Nurbs = NurbsCurve<Vector4>>
+        + ParameterDivision1D<Point = Point3>
+        + SearchNearestParameter<D1, Point = Point3>
+{
+}
+
+/// The surfaces which can be the arguments of [`intersect_curve_surface`].
+pub trait IntersectableSurface:
+    ParametricSurface3D
+    + BoundedSurface
+    + ToNurbs<D2, Range = ((f64, f64), (f64, f64)), Nurbs = NurbsSurface<Vector4>>
+    + ParameterDivision2D
+    + SearchNearestParameter<D2, Point = Point3> {
+}
+
+impl<S> IntersectableSurface for S where S: ParametricSurface3D
+        + BoundedSurface
+        + ToNurbs<D2, Range = ((f64, f64), (f64, f64)), Nurbs = NurbsSurface<Vector4>>
+        + ParameterDivision2D
+        + SearchNearestParameter<D2, Point = Point3>
+{
+}
+
+/// A part of geometry in the subdivision.
+pub(super) trait Piece: Sized {
+    type Parameter: Copy;
+    fn bounding_box(&self) -> BoundingBox<Point3>;
+    fn is_flat(&self, tol: f64) -> bool;
+    fn is_divisible(&self) -> bool;
+    fn split(&self) -> (Self, Self);
+    fn middle(&self) -> (Self::Parameter, Point3);
+}
+
+#[derive(Clone, Debug)]
+pub(super) enum CurvePiece {
+    Bezier(NurbsCurve<Vector4>),
+    // the points are within `margin` from the curve.
+    Polyline {
+        params: Vec<f64>,
+        points: Vec<Point3>,
+        margin: f64,
+    },
+}
+
+#[derive(Clone, Debug)]
+pub(super) enum SurfacePiece {
+    Bezier(NurbsSurface<Vector4>),
+    // the points are within `margin` from the surface.
+    Grid {
+        params: (Vec<f64>, Vec<f64>),
+        points: Vec<Vec<Point3>>,
+        margin: f64,
+    },
+}
+
+impl CurvePiece {
+    pub(super) fn decompose<C: IntersectableCurve>(curve: &C, tol: f64) -> Vec<Self> {
+        match curve.to_nurbs() {
+            Some(nurbs) => nurbs
+                .non_rationalized()
+                .bezier_decomposition()
+                .into_iter()
+                .map(|bezier| CurvePiece::Bezier(NurbsCurve::new(bezier)))
+                .collect(),
+            None => {
+                let (t0, t1) = curve.range_tuple();
+                let bdd = (0..=16)
+                    .map(|i| curve.subs(t0 + (t1 - t0) * i as f64 / 16.0))
+                    .collect::<BoundingBox<_>>();
+                let margin = f64::max(tol, bdd.diameter() * DIVISION_RATIO);
+                let (params, points) = curve.parameter_division((t0, t1), margin);
+                vec![CurvePiece::Polyline {
+                    params,
+                    points,
+                    margin,
+                }]
+            }
+        }
+    }
+}
+
+impl Piece for CurvePiece {
+    type Parameter = f64;
+    fn bounding_box(&self) -> BoundingBox<Point3> {
+        match self {
+            CurvePiece::Bezier(bezier) => bezier.roughly_bounding_box(),
+            CurvePiece::Polyline { points, margin, .. } => expand(points.iter().collect(), *margin),
+        }
+    }
+    fn is_flat(&self, tol: f64) -> bool {
+        match self {
+            CurvePiece::Bezier(bezier) => {
+                let points = bezier.control_points();
+                let (p, q) = (points[0].to_point(), points[points.len() - 1].to_point());
+                let margin = f64::max(tol, p.distance(q) * FLATNESS_RATIO);
+                points
+                    .iter()
+                    .all(|x| distance_to_segment(x.to_point(), (p, q)) <= margin)
+            }
+            CurvePiece::Polyline { points, .. } => points.len() <= 2,
+        }
+    }
+    fn is_divisible(&self) -> bool {
+        match self {
+            CurvePiece::Bezier(bezier) => {
+                let (t0, t1) = bezier.range_tuple();
+                t1 - t0 > 2.0 * TOLERANCE
+            }
+            CurvePiece::Polyline { points, .. } => points.len() > 2,
+        }
+    }
+    fn split(&self) -> (Self, Self) {
+        match self {
+            CurvePiece::Bezier(bezier) => {
+                let (t0, t1) = bezier.range_tuple();
+                let mut bezier0 = bezier.clone();
+                let bezier1 = bezier0.cut((t0 + t1) / 2.0);
+                (CurvePiece::Bezier(bezier0), CurvePiece::Bezier(bezier1))
+            }
+            CurvePiece::Polyline {
+                params,
+                points,
+                margin,
+            } => {
+                let k = params.len() / 2;
+                let piece0 = CurvePiece::Polyline {
+                    params: params[..=k].to_vec(),
+                    points: points[..=k].to_vec(),
+                    margin: *margin,
+                };
+                let piece1 = CurvePiece::Polyline {
+                    params: params[k..].to_vec(),
+                    points: points[k..].to_vec(),
+                    margin: *margin,
+                };
+                (piece0, piece1)
+            }
+        }
+    }
+    fn middle(&self) -> (f64, Point3) {
+        match self {
+            CurvePiece::Bezier(bezier) => {
+                let (t0, t1) = bezier.range_tuple();
+                let t = (t0 + t1) / 2.0;
+                (t, bezier.subs(t))
+            }
+            CurvePiece::Polyline { params, points, .. } => match params.len() {
+                2 => ((params[0] + params[1]) / 2.0, points[0].midpoint(points[1])),
+                len => (params[len / 2], points[len / 2]),
+            },
+        }
+    }
+}
+
+impl SurfacePiece {
+    pub(super) fn decompose<S: IntersectableSurface>(surface: &S, tol: f64) -> Vec<Self> {
+        match surface.to_nurbs() {
+            Some(nurbs) => {
+                let split_knots = |knot_vec: &KnotVec| {
+                    let (knots, _) = knot_vec.to_single_multi();
+                    knots[1..knots.len() - 1].to_vec()
+                };
+                let uknots = split_knots(nurbs.uknot_vec());
+                let vknots = split_knots(nurbs.vknot_vec());
+                let mut strips = Vec::new();
+                let mut strip = nurbs;
+                uknots
+                    .iter()
+                    .rev()
+                    .for_each(|u| strips.push(strip.ucut(*u)));
+                strips.push(strip);
+                strips
+                    .into_iter()
+                    .flat_map(|mut strip| {
+                        let mut patches = Vec::new();
+                        vknots
+                            .iter()
+                            .rev()
+                            .for_each(|v| patches.push(strip.vcut(*v)));
+                        patches.push(strip);
+                        patches
+                    })
+                    .map(SurfacePiece::Bezier)
+                    .collect()
+            }
+            None => {
+                let ((u0, u1), (v0, v1)) = surface.range_tuple();
+                let bdd = (0..=64)
+                    .map(|i| {
+                        let (u, v) = ((i / 8) as f64 / 8.0, (i % 8) as f64 / 8.0);
+                        surface.subs(u0 + (u1 - u0) * u, v0 + (v1 - v0) * v)
+                    })
+                    .collect::<BoundingBox<_>>();
+                let margin = f64::max(tol, bdd.diameter() * DIVISION_RATIO);
+                let (udiv, vdiv) = surface.parameter_division(((u0, u1), (v0, v1)), margin);
+                let points = udiv
+                    .iter()
+                    .map(|u| vdiv.iter().map(|v| surface.subs(*u, *v)).collect())
+                    .collect();
+                vec![SurfacePiece::Grid {
+                    params: (udiv, vdiv),
+                    points,
+                    margin,
+                }]
+            }
+        }
+    }
+}
+
+impl Piece for SurfacePiece {
+    type Parameter = (f64, f64);
+    fn bounding_box(&self) -> BoundingBox<Point3> {
+        match self {
+            SurfacePiece::Bezier(bezier) => bezier.roughly_bounding_box(),
+            SurfacePiece::Grid { points, margin, .. } => {
+                expand(points.iter().flatten().collect(), *margin)
+            }
+        }
+    }
+    fn is_flat(&self, tol: f64) -> bool {
+        match self {
+            SurfacePiece::Bezier(bezier) => {
+                let points = bezier.control_points();
+                let (m, n) = (points.len() - 1, points[0].len() - 1);
+                let corner = |i: usize, j: usize| EuclideanSpace::to_vec(points[i][j].to_point());
+                let (p00, p01, p10, p11) = (corner(0, 0), corner(0, n), corner(m, 0), corner(m, n));
+                let margin = f64::max(
+                    tol,
+                    p00.distance(p11).max(p01.distance(p10)) * FLATNESS_RATIO,
+                );
+                points.iter().enumerate().all(|(i, row)| {
+                    row.iter().enumerate().all(|(j, x)| {
+                        let (s, t) = (i as f64 / m as f64, j as f64 / n as f64);
+                        let bilinear = (p00 * (1.0 - t) + p01 * t) * (1.0 - s)
+                            + (p10 * (1.0 - t) + p11 * t) * s;
+                        x.to_point().distance(EuclideanSpace::from_vec(bilinear)) <= margin
+                    })
+                })
+            }
+            SurfacePiece::Grid { points, .. } => points.len() <= 2 && points[0].len() <= 2,
+        }
+    }
+    fn is_divisible(&self) -> bool {
+        match self {
+            SurfacePiece::Bezier(bezier) => {
+                let ((u0, u1), (v0, v1)) = bezier.range_tuple();
+                u1 - u0 > 2.0 * TOLERANCE || v1 - v0 > 2.0 * TOLERANCE
+            }
+            SurfacePiece::Grid { points, .. } => points.len() > 2 || points[0].len() > 2,
+        }
+    }
+    fn split(&self) -> (Self, Self) {
+        match self {
+            SurfacePiece::Bezier(bezier) => {
+                let points = bezier.control_points();
+                let (m, n) = (points.len() - 1, points[0].len() - 1);
+                let p = |i: usize, j: usize| points[i][j].to_point();
+                let ulen = p(0, 0).distance(p(m, 0)) + p(0, n).distance(p(m, n));
+                let vlen = p(0, 0).distance(p(0, n)) + p(m, 0).distance(p(m, n));
+                let ((u0, u1), (v0, v1)) = bezier.range_tuple();
+                let mut bezier0 = bezier.clone();
+                let ucut =
+                    (ulen >= vlen && u1 - u0 > 2.0 * TOLERANCE) || v1 - v0 <= 2.0 * TOLERANCE;
+                let bezier1 = match ucut {
+                    true => bezier0.ucut((u0 + u1) / 2.0),
+                    false => bezier0.vcut((v0 + v1) / 2.0),
+                };
+                (SurfacePiece::Bezier(bezier0), SurfacePiece::Bezier(bezier1))
+            }
+            SurfacePiece::Grid {
+                params: (uparams, vparams),
+                points,
+                margin,
+            } => match uparams.len() >= vparams.len() {
+                true => {
+                    let k = uparams.len() / 2;
+                    let piece0 = SurfacePiece::Grid {
+                        params: (uparams[..=k].to_vec(), vparams.clone()),
+                        points: points[..=k].to_vec(),
+                        margin: *margin,
+                    };
+                    let piece1 = SurfacePiece::Grid {
+                        params: (uparams[k..].to_vec(), vparams.clone()),
+                        points: points[k..].to_vec(),
+                        margin: *margin,
+                    };
+                    (piece0, piece1)
+                }
+                false => {
+                    let k = vparams.len() / 2;
+                    let piece0 = SurfacePiece::Grid {
+                        params: (uparams.clone(), vparams[..=k].to_vec()),
+                        points: points.iter().map(|row| row[..=k].to_vec()).collect(),
+                        margin: *margin,
+                    };
+                    let piece1 = SurfacePiece::Grid {
+                        params: (uparams.clone(), vparams[k..].to_vec()),
+                        points: points.iter().map(|row| row[k..].to_vec()).collect(),
+                        margin: *margin,
+                    };
+                    (piece0, piece1)
+                }
+            },
+        }
+    }
+    fn middle(&self) -> ((f64, f64), Point3) {
+        match self {
+            SurfacePiece::Bezier(bezier) => {
+                let ((u0, u1), (v0, v1)) = bezier.range_tuple();
+                let (u, v) = ((u0 + u1) / 2.0, (v0 + v1) / 2.0);
+                ((u, v), bezier.subs(u, v))
+            }
+            SurfacePiece::Grid {
+                params: (uparams, vparams),
+                points,
+                ..
+            } => {
+                let (i, j) = (uparams.len() / 2, vparams.len() / 2);
+                let (i0, j0) = (i - 1 + uparams.len() % 2, j - 1 + vparams.len() % 2);
+                let u = (uparams[i0] + uparams[i]) / 2.0;
+                let v = (vparams[j0] + vparams[j]) / 2.0;
+                let pt = points[i0][j0].midpoint(points[i][j]);
+                ((u, v), pt)
+            }
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+fn sub_intersect<A: Piece, B: Piece>(
+    piece0: &A,
+    piece1: &B,
+    tol: f64,
+    depth: usize,
+    hints: &mut Vec<((A::Parameter, Point3), (B::Parameter, Point3))>,
+) {
+    let (bdd0, bdd1) = (piece0.bounding_box(), piece1.bounding_box());
+    let gap = (bdd0.min() - bdd1.max()).zip(bdd1.min() - bdd0.max(), f64::max);
+    if gap.x > tol || gap.y > tol || gap.z > tol {
+        return;
+    }
+    let (flat0, flat1) = (piece0.is_flat(tol), piece1.is_flat(tol));
+    if (flat0 && flat1) || depth >= MAX_SUBDIVISION_DEPTH {
+        hints.push((piece0.middle(), piece1.middle()));
+    } else if !flat0 && (flat1 || bdd0.diameter() >= bdd1.diameter()) {
+        let (piece00, piece01) = piece0.split();
+        sub_intersect(&piece00, piece1, tol, depth + 1, hints);
+        sub_intersect(&piece01, piece1, tol, depth + 1, hints);
+    } else {
+        let (piece10, piece11) = piece1.split();
+        sub_intersect(piece0, &piece10, tol, depth + 1, hints);
+        sub_intersect(piece0, &piece11, tol, depth + 1, hints);
+    }
+}
+
+/// Merges the candidates of the intersections and detects the overlapping intervals.
+///
+/// `project(t, hint)` returns the parameter of the other geometry nearest to the point at `t`
+/// and the distance between them.
+fn classify_candidates<T: Copy>(
+    mut candidates: Vec<(f64, T, f64)>,
+    range: (f64, f64),
+    project: impl Fn(f64, T) -> Option<(T, f64)>,
+    tol: f64,
+) -> Vec<(f64, T)> {
+    candidates.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    let on = |t: f64, hint: T| project(t, hint).filter(|(_, dist)| *dist <= tol);
+    let connected = |(t0, x0, _): (f64, T, f64), (t1, x1, _): (f64, T, f64)| {
+        (1..4).all(|i| {
+            let t = t0 + (t1 - t0) * i as f64 / 4.0;
+            on(t, if i < 2 { x0 } else { x1 }).is_some()
+        })
+    };
+    let mut runs: Vec<Vec<(f64, T, f64)>> = Vec::new();
+    candidates
+        .into_iter()
+        .for_each(|candidate| match runs.last_mut() {
+            Some(run) if connected(*run.last().unwrap(), candidate) => run.push(candidate),
+            _ => runs.push(vec![candidate]),
+        });
+
+    // the end of the interval, on which the geometries are within `tol`, from `t` toward `end`.
+    let extend = |(t, x): (f64, T), end: f64| {
+        let (mut inside, mut outside) = ((t, x), None);
+        let mut delta = (range.1 - range.0) * 1.0e-8;
+        while outside.is_none() {
+            let s = match (end - inside.0).abs() <= delta {
+                true => end,
+                false => inside.0 + delta * (end - t).signum(),
+            };
+            match on(s, inside.1) {
+                Some((y, _)) if s == end => return (s, y),
+                Some((y, _)) => inside = (s, y),
+                None => outside = Some(s),
+            }
+            delta *= 2.0;
+        }
+        let mut outside = outside.unwrap();
+        for _ in 0..BISECTION_TRIALS {
+            let s = (inside.0 + outside) / 2.0;
+            match on(s, inside.1) {
+                Some((y, _)) => inside = (s, y),
+                None => outside = s,
+            }
+        }
+        inside
+    };
+
+    let mut res = Vec::new();
+    runs.into_iter().for_each(|run| {
+        let (first, last) = (run[0], run[run.len() - 1]);
+        let start = extend((first.0, first.1), range.0);
+        let end = extend((last.0, last.1), range.1);
+        // Tangential or transversal intersections depart from the other geometry in the interval.
+        let overlap = (1..4).all(|i| {
+            let t = start.0 + (end.0 - start.0) * i as f64 / 4.0;
+            let hint = if i < 2 { start.1 } else { end.1 };
+            project(t, hint).is_some_and(|(_, dist)| dist <= tol / 8.0)
+        });
+        if overlap {
+            res.push(start);
+            res.push(end);
+        } else {
+            let best = run
+                .into_iter()
+                .min_by(|a, b| a.2.partial_cmp(&b.2).unwrap())
+                .unwrap();
+            res.push((best.0, best.1));
+        }
+    });
+    res
+}
+
+pub(super) fn fit_into_range(t: f64, (t0, t1): (f64, f64), period: Option<f64>) -> Option<f64> {
+    let t = match period {
+        Some(period) if t < t0 - TOLERANCE || t1 + TOLERANCE < t => {
+            t0 + (t - t0).rem_euclid(period)
+        }
+        _ => t,
+    };
+    match t0 - TOLERANCE <= t && t <= t1 + TOLERANCE {
+        true => Some(f64::clamp(t, t0, t1)),
+        false => None,
+    }
+}
+
+fn expand(bdd: BoundingBox<Point3>, margin: f64) -> BoundingBox<Point3> {
+    let diag = Vector3::new(margin, margin, margin);
+    [bdd.min() - diag, bdd.max() + diag].iter().collect()
+}
+
+fn distance_to_segment(x: Point3, (p, q): (Point3, Point3)) -> f64 {
+    let dir = q - p;
+    let t = match dir.so_small() {
+        true => 0.0,
+        false => f64::clamp((x - p).dot(dir) / dir.magnitude2(), 0.0, 1.0),
+    };
+    x.distance(p + dir * t)
+}
@@ -118,11 +118,99 @@ pub struct NurbsCurve<V>(BSplineCurve<V>);
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize, SelfSameGeometry)]
 pub struct NurbsSurface<V>(BSplineSurface<V>);
 
+/// Exact conversion to the NURBS, the common representation of the curves and surfaces.
+///
+/// `D` is [`D1`] for curves and [`D2`] for surfaces. The ranges are `(f64, f64)` for curves
+/// and `((f64, f64), (f64, f64))`, the pair of u-range and v-range, for surfaces.
+///
+/// The geometries defined by approximations, e.g. [`PCurve`], [`IntersectionCurve`] and [`RbfSurface`],
+/// have no exact representation and do not implement this trait.
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+/// use std::f64::consts::PI;
+/// let sphere = Sphere::new(Point3::new(1.0, 2.0, 3.0), 4.0);
+/// let nurbs = sphere.to_nurbs().unwrap();
+/// assert_eq!(nurbs.range_tuple(), ((0.0, PI), (0.0, 2.0 * PI)));
+///
+/// const N: usize = 10;
+/// for i in 0..=N {
+///     for j in 0..=N {
+///         let (u, v) = (i as f64 / N as f64 * PI, j as f64 / N as f64 * 2.0 * PI);
+///         assert_near!(nurbs.subs(u, v).distance(sphere.center()), 4.0);
+///     }
+/// }
+/// // The points at the knots coincide.
+/// assert_near!(nurbs.subs(PI / 2.0, PI / 2.0), sphere.subs(PI / 2.0, PI / 2.0));
+/// ```
+pub trait ToNurbs<D: SPDimension> {
+    /// the parameter range
+    type Range;
+    /// the NURBS curve or surface
+    type Nurbs;
+    /// Returns the NURBS with the same image as the part of `self` in `range`.
+    ///
+    /// The knot vectors span `range`, and the points at the knots coincide with the ones of `self`,
+    /// though the parametrizations between the knots may be different.
+    /// Returns `None` if there is no exact representation or `range` is out of the domain.
+    fn to_nurbs_in(&self, range: Self::Range) -> Option<Self::Nurbs>;
+    /// Returns the NURBS with the same image as `self` on the whole parameter range.
+    ///
+    /// Returns `None` if there is no exact representation or the parameter range is unbounded.
+    fn to_nurbs(&self) -> Option<Self::Nurbs>;
+}
+
+/// The parametrization of the points in fitting, cf) [`BSplineCurve::fit`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum Parametrization {
+    /// The parameters are proportional to the chord lengths.
+    #[default]
+    ChordLength,
+    /// The parameters are proportional to the square roots of the chord lengths.
+    /// This is more stable for the points with sharp turns.
+    Centripetal,
+}
+
+/// The options of fitting, cf) [`BSplineCurve::fit_with_options`].
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct FittingOptions<V> {
+    /// the parametrization of the points
+    pub parametrization: Parametrization,
+    /// the derivation of the fitted curve at the front point
+    pub front_derivation: Option<V>,
+    /// the derivation of the fitted curve at the back point
+    pub back_derivation: Option<V>,
+}
+
+/// The options of surface fitting, cf) [`BSplineSurface::fit_grid`].
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct SurfaceFittingOptions {
+    /// the parametrization of the grid points, not used for the scattered points
+    pub parametrization: Parametrization,
+    /// the weight of the thin-plate energy relative to the sum of the squared distances
+    pub smoothing: f64,
+}
+
+/// The deviations of the fitted geometry from the points.
+#[derive(Clone, Copy, PartialEq, Debug, Default)]
+pub struct FittingDeviation {
+    /// the maximum distance
+    pub max: f64,
+    /// the root mean square of the distances
+    pub rms: f64,
+}
+
 mod bspcurve;
 mod bspsurface;
+mod intersection;
+pub use intersection::{
+    intersect_curve_surface, intersect_curves, IntersectableCurve, IntersectableSurface,
+};
 mod knot_vec;
 mod nurbscurve;
 mod nurbssurface;
+mod projection;
+pub use projection::{project_curve_to_surface, ProjectPoint, Projection};
 
 #[doc(hidden)]
 #[inline(always)]
@@ -134,6 +222,68 @@ pub const fn inv_or_zero(delta: f64) -> f64 {
     }
 }
 
+impl<V> Default for FittingOptions<V> {
+    fn default() -> Self {
+        Self {
+            parametrization: Parametrization::default(),
+            front_derivation: None,
+            back_derivation: None,
+        }
+    }
+}
+
+impl Parametrization {
+    /// Returns the parameters of `points` normalized in `[0, 1]`.
+    /// Returns `None` if all points are the same.
+    fn parameters<P: MetricSpace<Metric = f64> + Copy>(self, points: &[P]) -> Option<Vec<f64>> {
+        let lens = points
+            .windows(2)
+            .map(|p| match self {
+                Parametrization::ChordLength => p[0].distance(p[1]),
+                Parametrization::Centripetal => f64::sqrt(p[0].distance(p[1])),
+            })
+            .collect::<Vec<_>>();
+        let total = lens.iter().sum::<f64>();
+        if total.so_small() {
+            return None;
+        }
+        let mut sum = 0.0;
+        let mut params = vec![0.0];
+        lens.iter().for_each(|len| {
+            sum += len;
+            params.push(sum / total);
+        });
+        Some(params)
+    }
+}
+
+/// The average of the parameters of the rows, skipping the degenerate ones.
+fn average_parameters(params: impl Iterator<Item = Option<Vec<f64>>>) -> Option<Vec<f64>> {
+    let mut count = 0;
+    let sum = params.flatten().reduce(|mut sum, params| {
+        sum.iter_mut().zip(params).for_each(|(s, t)| *s += t);
+        count += 1;
+        sum
+    })?;
+    Some(sum.into_iter().map(|s| s / (count + 1) as f64).collect())
+}
+
+/// The clamped knot vector for the least squares fitting with `len` control points,
+/// whose every knot span contains some parameters. cf) The NURBS Book, (9.68).
+fn averaging_knot(params: &[f64], degree: usize, len: usize) -> KnotVec {
+    let m = params.len() - 1;
+    let d = (m + 1) as f64 / (len - degree) as f64;
+    let mut knots = vec![0.0; degree + 1];
+    (1..len - degree).for_each(|j| {
+        let jd = j as f64 * d;
+        let i = usize::clamp(jd as usize, 1, m);
+        let alpha = f64::clamp(jd - i as f64, 0.0, 1.0);
+        knots.push((1.0 - alpha) * params[i - 1] + alpha * params[i]);
+    });
+    knots.extend(std::iter::repeat_n(1.0, degree + 1));
+    KnotVec(knots)
+}
+
 // This code is modified version of https://the-algorithms.com/algorithm/gaussian-elimination?lang=rust
 mod gaussian_elimination {
     use truck_base::cgmath64::cgmath::BaseFloat;
@@ -193,3 +343,95 @@ mod gaussian_elimination {
         }
     }
 }
+
+/// The Cholesky decomposition of the symmetric positive definite band matrices,
+/// which is factored once and reused for all the coordinates of the control points.
+mod band_matrix {
+    /// The lower triangular part of a symmetric band matrix.
+    /// `entries[i][k]` is the `(i, i - k)` entry for `k <= bandwidth`.
+    #[derive(Clone, Debug)]
+    pub struct SymmetricBandMatrix {
+        bandwidth: usize,
+        entries: Vec<Vec<f64>>,
+    }
+
+    /// The relative threshold of the pivots, under which the matrix is regarded as singular.
+    const SINGULAR_RATIO: f64 = 1.0e-12;
+
+    impl SymmetricBandMatrix {
+        /// Creates the zero matrix.
+        pub fn new(size: usize, bandwidth: usize) -> Self {
+            Self {
+                bandwidth,
+                entries: vec![vec![0.0; bandwidth + 1]; size],
+            }
+        }
+
+        /// Adds `x` to the `(i, j)` entry. The entries in the upper triangular part are ignored,
+        /// since they are added as the symmetric ones.
+        /// # Panics
+        /// `i - j` must be less than or equal to the bandwidth.
+        #[inline(always)]
+        pub fn add(&mut self, i: usize, j: usize, x: f64) {
+            if j <= i {
+                self.entries[i][i - j] += x;
+            }
+        }
+
+        /// Returns the Cholesky factor `L` of `self = L L^T` in the same storage,
+        /// or `None` if the matrix is not positive definite.
+        pub fn cholesky(mut self) -> Option<Self> {
+            let bandwidth = self.bandwidth;
+            for i in 0..self.entries.len() {
+                let start = i.saturating_sub(bandwidth);
+                for j in start..=i {
+                    let sum = (start..j).fold(self.entries[i][i - j], |sum, l| {
+                        sum - self.entries[i][i - l] * self.entries[j][j - l]
+                    });
+                    if i != j {
+                        self.entries[i][i - j] = sum / self.entries[j][0];
+                    } else if sum > self.entries[i][0] * SINGULAR_RATIO && sum > 0.0 {
+                        self.entries[i][0] = f64::sqrt(sum);
+                    } else {
+                        return None;
+                    }
+                }
+            }
+            Some(self)
+        }
+
+        /// Solves `L L^T x = rhs`, where `self` is the Cholesky factor `L`.
+        pub fn solve(&self, mut rhs: Vec<f64>) -> Vec<f64> {
+            let (size, bandwidth) = (self.entries.len(), self.bandwidth);
+            (0..size).for_each(|i| {
+                let start = i.saturating_sub(bandwidth);
+                let sum = (start..i).fold(rhs[i], |sum, l| sum - self.entries[i][i - l] * rhs[l]);
+                rhs[i] = sum / self.entries[i][0];
+            });
+            (0..size).rev().for_each(|i| {
+                let end = usize::min(size, i + bandwidth + 1);
+                let sum = (i + 1..end).fold(rhs[i], |sum, l| sum - self.entries[l][l - i] * rhs[l]);
+                rhs[i] = sum / self.entries[i][0];
+            });
+            rhs
+        }
+    }
+
+    #[test]
+    fn band_cholesky_test() {
+        // the tridiagonal matrix with 2 on the diagonal and -1 on the others
+        let mut matrix = SymmetricBandMatrix::new(5, 1);
+        (0..5).for_each(|i| {
+            matrix.add(i, i, 2.0);
+            if i > 0 {
+                matrix.add(i, i - 1, -1.0);
+            }
+        });
+        let factor = matrix.cholesky().unwrap();
+        let x = factor.solve(vec![1.0, 0.0, 0.0, 0.0, 1.0]);
+        x.iter().for_each(|x| assert!((x - 1.0).abs() < 1.0e-12));
+
+        let singular = SymmetricBandMatrix::new(3, 1);
+        assert!(singular.cholesky().is_none());
+    }
+}
@@ -546,6 +546,47 @@ where M: Copy + std::ops::Mul<V, Output = V>
     }
 }
 
+impl<V> ToNurbs<D1> for NurbsCurve<V>
+where V: Homogeneous<Scalar = f64> + ControlPoint<f64, Diff = V> + Tolerance
+{
+    type Range = (f64, f64);
+    type Nurbs = Self;
+    fn to_nurbs_in(&self, (t0, t1): (f64, f64)) -> Option<Self> {
+        let (front, back) = self.range_tuple();
+        if t1 - t0 < TOLERANCE || t0 < front - TOLERANCE || back + TOLERANCE < t1 {
+            return None;
+        }
+        let mut curve = self.clone();
+        if t1 < back - TOLERANCE {
+            curve.cut(t1);
+        }
+        if front + TOLERANCE < t0 {
+            curve = curve.cut(t0);
+        }
+        Some(curve)
+    }
+    #[inline(always)]
+    fn to_nurbs(&self) -> Option<Self> { Some(self.clone()) }
+}
+
+macro_rules! impl_to_nurbs_for_bspcurve {
+    ($point: ty, $homog: ty) => {
+        impl ToNurbs<D1> for BSplineCurve<$point> {
+            type Range = (f64, f64);
+            type Nurbs = NurbsCurve<$homog>;
+            #[inline(always)]
+            fn to_nurbs_in(&self, range: (f64, f64)) -> Option<NurbsCurve<$homog>> {
+                NurbsCurve::from(self.clone()).to_nurbs_in(range)
+            }
+            #[inline(always)]
+            fn to_nurbs(&self) -> Option<NurbsCurve<$homog>> { Some(self.clone().into()) }
+        }
+    };
+}
+
+impl_to_nurbs_for_bspcurve!(Point2, Vector3);
+impl_to_nurbs_for_bspcurve!(Point3, Vector4);
+
 impl<V: Homogeneous<Scalar = f64>> From<BSplineCurve<V::Point>> for NurbsCurve<V> {
     #[inline(always)]
     fn from(bspcurve: BSplineCurve<V::Point>) -> NurbsCurve<V> {
@@ -628,6 +628,14 @@ where V::Point: MetricSpace<Metric = f64> + HashGen<f64>
 
 impl<V> BoundedSurface for NurbsSurface<V> where Self: ParametricSurface {}
 
+impl<V: ControlPoint<f64> + Tolerance> ConcatSurface for NurbsSurface<V> {
+    /// Concats two NURBS surfaces whose homogeneous control points coincide along the common boundary.
+    #[inline(always)]
+    fn try_concat_surface(&self, rhs: &Self) -> Option<Self> {
+        Some(NurbsSurface::new(self.0.try_concat_surface(&rhs.0)?))
+    }
+}
+
 impl IncludeCurve<NurbsCurve<Vector3>> for NurbsSurface<Vector3> {
     #[inline(always)]
     fn include(&self, curve: &NurbsCurve<Vector3>) -> bool {
@@ -793,6 +801,116 @@ where
     }
 }
 
+impl<V: Homogeneous<Scalar = f64>> NurbsSurface<V>
+where V::Point: ControlPoint<f64> + MetricSpace<Metric = f64>
+{
+    /// Fits a NURBS surface with the unit weights to the grid of `points`.
+    /// cf) [`BSplineSurface::fit_grid`]
+    /// # Examples
+    /// ```
+    /// use truck_geometry::prelude::*;
+    /// let points = (0..=10)
+    ///     .map(|i| {
+    ///         (0..=10)
+    ///             .map(|j| {
+    ///                 let (x, y) = (i as f64 / 10.0, j as f64 / 10.0);
+    ///                 Point3::new(x, y, x * y)
+    ///             })
+    ///             .collect()
+    ///     })
+    ///     .collect::<Vec<Vec<_>>>();
+    /// let (surface, deviation) =
+    ///     NurbsSurface::<Vector4>::fit_grid(&points, (2, 2), 1.0e-6, Default::default()).unwrap();
+    /// assert!(deviation.max < 1.0e-6);
+    /// assert_near!(surface.subs(0.5, 0.5), Point3::new(0.5, 0.5, 0.25));
+    /// ```
+    pub fn fit_grid(
+        points: &[Vec<V::Point>],
+        degrees: (usize, usize),
+        tol: f64,
+        options: SurfaceFittingOptions,
+    ) -> Option<(Self, FittingDeviation)> {
+        BSplineSurface::fit_grid(points, degrees, tol, options)
+            .map(|(surface, deviation)| (surface.into(), deviation))
+    }
+
+    /// Fits a NURBS surface with the unit weights to `parameter_points`.
+    /// cf) [`BSplineSurface::fit_parameter_points`]
+    pub fn fit_parameter_points(
+        parameter_points: &[((f64, f64), V::Point)],
+        degrees: (usize, usize),
+        tol: f64,
+        options: SurfaceFittingOptions,
+    ) -> Option<(Self, FittingDeviation)> {
+        BSplineSurface::fit_parameter_points(parameter_points, degrees, tol, options)
+            .map(|(surface, deviation)| (surface.into(), deviation))
+    }
+}
+
+impl NurbsSurface<Vector4> {
+    /// Fits a NURBS surface with the unit weights to the unorganized `points`.
+    /// cf) [`BSplineSurface::fit_scattered`]
+    pub fn fit_scattered(
+        points: &[Point3],
+        degrees: (usize, usize),
+        tol: f64,
+        options: SurfaceFittingOptions,
+    ) -> Option<(Self, FittingDeviation)> {
+        BSplineSurface::fit_scattered(points, degrees, tol, options)
+            .map(|(surface, deviation)| (surface.into(), deviation))
+    }
+}
+
+impl<V> ToNurbs<D2> for NurbsSurface<V>
+where V: Homogeneous<Scalar = f64> + ControlPoint<f64, Diff = V> + Tolerance
+{
+    type Range = ((f64, f64), (f64, f64));
+    type Nurbs = Self;
+    fn to_nurbs_in(&self, ((u0, u1), (v0, v1)): ((f64, f64), (f64, f64))) -> Option<Self> {
+        let ((ufront, uback), (vfront, vback)) = self.range_tuple();
+        let is_out = |t0: f64, t1: f64, front: f64, back: f64| {
+            t1 - t0 < TOLERANCE || t0 < front - TOLERANCE || back + TOLERANCE < t1
+        };
+        if is_out(u0, u1, ufront, uback) || is_out(v0, v1, vfront, vback) {
+            return None;
+        }
+        let mut surface = self.clone();
+        if u1 < uback - TOLERANCE {
+            surface.ucut(u1);
+        }
+        if ufront + TOLERANCE < u0 {
+            surface = surface.ucut(u0);
+        }
+        if v1 < vback - TOLERANCE {
+            surface.vcut(v1);
+        }
+        if vfront + TOLERANCE < v0 {
+            surface = surface.vcut(v0);
+        }
+        Some(surface)
+    }
+    #[inline(always)]
+    fn to_nurbs(&self) -> Option<Self> { Some(self.clone()) }
+}
+
+macro_rules! impl_to_nurbs_for_bspsurface {
+    ($point: ty, $homog: ty) => {
+        impl ToNurbs<D2> for BSplineSurface<$point> {
+            type Range = ((f64, f64), (f64, f64));
+            type Nurbs = NurbsSurface<$homog>;
+            #[inline(always)]
+            fn to_nurbs_in(&self, range: ((f64, f64), (f64, f64))) -> Option<NurbsSurface<$homog>> {
+                NurbsSurface::from(self.clone()).to_nurbs_in(range)
+            }
+            #[inline(always)]
+            fn to_nurbs(&self) -> Option<NurbsSurface<$homog>> { Some(self.clone().into()) }
+        }
+    };
+}
+
+impl_to_nurbs_for_bspsurface!(Point2, Vector3);
+impl_to_nurbs_for_bspsurface!(Point3, Vector4);
+
 impl<V: Homogeneous<Scalar = f64>> From<BSplineSurface<V::Point>> for NurbsSurface<V> {
     fn from(bsp: BSplineSurface<V::Point>) -> Self {
         let control_points = bsp
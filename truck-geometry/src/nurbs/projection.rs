@@ -0,0 +1,629 @@
+use super::{intersection::*, *};
+
+// pieces are divided until their thicknesses are within this ratio of their sizes.
+const NEWTON_FLATNESS_RATIO: f64 = 0.1;
+const CURVE_PROJECTION_REFINEMENTS: usize = 16;
+
+/// A local minimum of the distance from a point to a curve or a surface, cf. [`ProjectPoint`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Projection<T> {
+    /// the parameter of the foot point
+    pub parameter: T,
+    /// the foot point
+    pub point: Point3,
+    /// the distance between the point and the foot point
+    pub distance: f64,
+}
+
+/// Projection of points to curves and surfaces.
+///
+/// Unlike [`SearchNearestParameter`], this trait does not require any hint.
+/// The geometry is subdivided into Bezier pieces of its NURBS representation, cf. [`ToNurbs`],
+/// or into the polyline or grid of the parameter division if there is no NURBS representation,
+/// and Newton's method is applied on each piece.
+/// The pieces whose bounding boxes are farther than the nearest point found so far are culled,
+/// and the others are subdivided until the global minimum is determined within the tolerance.
+///
+/// # Examples
+/// ```
+/// use truck_geometry::prelude::*;
+/// // a wavy curve
+/// let curve = BSplineCurve::new(
+///     KnotVec::uniform_knot(3, 4),
+///     vec![
+///         Point3::new(0.0, 0.0, 0.0),
+///         Point3::new(1.0, 1.0, 0.0),
+///         Point3::new(2.0, -1.0, 0.0),
+///         Point3::new(3.0, 1.0, 0.0),
+///         Point3::new(4.0, -1.0, 0.0),
+///         Point3::new(5.0, 1.0, 0.0),
+///         Point3::new(6.0, 0.0, 0.0),
+///     ],
+/// );
+/// let point = Point3::new(3.0, -5.0, 0.0);
+/// let projections = curve.project_point(point, TOLERANCE);
+/// assert!(projections.len() > 1);
+///
+/// // the first one is the global minimum.
+/// let global = projections[0];
+/// assert_near!(curve.subs(global.parameter), global.point);
+/// (0..=1000).for_each(|i| {
+///     let t = i as f64 / 1000.0;
+///     assert!(global.distance <= curve.subs(t).distance(point) + TOLERANCE);
+/// });
+/// ```
+pub trait ProjectPoint<D: SPDimension> {
+    /// the parameter of the curve `f64` or the surface `(f64, f64)`
+    type Parameter;
+    /// Returns the local minima of the distance from `point`, sorted by the distance.
+    ///
+    /// The ends of curves are included if they are local minima.
+    /// For surfaces, the local minima in the interior of the domain are returned,
+    /// and the nearest point on the boundary is added only if it is the global minimum.
+    ///
+    /// # Panics
+    ///
+    /// `tol` must be greater than or equal to `TOLERANCE`.
+    fn project_point(&self, point: Point3, tol: f64) -> Vec<Projection<Self::Parameter>>;
+}
+
+impl<C: IntersectableCurve> ProjectPoint<D1> for C {
+    type Parameter = f64;
+    fn project_point(&self, point: Point3, tol: f64) -> Vec<Projection<f64>> {
+        nonpositive_tolerance!(tol);
+        let curve = self;
+        let range = curve.range_tuple();
+        let mut leaves = Vec::new();
+        CurvePiece::decompose(curve, tol)
+            .into_iter()
+            .for_each(|piece| flat_leaves(piece, tol, 0, &mut leaves));
+        // the points of the pieces are on the curve, but the parameters may be different.
+        let to_curve_parameter = |(t, pt): (f64, Point3)| {
+            curve
+                .search_nearest_parameter(pt, Some(t), INTERSECTION_TRIALS)
+                .unwrap_or(t)
+        };
+        let projection = |t: f64| {
+            let pt = curve.subs(t);
+            Projection {
+                parameter: t,
+                point: pt,
+                distance: pt.distance(point),
+            }
+        };
+
+        let mut candidates = leaves
+            .iter()
+            .filter_map(|leaf| {
+                let hint = to_curve_parameter(leaf.middle());
+                let t = curve.search_nearest_parameter(point, Some(hint), INTERSECTION_TRIALS)?;
+                let t = fit_into_range(t, range, curve.period())?;
+                // the second derivation of the half of the squared distance
+                let der2 = curve.der(t).magnitude2() + (curve.subs(t) - point).dot(curve.der2(t));
+                match der2 >= -TOLERANCE {
+                    true => Some(projection(t)),
+                    false => None,
+                }
+            })
+            .collect::<Vec<_>>();
+        let front_der = (curve.front() - point).dot(curve.der(range.0));
+        let back_der = (curve.back() - point).dot(curve.der(range.1));
+        match curve.front().near(&curve.back()) {
+            true if front_der > 0.0 && back_der < 0.0 => candidates.push(projection(range.0)),
+            true => {}
+            false => {
+                if front_der > 0.0 {
+                    candidates.push(projection(range.0));
+                }
+                if back_der < 0.0 {
+                    candidates.push(projection(range.1));
+                }
+            }
+        }
+
+        let bound = candidates
+            .iter()
+            .fold(f64::INFINITY, |d, x| d.min(x.distance));
+        let mut nearest = (bound, None);
+        leaves
+            .iter()
+            .for_each(|leaf| branch_and_bound(leaf, point, tol, 0, &mut nearest));
+        if let (_, Some(hint)) = nearest {
+            let hint = to_curve_parameter(hint);
+            let t = curve
+                .search_nearest_parameter(point, Some(hint), INTERSECTION_TRIALS)
+                .and_then(|t| fit_into_range(t, range, curve.period()))
+                .filter(|t| curve.subs(*t).distance(point) < curve.subs(hint).distance(point))
+                .unwrap_or(hint);
+            candidates.push(projection(t));
+        }
+        unique_projections(candidates, tol)
+    }
+}
+
+impl<S: IntersectableSurface> ProjectPoint<D2> for S {
+    type Parameter = (f64, f64);
+    fn project_point(&self, point: Point3, tol: f64) -> Vec<Projection<(f64, f64)>> {
+        nonpositive_tolerance!(tol);
+        let surface = self;
+        let (urange, vrange) = surface.range_tuple();
+        let fit_into_ranges = |(u, v): (f64, f64)| {
+            let u = fit_into_range(u, urange, surface.u_period())?;
+            let v = fit_into_range(v, vrange, surface.v_period())?;
+            Some((u, v))
+        };
+        let mut leaves = Vec::new();
+        SurfacePiece::decompose(surface, tol)
+            .into_iter()
+            .for_each(|piece| flat_leaves(piece, tol, 0, &mut leaves));
+        // the points of the pieces are on the surface, but the parameters may be different.
+        let to_surface_parameter = |(uv, pt): ((f64, f64), Point3)| {
+            surface
+                .search_nearest_parameter(pt, Some(uv), INTERSECTION_TRIALS)
+                .unwrap_or(uv)
+        };
+        let projection = |(u, v): (f64, f64)| {
+            let pt = surface.subs(u, v);
+            Projection {
+                parameter: (u, v),
+                point: pt,
+                distance: pt.distance(point),
+            }
+        };
+
+        let mut candidates = leaves
+            .iter()
+            .filter_map(|leaf| {
+                let hint = to_surface_parameter(leaf.middle());
+                let uv =
+                    surface.search_nearest_parameter(point, Some(hint), INTERSECTION_TRIALS)?;
+                let (u, v) = fit_into_ranges(uv)?;
+                // the hessian of the half of the squared distance
+                let diff = surface.subs(u, v) - point;
+                let (su, sv) = (surface.uder(u, v), surface.vder(u, v));
+                let h00 = su.dot(su) + diff.dot(surface.uuder(u, v));
+                let h01 = su.dot(sv) + diff.dot(surface.uvder(u, v));
+                let h11 = sv.dot(sv) + diff.dot(surface.vvder(u, v));
+                match h00 + h11 >= -TOLERANCE && h00 * h11 - h01 * h01 >= -TOLERANCE {
+                    true => Some(projection((u, v))),
+                    false => None,
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let bound = candidates
+            .iter()
+            .fold(f64::INFINITY, |d, x| d.min(x.distance));
+        let mut nearest = (bound, None);
+        leaves
+            .iter()
+            .for_each(|leaf| branch_and_bound(leaf, point, tol, 0, &mut nearest));
+        if let (_, Some(hint)) = nearest {
+            let hint = to_surface_parameter(hint);
+            let distance = |(u, v): (f64, f64)| surface.subs(u, v).distance(point);
+            let uv = surface
+                .search_nearest_parameter(point, Some(hint), INTERSECTION_TRIALS)
+                .and_then(fit_into_ranges)
+                .filter(|uv| distance(*uv) < distance(hint))
+                .unwrap_or(hint);
+            candidates.push(projection(uv));
+        }
+        unique_projections(candidates, tol)
+    }
+}
+
+/// Projects `curve` to `surface` and returns the parameter curve on the surface.
+///
+/// The parameter curve is a cubic B-spline curve whose parameter range is the one of `curve`,
+/// and `pcurve.subs(t)` is the foot of the perpendicular from `curve.subs(t)` within `tol`.
+/// The parameters are continued across the seams of the periodic surfaces, cf. `u_period` and `v_period`,
+/// and the indeterminate parameters at the poles are determined by the neighboring ones.
+///
+/// The second returned value is the parameter ranges of `curve` where the curve is farther than `tol`
+/// from the surface, e.g. the curve runs off the boundary of the surface.
+///
+/// Returns `None` if the projection or the fitting of the parameter curve fails.
+///
+/// # Examples
+/// ```
+/// use std::f64::consts::PI;
+/// use truck_geometry::prelude::*;
+/// let sphere = Sphere::new(Point3::origin(), 1.0);
+/// // the latitude circle, which starts and ends at the seam
+/// let circle = Processor::with_transform(
+///     TrimmedCurve::new(UnitCircle::<Point3>::new(), (0.0, 2.0 * PI)),
+///     Matrix4::from_translation(Vector3::new(0.0, 0.0, 0.5))
+///         * Matrix4::from_scale(f64::sqrt(3.0) / 2.0),
+/// );
+/// let (pcurve, off_ranges) = project_curve_to_surface(&circle, sphere, 1.0e-4).unwrap();
+/// assert!(off_ranges.is_empty());
+/// assert_eq!(pcurve.range_tuple(), (0.0, 2.0 * PI));
+///
+/// let v0 = pcurve.curve().front().y;
+/// (0..=10).for_each(|i| {
+///     let t = 2.0 * PI * i as f64 / 10.0;
+///     assert!(pcurve.subs(t).distance(circle.subs(t)) < 1.0e-4);
+///     // the parameter curve goes across the seam continuously.
+///     let uv = pcurve.curve().subs(t);
+///     assert!(uv.distance(Point2::new(PI / 3.0, v0 + t)) < 1.0e-3);
+/// });
+/// ```
+///
+/// # Panics
+///
+/// `tol` must be greater than or equal to `TOLERANCE`.
+#[allow(clippy::type_complexity)]
+pub fn project_curve_to_surface<C, S>(
+    curve: &C,
+    surface: S,
+    tol: f64,
+) -> Option<(PCurve<BSplineCurve<Point2>, S>, Vec<(f64, f64)>)>
+where
+    C: ParametricCurve3D + BoundedCurve + ParameterDivision1D<Point = Point3>,
+    S: IntersectableSurface,
+{
+    nonpositive_tolerance!(tol);
+    let sample = |t: f64, hint: Option<Point2>| {
+        let uv = foot_parameter(&surface, curve.subs(t), hint, tol)?;
+        let on = curve.subs(t).distance(surface.subs(uv.x, uv.y)) <= tol;
+        Some(FootSample { t, uv, on })
+    };
+    let (params, _) = curve.parameter_division(curve.range_tuple(), tol);
+    let mut samples = Vec::<FootSample>::new();
+    for t in params.windows(2).flat_map(|x| [x[0], (x[0] + x[1]) / 2.0]) {
+        samples.push(sample(t, samples.last().map(|x| x.uv))?);
+    }
+    let t = params[params.len() - 1];
+    samples.push(sample(t, samples.last().map(|x| x.uv))?);
+
+    // the last parameter on the surface between the samples on and off the surface.
+    let transition = |on: FootSample, off: FootSample| {
+        let (mut t_on, mut t_off) = (on.t, off.t);
+        for _ in 0..BISECTION_TRIALS {
+            let t = (t_on + t_off) / 2.0;
+            match sample(t, Some(on.uv)) {
+                Some(x) if x.on => t_on = t,
+                _ => t_off = t,
+            }
+            if (t_off - t_on).abs() < TOLERANCE {
+                break;
+            }
+        }
+        t_on
+    };
+
+    for _ in 0..CURVE_PROJECTION_REFINEMENTS {
+        determine_pole_parameters(&surface, &mut samples, tol);
+        let bsp = fit_parameter_curve(&surface, &samples, tol)?;
+        let mut refined = Vec::with_capacity(samples.len() * 2);
+        for x in samples.windows(2) {
+            refined.push(x[0]);
+            if x[0].on != x[1].on {
+                let (on, off) = if x[0].on { (x[0], x[1]) } else { (x[1], x[0]) };
+                let t = transition(on, off);
+                if (t - on.t).abs() > TOLERANCE {
+                    refined.push(sample(t, Some(on.uv))?);
+                }
+            } else {
+                let mid = sample((x[0].t + x[1].t) / 2.0, Some(x[0].uv.midpoint(x[1].uv)))?;
+                let pt = bsp.subs(mid.t);
+                let dist = surface
+                    .subs(pt.x, pt.y)
+                    .distance(surface.subs(mid.uv.x, mid.uv.y));
+                if mid.on != x[0].on || (mid.on && dist > tol) {
+                    refined.push(mid);
+                }
+            }
+        }
+        refined.push(samples[samples.len() - 1]);
+        if refined.len() == samples.len() {
+            let mut off_ranges = Vec::new();
+            let mut start = None;
+            samples
+                .iter()
+                .enumerate()
+                .for_each(|(i, x)| match (x.on, start) {
+                    (false, None) => start = Some(if i == 0 { x.t } else { samples[i - 1].t }),
+                    (true, Some(t0)) => {
+                        off_ranges.push((t0, x.t));
+                        start = None;
+                    }
+                    _ => {}
+                });
+            if let Some(t0) = start {
+                off_ranges.push((t0, samples[samples.len() - 1].t));
+            }
+            return Some((PCurve::new(bsp, surface), off_ranges));
+        }
+        samples = refined;
+    }
+    None
+}
+
+/// The parameter `uv` of the foot of the perpendicular from the point at `t` on the curve.
+#[derive(Clone, Copy, Debug)]
+struct FootSample {
+    t: f64,
+    uv: Point2,
+    // the curve is on the surface within the tolerance.
+    on: bool,
+}
+
+/// Returns the parameter of the foot of the perpendicular from `point` in the domain,
+/// continued from `hint` across the seams.
+fn foot_parameter<S: IntersectableSurface>(
+    surface: &S,
+    point: Point3,
+    hint: Option<Point2>,
+    tol: f64,
+) -> Option<Point2> {
+    let local = hint.and_then(|hint| {
+        surface.search_nearest_parameter(point, Some((hint.x, hint.y)), INTERSECTION_TRIALS)
+    });
+    let (u, v) = match local {
+        Some(uv) => uv,
+        None => surface.project_point(point, tol).first()?.parameter,
+    };
+    // Newton's method may run off the boundary of the surface.
+    let clamped = |x: f64, range: Option<(f64, f64)>, period: Option<f64>| match (range, period) {
+        (Some((x0, x1)), None) => f64::clamp(x, x0, x1),
+        _ => x,
+    };
+    let (urange, vrange) = surface.try_range_tuple();
+    let (u, v) = (
+        clamped(u, urange, surface.u_period()),
+        clamped(v, vrange, surface.v_period()),
+    );
+    let continued = |x: f64, hint: f64, period: Option<f64>| match period {
+        Some(p) if hint.is_finite() => x - p * ((x - hint) / p).round(),
+        _ => x,
+    };
+    Some(match hint {
+        Some(hint) => Point2::new(
+            continued(u, hint.x, surface.u_period()),
+            continued(v, hint.y, surface.v_period()),
+        ),
+        None => Point2::new(u, v),
+    })
+}
+
+/// Replaces the indeterminate parameters at the poles, which move the point less than `tol`,
+/// by the interpolation of the neighboring ones.
+fn determine_pole_parameters<S: ParametricSurface3D>(
+    surface: &S,
+    samples: &mut [FootSample],
+    tol: f64,
+) {
+    let degenerate = samples
+        .iter()
+        .map(|FootSample { uv, .. }| {
+            let (u, v) = (uv.x, uv.y);
+            let indeterminate = |x: f64, der: Vector3| !x.is_finite() || der.magnitude() <= tol;
+            [
+                indeterminate(u, surface.uder(u, v)),
+                indeterminate(v, surface.vder(u, v)),
+            ]
+        })
+        .collect::<Vec<_>>();
+    (0..2).for_each(|k| {
+        let determinate = (0..samples.len())
+            .filter(|i| !degenerate[*i][k])
+            .collect::<Vec<_>>();
+        if determinate.is_empty() {
+            return;
+        }
+        (0..samples.len())
+            .filter(|i| degenerate[*i][k])
+            .for_each(|i| {
+                let idx = determinate.partition_point(|j| *j < i);
+                samples[i].uv[k] = match (idx.checked_sub(1), determinate.get(idx)) {
+                    (Some(i0), Some(&i1)) => {
+                        let (x0, x1) = (samples[determinate[i0]], samples[i1]);
+                        let s = (samples[i].t - x0.t) / (x1.t - x0.t);
+                        x0.uv[k] * (1.0 - s) + x1.uv[k] * s
+                    }
+                    (Some(i0), None) => samples[determinate[i0]].uv[k],
+                    (None, Some(&i1)) => samples[i1].uv[k],
+                    (None, None) => unreachable!(),
+                };
+            });
+    });
+}
+
+/// Fits a cubic B-spline curve to `samples` by the least squares method, increasing the control points
+/// until the errors on `surface` are less than `tol` at the samples on the surface.
+fn fit_parameter_curve<S: ParametricSurface3D>(
+    surface: &S,
+    samples: &[FootSample],
+    tol: f64,
+) -> Option<BSplineCurve<Point2>> {
+    let degree = usize::min(3, samples.len() - 1);
+    let (t0, t1) = (samples[0].t, samples[samples.len() - 1].t);
+    let params = samples
+        .iter()
+        .map(|x| (x.t - t0) / (t1 - t0))
+        .collect::<Vec<_>>();
+    let points = samples.iter().map(|x| x.uv).collect::<Vec<_>>();
+    let mut len = degree + 1;
+    loop {
+        let knot_vec = averaging_knot(&params, degree, len);
+        let mut bsp =
+            BSplineCurve::least_squares(&points, &params, degree, knot_vec, (None, None))?;
+        let is_fitted = params.iter().zip(samples).all(|(s, x)| {
+            let pt = bsp.subs(*s);
+            !x.on
+                || surface
+                    .subs(pt.x, pt.y)
+                    .distance(surface.subs(x.uv.x, x.uv.y))
+                    <= tol
+        });
+        if is_fitted {
+            bsp.parameter_transform(t1 - t0, t0);
+            return Some(bsp);
+        } else if len == samples.len() {
+            return None;
+        }
+        len = usize::min(len + (len - degree).div_ceil(2), samples.len());
+    }
+}
+
+fn flat_leaves<P: OrientedBounded>(piece: P, tol: f64, depth: usize, leaves: &mut Vec<P>) {
+    if piece.is_roughly_flat(tol) || !piece.is_divisible() || depth >= MAX_SUBDIVISION_DEPTH {
+        leaves.push(piece);
+    } else {
+        let (piece0, piece1) = piece.split();
+        flat_leaves(piece0, tol, depth + 1, leaves);
+        flat_leaves(piece1, tol, depth + 1, leaves);
+    }
+}
+
+/// Updates `nearest`, the pair of the distance and the nearest point, by the points in `piece`.
+/// The pieces whose oriented boxes are farther than `nearest.0 - tol` are culled.
+#[allow(clippy::type_complexity)]
+fn branch_and_bound<P: OrientedBounded>(
+    piece: &P,
+    point: Point3,
+    tol: f64,
+    depth: usize,
+    nearest: &mut (f64, Option<(P::Parameter, Point3)>),
+) {
+    let lower_bound = piece.oriented_box().distance(point);
+    if lower_bound > nearest.0 - tol {
+        return;
+    }
+    let (param, pt) = piece.middle();
+    if pt.distance(point) < nearest.0 {
+        *nearest = (pt.distance(point), Some((param, pt)));
+    }
+    if lower_bound <= nearest.0 - tol && piece.is_divisible() && depth < MAX_SUBDIVISION_DEPTH {
+        let (piece0, piece1) = piece.split();
+        branch_and_bound(&piece0, point, tol, depth + 1, nearest);
+        branch_and_bound(&piece1, point, tol, depth + 1, nearest);
+    }
+}
+
+/// Pieces bounded by oriented boxes.
+///
+/// Axis-aligned bounding boxes are too loose near the foot points of tilted pieces.
+/// The boxes along the chords of curves or the tangent planes of surfaces are used instead,
+/// whose thicknesses are of second order in the sizes of the pieces.
+trait OrientedBounded: Piece {
+    fn oriented_box(&self) -> OrientedBox;
+    /// Returns `true` if the piece is flat enough for Newton's method started from its middle.
+    fn is_roughly_flat(&self, tol: f64) -> bool;
+}
+
+struct OrientedBox {
+    axes: [Vector3; 3],
+    intervals: [(f64, f64); 3],
+    margin: f64,
+}
+
+impl OrientedBox {
+    /// The first axis is the chord of curves, or the normal of surfaces.
+    fn new<'a>(points: impl IntoIterator<Item = &'a Point3>, axis: Vector3, margin: f64) -> Self {
+        let axis = match axis.so_small() {
+            true => Vector3::unit_x(),
+            false => axis.normalize(),
+        };
+        let (x, y, z) = (axis.x.abs(), axis.y.abs(), axis.z.abs());
+        let other = if x <= y && x <= z {
+            Vector3::unit_x()
+        } else if y <= z {
+            Vector3::unit_y()
+        } else {
+            Vector3::unit_z()
+        };
+        let axis1 = axis.cross(other).normalize();
+        let axes = [axis, axis1, axis.cross(axis1)];
+        let mut intervals = [(f64::INFINITY, f64::NEG_INFINITY); 3];
+        points.into_iter().for_each(|p| {
+            intervals
+                .iter_mut()
+                .zip(axes)
+                .for_each(|((min, max), axis)| {
+                    let x = EuclideanSpace::to_vec(*p).dot(axis);
+                    *min = f64::min(*min, x);
+                    *max = f64::max(*max, x);
+                });
+        });
+        Self {
+            axes,
+            intervals,
+            margin,
+        }
+    }
+
+    fn width(&self, i: usize) -> f64 {
+        self.intervals[i].1 - self.intervals[i].0 + 2.0 * self.margin
+    }
+
+    /// Returns a lower bound of the distance between `point` and the contents of the box.
+    fn distance(&self, point: Point3) -> f64 {
+        let dist2 = (self.intervals.iter().zip(self.axes)).fold(0.0, |sum, (&(min, max), axis)| {
+            let x = EuclideanSpace::to_vec(point).dot(axis);
+            let gap = f64::max(min - x, x - max).max(0.0);
+            sum + gap * gap
+        });
+        dist2.sqrt() - self.margin
+    }
+}
+
+impl OrientedBounded for CurvePiece {
+    fn oriented_box(&self) -> OrientedBox {
+        match self {
+            CurvePiece::Bezier(bezier) => {
+                let points = (bezier.control_points().iter())
+                    .map(|p| p.to_point())
+                    .collect::<Vec<_>>();
+                let chord = points[points.len() - 1] - points[0];
+                OrientedBox::new(&points, chord, 0.0)
+            }
+            CurvePiece::Polyline { points, margin, .. } => {
+                let chord = points[points.len() - 1] - points[0];
+                OrientedBox::new(points, chord, *margin)
+            }
+        }
+    }
+    fn is_roughly_flat(&self, tol: f64) -> bool {
+        let obb = self.oriented_box();
+        let thickness = f64::max(obb.width(1), obb.width(2));
+        thickness <= f64::max(tol, obb.width(0) * NEWTON_FLATNESS_RATIO)
+    }
+}
+
+impl OrientedBounded for SurfacePiece {
+    fn oriented_box(&self) -> OrientedBox {
+        let normal = |points: &Vec<Vec<Point3>>| {
+            let (m, n) = (points.len() - 1, points[0].len() - 1);
+            (points[m][n] - points[0][0]).cross(points[0][n] - points[m][0])
+        };
+        match self {
+            SurfacePiece::Bezier(bezier) => {
+                let points = (bezier.control_points().iter())
+                    .map(|row| row.iter().map(|p| p.to_point()).collect())
+                    .collect::<Vec<_>>();
+                OrientedBox::new(points.iter().flatten(), normal(&points), 0.0)
+            }
+            SurfacePiece::Grid { points, margin, .. } => {
+                OrientedBox::new(points.iter().flatten(), normal(points), *margin)
+            }
+        }
+    }
+    fn is_roughly_flat(&self, tol: f64) -> bool {
+        let obb = self.oriented_box();
+        let size = f64::max(obb.width(1), obb.width(2));
+        obb.width(0) <= f64::max(tol, size * NEWTON_FLATNESS_RATIO)
+    }
+}
+
+fn unique_projections<T>(mut candidates: Vec<Projection<T>>, tol: f64) -> Vec<Projection<T>> {
+    candidates.sort_by(|x, y| x.distance.partial_cmp(&y.distance).unwrap());
+    let mut res = Vec::<Projection<T>>::new();
+    candidates.into_iter().for_each(|x| {
+        if res.iter().all(|y| x.point.distance(y.point) > tol) {
+            res.push(x);
+        }
+    });
+    res
+}
@@ -179,3 +179,48 @@ impl ToSameGeometry<NurbsCurve<Vector4>> for TrimmedCurve<UnitCircle<Point3>> {
         curve
     }
 }
+
+impl ToNurbs<D1> for UnitCircle<Point2> {
+    type Range = (f64, f64);
+    type Nurbs = NurbsCurve<Vector3>;
+    /// The arc is divided into the quadratic segments whose central angles are at most the right angle.
+    fn to_nurbs_in(&self, (t0, t1): (f64, f64)) -> Option<NurbsCurve<Vector3>> {
+        let angle = t1 - t0;
+        if angle < TOLERANCE {
+            return None;
+        }
+        let division = f64::ceil(angle / (PI / 2.0) - TOLERANCE) as usize;
+        let mut knots = vec![t0; 3];
+        let mut control_points = vec![Vector3::new(f64::cos(t0), f64::sin(t0), 1.0)];
+        (0..division).for_each(|i| {
+            let a = t0 + angle * i as f64 / division as f64;
+            let b = t0 + angle * (i + 1) as f64 / division as f64;
+            let (m, h) = ((a + b) / 2.0, (b - a) / 2.0);
+            control_points.push(Vector3::new(f64::cos(m), f64::sin(m), f64::cos(h)));
+            control_points.push(Vector3::new(f64::cos(b), f64::sin(b), 1.0));
+            match i + 1 == division {
+                true => knots.extend([t1; 3]),
+                false => knots.extend([b; 2]),
+            }
+        });
+        Some(NurbsCurve::new(BSplineCurve::new_unchecked(
+            KnotVec::from(knots),
+            control_points,
+        )))
+    }
+    #[inline(always)]
+    fn to_nurbs(&self) -> Option<NurbsCurve<Vector3>> { self.to_nurbs_in((0.0, 2.0 * PI)) }
+}
+
+impl ToNurbs<D1> for UnitCircle<Point3> {
+    type Range = (f64, f64);
+    type Nurbs = NurbsCurve<Vector4>;
+    #[inline(always)]
+    fn to_nurbs_in(&self, range: (f64, f64)) -> Option<NurbsCurve<Vector4>> {
+        UnitCircle::<Point2>::new()
+            .to_nurbs_in(range)
+            .map(embed_nurbs)
+    }
+    #[inline(always)]
+    fn to_nurbs(&self) -> Option<NurbsCurve<Vector4>> { self.to_nurbs_in((0.0, 2.0 * PI)) }
+}
@@ -117,3 +117,38 @@ impl SearchParameter<D1> for UnitHyperbola<Point3> {
         }
     }
 }
+
+impl ToNurbs<D1> for UnitHyperbola<Point2> {
+    type Range = (f64, f64);
+    type Nurbs = NurbsCurve<Vector3>;
+    fn to_nurbs_in(&self, (t0, t1): (f64, f64)) -> Option<NurbsCurve<Vector3>> {
+        if t1 - t0 < TOLERANCE {
+            return None;
+        }
+        let (m, h) = ((t0 + t1) / 2.0, (t1 - t0) / 2.0);
+        let w = f64::cosh(h);
+        let points = [
+            Point2::new(f64::cosh(t0), f64::sinh(t0)),
+            Point2::new(f64::cosh(m) / w, f64::sinh(m) / w),
+            Point2::new(f64::cosh(t1), f64::sinh(t1)),
+        ];
+        Some(quadratic_rational_segment((t0, t1), points, w))
+    }
+    /// Always returns `None` since the hyperbola is unbounded.
+    #[inline(always)]
+    fn to_nurbs(&self) -> Option<NurbsCurve<Vector3>> { None }
+}
+
+impl ToNurbs<D1> for UnitHyperbola<Point3> {
+    type Range = (f64, f64);
+    type Nurbs = NurbsCurve<Vector4>;
+    #[inline(always)]
+    fn to_nurbs_in(&self, range: (f64, f64)) -> Option<NurbsCurve<Vector4>> {
+        UnitHyperbola::<Point2>::new()
+            .to_nurbs_in(range)
+            .map(embed_nurbs)
+    }
+    /// Always returns `None` since the hyperbola is unbounded.
+    #[inline(always)]
+    fn to_nurbs(&self) -> Option<NurbsCurve<Vector4>> { None }
+}
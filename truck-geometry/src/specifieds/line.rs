@@ -198,6 +198,31 @@ impl<P: Copy> ToSameGeometry<BSplineCurve<P>> for Line<P> {
     fn to_same_geometry(&self) -> BSplineCurve<P> { BSplineCurve::from(*self) }
 }
 
+macro_rules! impl_to_nurbs_for_line {
+    ($point: ty, $homog: ty) => {
+        impl ToNurbs<D1> for Line<$point> {
+            type Range = (f64, f64);
+            type Nurbs = NurbsCurve<$homog>;
+            fn to_nurbs_in(&self, (t0, t1): (f64, f64)) -> Option<NurbsCurve<$homog>> {
+                if t1 - t0 < TOLERANCE {
+                    return None;
+                }
+                let mut knot_vec = KnotVec::bezier_knot(1);
+                knot_vec.transform(t1 - t0, t0);
+                let control_points = vec![self.subs(t0), self.subs(t1)];
+                Some(BSplineCurve::new_unchecked(knot_vec, control_points).into())
+            }
+            #[inline(always)]
+            fn to_nurbs(&self) -> Option<NurbsCurve<$homog>> {
+                Some(BSplineCurve::from(*self).into())
+            }
+        }
+    };
+}
+
+impl_to_nurbs_for_line!(Point2, Vector3);
+impl_to_nurbs_for_line!(Point3, Vector4);
+
 #[test]
 fn line() {
     let line = Line(Point2::new(1.0, 0.0), Point2::new(0.0, 1.0));
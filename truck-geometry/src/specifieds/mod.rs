@@ -113,3 +113,29 @@ macro_rules! always_true {
 always_true!(UnitCircle);
 always_true!(UnitParabola);
 always_true!(UnitHyperbola);
+
+/// Embeds the planar NURBS curve into the xy-plane of the space.
+fn embed_nurbs(curve: NurbsCurve<Vector3>) -> NurbsCurve<Vector4> {
+    let (knot_vec, control_points) = curve.into_non_rationalized().destruct();
+    let control_points = control_points
+        .into_iter()
+        .map(|v| Vector4::new(v.x, v.y, 0.0, v.z))
+        .collect();
+    NurbsCurve::new(BSplineCurve::new_unchecked(knot_vec, control_points))
+}
+
+/// The quadratic rational Bezier curve whose control points are `p0`, `p1` and `p2` with the weights `1`, `w` and `1`.
+fn quadratic_rational_segment(
+    (t0, t1): (f64, f64),
+    [p0, p1, p2]: [Point2; 3],
+    w: f64,
+) -> NurbsCurve<Vector3> {
+    let mut knot_vec = KnotVec::bezier_knot(2);
+    knot_vec.transform(t1 - t0, t0);
+    let control_points = vec![
+        p0.to_vec().extend(1.0),
+        (p1.to_vec() * w).extend(w),
+        p2.to_vec().extend(1.0),
+    ];
+    NurbsCurve::new(BSplineCurve::new_unchecked(knot_vec, control_points))
+}
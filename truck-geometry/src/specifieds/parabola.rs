@@ -131,6 +131,38 @@ impl SearchParameter<D1> for UnitParabola<Point3> {
     }
 }
 
+impl ToNurbs<D1> for UnitParabola<Point2> {
+    type Range = (f64, f64);
+    type Nurbs = NurbsCurve<Vector3>;
+    fn to_nurbs_in(&self, (t0, t1): (f64, f64)) -> Option<NurbsCurve<Vector3>> {
+        if t1 - t0 < TOLERANCE {
+            return None;
+        }
+        let points = [
+            Point2::new(t0 * t0, 2.0 * t0),
+            Point2::new(t0 * t1, t0 + t1),
+            Point2::new(t1 * t1, 2.0 * t1),
+        ];
+        Some(quadratic_rational_segment((t0, t1), points, 1.0))
+    }
+    /// Always returns `None` since the parabola is unbounded.
+    #[inline(always)]
+    fn to_nurbs(&self) -> Option<NurbsCurve<Vector3>> { None }
+}
+
+impl ToNurbs<D1> for UnitParabola<Point3> {
+    type Range = (f64, f64);
+    type Nurbs = NurbsCurve<Vector4>;
+    #[inline(always)]
+    fn to_nurbs_in(&self, range: (f64, f64)) -> Option<NurbsCurve<Vector4>> {
+        UnitParabola::<Point2>::new()
+            .to_nurbs_in(range)
+            .map(embed_nurbs)
+    }
+    /// Always returns `None` since the parabola is unbounded.
+    #[inline(always)]
+    fn to_nurbs(&self) -> Option<NurbsCurve<Vector4>> { None }
+}
 #[test]
 fn snp_test() {
     let curve = UnitParabola::<Point2>::new();
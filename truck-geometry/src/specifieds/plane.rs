@@ -251,3 +251,26 @@ impl From<Plane> for BSplineSurface<Point3> {
 impl ToSameGeometry<BSplineSurface<Point3>> for Plane {
     fn to_same_geometry(&self) -> BSplineSurface<Point3> { (*self).into() }
 }
+
+impl ToNurbs<D2> for Plane {
+    type Range = ((f64, f64), (f64, f64));
+    type Nurbs = NurbsSurface<Vector4>;
+    fn to_nurbs_in(
+        &self,
+        ((u0, u1), (v0, v1)): ((f64, f64), (f64, f64)),
+    ) -> Option<NurbsSurface<Vector4>> {
+        if u1 - u0 < TOLERANCE || v1 - v0 < TOLERANCE {
+            return None;
+        }
+        let (mut uknot, mut vknot) = (KnotVec::bezier_knot(1), KnotVec::bezier_knot(1));
+        uknot.transform(u1 - u0, u0);
+        vknot.transform(v1 - v0, v0);
+        let control_points = vec![
+            vec![self.subs(u0, v0), self.subs(u0, v1)],
+            vec![self.subs(u1, v0), self.subs(u1, v1)],
+        ];
+        Some(BSplineSurface::new_unchecked((uknot, vknot), control_points).into())
+    }
+    #[inline(always)]
+    fn to_nurbs(&self) -> Option<NurbsSurface<Vector4>> { Some(BSplineSurface::from(*self).into()) }
+}
@@ -194,7 +194,7 @@ impl SearchNearestParameter<D2> for Sphere {
         let radius = (point - self.center).normalize();
         let u = f64::acos(radius[2]);
         let sinu = f64::sqrt(1.0 - radius[2] * radius[2]);
-        let cosv = radius[0] / sinu;
+        let cosv = f64::clamp(radius[0] / sinu, -1.0, 1.0);
         let v = if radius[1] > 0.0 {
             f64::acos(cosv)
         } else {
@@ -203,3 +203,27 @@ impl SearchNearestParameter<D2> for Sphere {
         Some((u, v))
     }
 }
+
+impl ToNurbs<D2> for Sphere {
+    type Range = ((f64, f64), (f64, f64));
+    type Nurbs = NurbsSurface<Vector4>;
+    /// The meridian is the circle arc in the xz-plane, and it is revolved around the z-axis.
+    fn to_nurbs_in(
+        &self,
+        (urange, vrange): ((f64, f64), (f64, f64)),
+    ) -> Option<NurbsSurface<Vector4>> {
+        let arc = UnitCircle::<Point2>::new().to_nurbs_in(urange)?;
+        let (c, r) = (self.center, self.radius);
+        let (knot_vec, control_points) = arc.into_non_rationalized().destruct();
+        let control_points = control_points
+            .into_iter()
+            .map(|v| Vector4::new(c.x * v.z + r * v.y, c.y * v.z, c.z * v.z + r * v.x, v.z))
+            .collect();
+        let meridian = NurbsCurve::new(BSplineCurve::new_unchecked(knot_vec, control_points));
+        RevolutedCurve::by_revolution(meridian, c, Vector3::unit_z()).to_nurbs_in((urange, vrange))
+    }
+    #[inline(always)]
+    fn to_nurbs(&self) -> Option<NurbsSurface<Vector4>> {
+        self.to_nurbs_in(((0.0, PI), (0.0, 2.0 * PI)))
+    }
+}
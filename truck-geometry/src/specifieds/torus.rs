@@ -201,3 +201,33 @@ impl ParameterDivision2D for Torus {
         (udiv, vdiv)
     }
 }
+
+impl ToNurbs<D2> for Torus {
+    type Range = ((f64, f64), (f64, f64));
+    type Nurbs = NurbsSurface<Vector4>;
+    /// The tube section is the circle arc in the xz-plane, and it is revolved around the z-axis.
+    fn to_nurbs_in(
+        &self,
+        (urange, vrange): ((f64, f64), (f64, f64)),
+    ) -> Option<NurbsSurface<Vector4>> {
+        let arc = UnitCircle::<Point2>::new().to_nurbs_in(vrange)?;
+        let (c, r0, r1) = (self.center, self.large_radius, self.small_radius);
+        let (knot_vec, control_points) = arc.into_non_rationalized().destruct();
+        let control_points = control_points
+            .into_iter()
+            .map(|v| {
+                let x = (c.x + r0) * v.z + r1 * v.x;
+                Vector4::new(x, c.y * v.z, c.z * v.z + r1 * v.y, v.z)
+            })
+            .collect();
+        let section = NurbsCurve::new(BSplineCurve::new_unchecked(knot_vec, control_points));
+        let revoluted = RevolutedCurve::by_revolution(section, c, Vector3::unit_z());
+        let mut surface = revoluted.to_nurbs_in((vrange, urange))?;
+        surface.swap_axes();
+        Some(surface)
+    }
+    #[inline(always)]
+    fn to_nurbs(&self) -> Option<NurbsSurface<Vector4>> {
+        self.to_nurbs_in(((0.0, 2.0 * PI), (0.0, 2.0 * PI)))
+    }
+}
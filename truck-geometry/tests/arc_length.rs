@@ -0,0 +1,63 @@
+use std::f64::consts::PI;
+use truck_geometry::prelude::*;
+
+#[test]
+fn circle_arc_length_ders() {
+    let circle = Processor::with_transform(
+        TrimmedCurve::new(UnitCircle::<Point2>::new(), (0.5, 2.0 * PI)),
+        Matrix3::from_scale(2.0),
+    );
+    let curve = ArcLengthReparametrized::new(circle, TOLERANCE);
+    assert_near!(curve.range_tuple().1, 2.0 * (2.0 * PI - 0.5));
+    (0..=10).for_each(|i| {
+        let s = curve.range_tuple().1 * i as f64 / 10.0;
+        let theta = 0.5 + s / 2.0;
+        let ders = curve.ders(4, s);
+        (0..=4).for_each(|k| {
+            let phi = theta + k as f64 * PI / 2.0;
+            let ans = Vector2::new(f64::cos(phi), f64::sin(phi)) * 2.0 * 0.5f64.powi(k);
+            assert_near!(ders[k as usize], ans);
+            assert_near!(curve.der_n(k as usize, s), ans);
+        });
+        assert_near!(curve.subs(s), Point2::from_vec(ders[0]));
+        assert_near!(curve.arc_length_parameter(curve.curve_parameter(s)), s);
+    });
+}
+
+#[test]
+fn arc_length_parameter_transform() {
+    let bsp = BSplineCurve::new(
+        KnotVec::bezier_knot(3),
+        vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 2.0, 0.0),
+            Point3::new(2.0, -1.0, 1.0),
+            Point3::new(4.0, 0.0, 0.0),
+        ],
+    );
+    let curve0 = ArcLengthReparametrized::new(bsp.clone(), TOLERANCE);
+    let length = curve0.range_tuple().1;
+    let curve1 = curve0.parameter_transformed(1.0 / length, 1.0);
+    assert_near!(curve1.range_tuple().0, 1.0);
+    assert_near!(curve1.range_tuple().1, 2.0);
+    (0..=10).for_each(|i| {
+        let s = i as f64 / 10.0;
+        assert_near!(curve1.subs(1.0 + s), curve0.subs(length * s));
+        assert_near!(curve1.der(1.0 + s), curve0.der(length * s) * length);
+        assert_near!(
+            curve1.der2(1.0 + s),
+            curve0.der2(length * s) * length * length
+        );
+    });
+
+    let pt = bsp.subs(0.3);
+    let s = curve1.search_nearest_parameter(pt, None, 100).unwrap();
+    assert_near!(curve1.subs(s), pt);
+    let s = curve1.search_parameter(pt, 1.5, 100).unwrap();
+    assert_near!(curve1.subs(s), pt);
+    let (params, pts) = curve1.parameter_division(curve1.range_tuple(), 0.01);
+    params
+        .iter()
+        .zip(&pts)
+        .for_each(|(s, p)| assert_near!(curve1.subs(*s), *p));
+}
@@ -0,0 +1,52 @@
+use truck_geometry::prelude::*;
+
+#[test]
+fn sphere_curvature() {
+    let sphere = Sphere::new(Point3::new(1.0, 2.0, 3.0), 2.0);
+    let (u, v) = (0.7, 1.2);
+    let forms = algo::surface::fundamental_forms(&sphere, u, v).unwrap();
+    assert_near!(forms.second, forms.first * -0.5);
+    assert_near!(
+        algo::surface::gaussian_curvature(&sphere, u, v).unwrap(),
+        0.25
+    );
+    assert_near!(algo::surface::mean_curvature(&sphere, u, v).unwrap(), -0.5);
+    let curvature = algo::surface::curvature(&sphere, u, v).unwrap();
+    assert_near!(curvature.principal_curvatures.0, -0.5);
+    assert_near!(curvature.principal_curvatures.1, -0.5);
+    let (d0, d1) = curvature.principal_directions;
+    assert_near!(d0.cross(d1), sphere.normal(u, v));
+}
+
+#[test]
+fn torus_curvature() {
+    let torus = Torus::new(Point3::new(-1.0, 0.5, 2.0), 3.0, 1.0);
+    (0..=10).for_each(|i| {
+        let (u, v) = (0.3 + i as f64 * 0.5, 0.1 + i as f64 * 0.6);
+        let ku = -f64::cos(v) / (3.0 + f64::cos(v));
+        let curvature = algo::surface::curvature(&torus, u, v).unwrap();
+        assert_near!(curvature.principal_curvatures.0, ku);
+        assert_near!(curvature.principal_curvatures.1, -1.0);
+        assert_near!(curvature.gaussian(), -ku);
+        assert_near!(curvature.mean(), (ku - 1.0) / 2.0);
+        let (d0, d1) = curvature.principal_directions;
+        assert_near!(d0.dot(torus.uder(u, v).normalize()).abs(), 1.0);
+        assert_near!(d1.dot(torus.vder(u, v).normalize()).abs(), 1.0);
+        assert_near!(d0.cross(d1), torus.normal(u, v));
+    });
+}
+
+#[test]
+fn zebra_stripes() {
+    let plane = Plane::new(
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+    let direction = Vector3::new(0.0, 1.0, 1.0);
+    let stripes =
+        algo::surface::zebra_stripes(&plane, ((0.0, 1.0), (0.0, 1.0)), (4, 4), direction, 0.1);
+    assert_eq!(stripes.len(), 5);
+    // the angle is PI / 4, which is in the 7th stripe.
+    assert!(stripes.iter().flatten().all(|b| !b));
+}
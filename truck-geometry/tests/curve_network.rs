@@ -0,0 +1,192 @@
+use truck_geometry::prelude::*;
+
+fn wavy_surface() -> BSplineSurface<Point3> {
+    BSplineSurface::new(
+        (KnotVec::uniform_knot(3, 3), KnotVec::uniform_knot(2, 3)),
+        (0..6)
+            .map(|i| {
+                (0..5)
+                    .map(|j| {
+                        let z = f64::sin(i as f64 * 1.3) * f64::cos(j as f64 * 0.7);
+                        Point3::new(i as f64, j as f64 + 0.2 * i as f64, z)
+                    })
+                    .collect()
+            })
+            .collect(),
+    )
+}
+
+/// the curve `surface(u, v)` along `u` direction
+fn uiso_curve(surface: &BSplineSurface<Point3>, v: f64) -> BSplineCurve<Point3> {
+    let (uknot_vec, vknot_vec) = surface.knot_vecs();
+    let bases = vknot_vec.bspline_basis_functions(surface.vdegree(), 0, v);
+    let control_points = surface
+        .control_points()
+        .iter()
+        .map(|row| {
+            let vec = row
+                .iter()
+                .zip(&bases)
+                .fold(Vector3::zero(), |sum, (p, b)| sum + p.to_vec() * *b);
+            Point3::from_vec(vec)
+        })
+        .collect();
+    BSplineCurve::new(uknot_vec.clone(), control_points)
+}
+
+/// the curve `surface(u, v)` along `v` direction
+fn viso_curve(surface: &BSplineSurface<Point3>, u: f64) -> BSplineCurve<Point3> {
+    let mut surface = surface.clone();
+    surface.swap_axes();
+    uiso_curve(&surface, u)
+}
+
+/// the derivation of the clamped curve at the end `t = 0` or `t = 1` by the control points
+fn end_derivation(curve: &BSplineCurve<Point3>, start: bool) -> Vector3 {
+    let (knot_vec, degree) = (curve.knot_vec(), curve.degree());
+    let n = curve.control_points().len() - 1;
+    match start {
+        true => {
+            let scalar = degree as f64 / (knot_vec[degree + 1] - knot_vec[1]);
+            (curve.control_point(1) - curve.control_point(0)) * scalar
+        }
+        false => {
+            let scalar = degree as f64 / (knot_vec[n + degree] - knot_vec[n]);
+            (curve.control_point(n) - curve.control_point(n - 1)) * scalar
+        }
+    }
+}
+
+/// the cross-boundary derivation along `v` direction at `v = 0` or `v = 1`
+fn vcross_derivation(surface: &BSplineSurface<Point3>, start: bool) -> BSplineCurve<Vector3> {
+    let control_points = (0..surface.control_points().len())
+        .map(|i| end_derivation(&surface.column_curve(i), start))
+        .collect();
+    BSplineCurve::new(surface.uknot_vec().clone(), control_points)
+}
+
+#[test]
+fn coons_g1_boundary() {
+    let surface = wavy_surface();
+    let mut swapped = surface.clone();
+    swapped.swap_axes();
+    let boundary = [
+        uiso_curve(&surface, 0.0),
+        uiso_curve(&surface, 1.0),
+        viso_curve(&surface, 0.0),
+        viso_curve(&surface, 1.0),
+    ];
+    let derivations = [
+        vcross_derivation(&surface, true),
+        vcross_derivation(&surface, false),
+        vcross_derivation(&swapped, true),
+        vcross_derivation(&swapped, false),
+    ];
+    let coons = BSplineSurface::bicubic_coons(boundary.clone(), derivations.clone());
+    assert_eq!(coons.parameter_range(), surface.parameter_range());
+    (0..=20).for_each(|i| {
+        let t = i as f64 / 20.0;
+        assert_near!(coons.subs(t, 0.0), boundary[0].subs(t));
+        assert_near!(coons.subs(t, 1.0), boundary[1].subs(t));
+        assert_near!(coons.subs(0.0, t), boundary[2].subs(t));
+        assert_near!(coons.subs(1.0, t), boundary[3].subs(t));
+        assert_near!(coons.vder(t, 0.0), derivations[0].subs(t));
+        assert_near!(coons.vder(t, 1.0), derivations[1].subs(t));
+        assert_near!(coons.uder(0.0, t), derivations[2].subs(t));
+        assert_near!(coons.uder(1.0, t), derivations[3].subs(t));
+        // G1 continuity with the original surface
+        [(t, 0.0), (t, 1.0), (0.0, t), (1.0, t)]
+            .into_iter()
+            .for_each(|(u, v)| {
+                assert_near!(coons.normal(u, v), surface.normal(u, v));
+            });
+    });
+}
+
+#[test]
+fn gordon_network() {
+    let surface = wavy_surface();
+    let v_params = [0.0, 0.25, 0.6, 0.8, 1.0];
+    let u_params = [0.0, 0.1, 0.35, 0.5, 0.75, 1.0];
+    // the curves are given in random order
+    let u_curves = [2, 0, 4, 1, 3]
+        .map(|j| uiso_curve(&surface, v_params[j]))
+        .to_vec();
+    let v_curves = [5, 3, 0, 2, 4, 1]
+        .map(|i| viso_curve(&surface, u_params[i]))
+        .to_vec();
+    let gordon = BSplineSurface::gordon(u_curves.clone(), v_curves.clone(), TOLERANCE).unwrap();
+    assert_eq!(gordon.parameter_range(), surface.parameter_range());
+    (0..=20).for_each(|k| {
+        let t = k as f64 / 20.0;
+        v_params.iter().for_each(|&v| {
+            assert!(gordon.subs(t, v).distance(surface.subs(t, v)) < 1.0e-6);
+        });
+        u_params.iter().for_each(|&u| {
+            assert!(gordon.subs(u, t).distance(surface.subs(u, t)) < 1.0e-6);
+        });
+    });
+
+    // the network is not compatible
+    let mut moved = v_curves.clone();
+    moved[1].transform_control_points(|p| *p += Vector3::new(0.0, 0.0, 0.1));
+    assert!(BSplineSurface::gordon(u_curves.clone(), moved, TOLERANCE).is_none());
+    // too few curves
+    assert!(BSplineSurface::gordon(u_curves[..1].to_vec(), v_curves, TOLERANCE).is_none());
+}
+
+#[test]
+fn gordon_independent_parameters() {
+    let surface = BSplineSurface::new(
+        (KnotVec::bezier_knot(3), KnotVec::bezier_knot(3)),
+        (0..4)
+            .map(|i| {
+                (0..4)
+                    .map(|j| Point3::new(i as f64, j as f64, ((i * j) % 3) as f64 * 0.5))
+                    .collect()
+            })
+            .collect(),
+    );
+    // the curve `t -> surface(phi(t), v)` or `t -> surface(u, phi(t))` for `phi(t) = (1 - a) t + a t^2`
+    let curve = |a: f64, f: &dyn Fn(f64) -> Point3| {
+        let points = (0..=6)
+            .map(|k| k as f64 / 6.0)
+            .map(|t| (t, f((1.0 - a) * t + a * t * t)))
+            .collect::<Vec<_>>();
+        BSplineCurve::interpole(KnotVec::bezier_knot(6), points)
+    };
+    let u_curves = [(0.0, 0.3), (0.3, -0.2), (0.6, 0.5), (1.0, 0.1)]
+        .map(|(v, a)| curve(a, &|u| surface.subs(u, v)))
+        .to_vec();
+    let v_curves = [(0.0, -0.4), (0.5, 0.2), (1.0, 0.4)]
+        .map(|(u, a)| curve(a, &|v| surface.subs(u, v)))
+        .to_vec();
+    let gordon = BSplineSurface::gordon(u_curves.clone(), v_curves.clone(), TOLERANCE).unwrap();
+    let on_surface = |curve: &BSplineCurve<Point3>, hint: &dyn Fn(f64) -> (f64, f64)| {
+        (0..=20).for_each(|k| {
+            let t = k as f64 / 20.0;
+            let p = curve.subs(t);
+            let (u, v) = gordon.search_nearest_parameter(p, hint(t), 100).unwrap();
+            assert!(gordon.subs(u, v).distance(p) < 1.0e-6);
+        });
+    };
+    u_curves
+        .iter()
+        .zip([0.0, 0.3, 0.6, 1.0])
+        .for_each(|(curve, v)| on_surface(curve, &|t| (t, v)));
+    v_curves
+        .iter()
+        .zip([0.0, 0.5, 1.0])
+        .for_each(|(curve, u)| on_surface(curve, &|t| (u, t)));
+    // the corners of the network
+    assert_near!(gordon.subs(0.0, 0.0), surface.subs(0.0, 0.0));
+    assert_near!(gordon.subs(1.0, 1.0), surface.subs(1.0, 1.0));
+
+    // the intersections are in the different orders
+    let mut v_curves = v_curves;
+    v_curves[1] = curve(0.0, &|v| surface.subs(0.5, 1.0 - v));
+    let u_curves = [(0.0, 0.0), (0.3, 0.0), (0.6, 0.0), (1.0, 0.0)]
+        .map(|(v, a)| curve(a, &|u| surface.subs(u, v)))
+        .to_vec();
+    assert!(BSplineSurface::gordon(u_curves, v_curves, TOLERANCE).is_none());
+}
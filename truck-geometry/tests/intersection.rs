@@ -0,0 +1,99 @@
+use std::f64::consts::PI;
+use truck_geometry::prelude::*;
+
+fn circle(center: Point3, radius: f64) -> Processor<TrimmedCurve<UnitCircle<Point3>>, Matrix4> {
+    Processor::with_transform(
+        TrimmedCurve::new(UnitCircle::new(), (0.0, 2.0 * PI)),
+        Matrix4::from_translation(center.to_vec()) * Matrix4::from_scale(radius),
+    )
+}
+
+#[test]
+fn transversal_curves() {
+    let circle0 = circle(Point3::origin(), 1.0);
+    let circle1 = circle(Point3::new(1.0, 0.0, 0.0), 1.0);
+    let res = intersect_curves(&circle0, &circle1, TOLERANCE);
+    assert_eq!(res.len(), 2);
+    assert_near!(res[0].0, PI / 3.0);
+    assert_near!(res[1].0, 5.0 * PI / 3.0);
+    res.iter()
+        .for_each(|&(t0, t1)| assert_near!(circle0.subs(t0), circle1.subs(t1)));
+
+    let far = circle(Point3::new(3.0, 0.0, 0.0), 0.5);
+    assert!(intersect_curves(&circle0, &far, TOLERANCE).is_empty());
+}
+
+#[test]
+fn tangential_curves() {
+    let circle0 = circle(Point3::origin(), 1.0);
+    let line = Line(Point3::new(-2.0, 1.0, 0.0), Point3::new(2.0, 1.0, 0.0));
+    let res = intersect_curves(&circle0, &line, TOLERANCE);
+    assert_eq!(res.len(), 1);
+    assert!(circle0.subs(res[0].0).distance(Point3::new(0.0, 1.0, 0.0)) < 1.0e-3);
+    assert!(circle0.subs(res[0].0).distance(line.subs(res[0].1)) <= TOLERANCE);
+
+    let circle1 = circle(Point3::new(0.0, 3.0, 0.0), 2.0);
+    let res = intersect_curves(&circle0, &circle1, TOLERANCE);
+    assert_eq!(res.len(), 1);
+    assert!(circle0.subs(res[0].0).distance(Point3::new(0.0, 1.0, 0.0)) < 1.0e-3);
+}
+
+#[test]
+fn overlapping_curves() {
+    let curve0 = BSplineCurve::new(
+        KnotVec::uniform_knot(3, 3),
+        vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 2.0, 0.0),
+            Point3::new(2.0, -1.0, 1.0),
+            Point3::new(3.0, 1.0, 0.0),
+            Point3::new(4.0, 0.0, -1.0),
+            Point3::new(5.0, 2.0, 0.0),
+        ],
+    );
+    let mut curve1 = curve0.clone();
+    curve1.cut(0.8);
+    let mut curve1 = curve1.cut(0.3);
+    curve1.invert();
+    let res = intersect_curves(&curve0, &curve1, TOLERANCE);
+    assert_eq!(res.len(), 2);
+    assert!(f64::abs(res[0].0 - 0.3) < 1.0e-4);
+    assert!(f64::abs(res[1].0 - 0.8) < 1.0e-4);
+    res.iter()
+        .for_each(|&(t0, t1)| assert!(curve0.subs(t0).distance(curve1.subs(t1)) <= TOLERANCE));
+}
+
+#[test]
+fn curve_surface() {
+    let sphere = Sphere::new(Point3::new(0.0, 0.0, 1.0), 1.0);
+    let curve = BSplineCurve::new(
+        KnotVec::bezier_knot(2),
+        vec![
+            Point3::new(-2.0, 0.5, 0.0),
+            Point3::new(0.0, 0.0, 4.0),
+            Point3::new(2.0, -0.5, 0.0),
+        ],
+    );
+    let res = intersect_curve_surface(&curve, &sphere, TOLERANCE);
+    assert_eq!(res.len(), 2);
+    res.iter()
+        .for_each(|&(t, (u, v))| assert_near!(curve.subs(t), sphere.subs(u, v)));
+
+    // tangential
+    let line = Line(Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 0.0, 2.0));
+    let res = intersect_curve_surface(&line, &sphere, TOLERANCE);
+    assert_eq!(res.len(), 1);
+    assert!(line.subs(res[0].0).distance(Point3::new(1.0, 0.0, 1.0)) < 1.0e-3);
+
+    // on the surface
+    let plane = Plane::new(
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+    let line = Line(Point3::new(-0.5, 0.5, 0.0), Point3::new(1.5, 0.5, 0.0));
+    let res = intersect_curve_surface(&line, &plane, TOLERANCE);
+    assert_eq!(res.len(), 2);
+    assert_near!(line.subs(res[0].0), Point3::new(0.0, 0.5, 0.0));
+    assert_near!(line.subs(res[1].0), Point3::new(1.0, 0.5, 0.0));
+}
@@ -0,0 +1,206 @@
+use std::f64::consts::PI;
+use truck_geometry::prelude::*;
+
+fn wavy_curve() -> BSplineCurve<Point3> {
+    BSplineCurve::new(
+        KnotVec::uniform_knot(3, 4),
+        vec![
+            Point3::new(0.0, 0.0, 0.0),
+            Point3::new(1.0, 1.0, 0.0),
+            Point3::new(2.0, -1.0, 1.0),
+            Point3::new(3.0, 1.0, 0.0),
+            Point3::new(4.0, -1.0, -1.0),
+            Point3::new(5.0, 1.0, 0.0),
+            Point3::new(6.0, 0.0, 0.0),
+        ],
+    )
+}
+
+#[test]
+fn project_to_curve() {
+    let curve = wavy_curve();
+    let (t0, t1) = curve.range_tuple();
+    for point in [
+        Point3::new(3.0, -5.0, 0.0),
+        Point3::new(2.5, 3.0, 1.0),
+        Point3::new(-1.0, 0.5, 0.0),
+        Point3::new(7.0, 0.0, 2.0),
+    ] {
+        let res = curve.project_point(point, TOLERANCE);
+        assert!(!res.is_empty());
+        res.windows(2)
+            .for_each(|x| assert!(x[0].distance <= x[1].distance));
+        res.iter().for_each(|proj| {
+            let t = proj.parameter;
+            assert_near!(curve.subs(t), proj.point);
+            assert_near!(proj.point.distance(point), proj.distance);
+            let der = (proj.point - point).dot(curve.der(t));
+            match (t - t0).so_small() || (t - t1).so_small() {
+                true => assert!(der.abs() < 1.0e-6 || (t - t0).so_small() == (der > 0.0)),
+                false => assert!(der.abs() < 1.0e-6, "{der}"),
+            }
+        });
+        (0..=10000).for_each(|i| {
+            let t = t0 + (t1 - t0) * i as f64 / 10000.0;
+            assert!(res[0].distance <= curve.subs(t).distance(point) + TOLERANCE);
+        });
+    }
+}
+
+#[test]
+fn project_to_circle() {
+    let circle = Processor::with_transform(
+        TrimmedCurve::new(UnitCircle::<Point3>::new(), (0.0, 2.0 * PI)),
+        Matrix4::from_scale(2.0),
+    );
+    // the antipodal point is the maximum.
+    let res = circle.project_point(Point3::new(-3.0, 0.0, 0.0), TOLERANCE);
+    assert_eq!(res.len(), 1);
+    assert_near!(res[0].point, Point3::new(-2.0, 0.0, 0.0));
+    assert_near!(res[0].distance, 1.0);
+
+    // the minimum at the seam of the closed curve is not duplicated.
+    let res = circle.project_point(Point3::new(3.0, 0.0, 1.0), TOLERANCE);
+    assert_eq!(res.len(), 1);
+    assert_near!(res[0].point, Point3::new(2.0, 0.0, 0.0));
+}
+
+#[test]
+fn project_to_surface() {
+    // two bumps
+    let control_points = (0..5)
+        .map(|i| {
+            (0..4)
+                .map(|j| {
+                    let z = if (i == 1 || i == 3) && (j == 1 || j == 2) {
+                        1.5
+                    } else {
+                        0.0
+                    };
+                    Point3::new(i as f64, j as f64, z)
+                })
+                .collect()
+        })
+        .collect();
+    let surface = BSplineSurface::new(
+        (KnotVec::uniform_knot(2, 3), KnotVec::bezier_knot(3)),
+        control_points,
+    );
+    let ((u0, u1), (v0, v1)) = surface.range_tuple();
+    let point = Point3::new(2.0, 1.5, 3.0);
+    let res = surface.project_point(point, TOLERANCE);
+    // the tops of the bumps
+    assert_eq!(res.len(), 2, "{res:?}");
+    assert_near!(res[0].distance, res[1].distance);
+    res.iter().for_each(|proj| {
+        let (u, v) = proj.parameter;
+        assert_near!(surface.subs(u, v), proj.point);
+        let diff = proj.point - point;
+        assert!(diff.dot(surface.uder(u, v)).abs() < 1.0e-6);
+        assert!(diff.dot(surface.vder(u, v)).abs() < 1.0e-6);
+    });
+    (0..=200).for_each(|i| {
+        (0..=200).for_each(|j| {
+            let u = u0 + (u1 - u0) * i as f64 / 200.0;
+            let v = v0 + (v1 - v0) * j as f64 / 200.0;
+            assert!(res[0].distance <= surface.subs(u, v).distance(point) + TOLERANCE);
+        });
+    });
+}
+
+#[test]
+fn project_to_sphere() {
+    let sphere = Sphere::new(Point3::new(1.0, 2.0, 3.0), 2.0);
+    let res = sphere.project_point(Point3::new(1.0, 5.0, 4.0), TOLERANCE);
+    assert_eq!(res.len(), 1);
+    let answer = Point3::new(1.0, 2.0, 3.0) + Vector3::new(0.0, 3.0, 1.0).normalize() * 2.0;
+    assert_near!(res[0].point, answer);
+}
+
+#[test]
+fn project_curve_on_surface() {
+    let surface = BSplineSurface::new(
+        (KnotVec::bezier_knot(2), KnotVec::bezier_knot(2)),
+        vec![
+            vec![
+                Point3::new(0.0, 0.0, 0.0),
+                Point3::new(0.0, 0.5, 0.5),
+                Point3::new(0.0, 1.0, 0.0),
+            ],
+            vec![
+                Point3::new(0.5, 0.0, 0.5),
+                Point3::new(0.5, 0.5, 1.5),
+                Point3::new(0.5, 1.0, 0.5),
+            ],
+            vec![
+                Point3::new(1.0, 0.0, 0.0),
+                Point3::new(1.0, 0.5, 0.5),
+                Point3::new(1.0, 1.0, 0.0),
+            ],
+        ],
+    );
+    let uv_curve = BSplineCurve::new(
+        KnotVec::bezier_knot(2),
+        vec![
+            Point2::new(0.1, 0.2),
+            Point2::new(0.9, 0.4),
+            Point2::new(0.3, 0.9),
+        ],
+    );
+    let curve = PCurve::new(uv_curve.clone(), surface.clone());
+    let (pcurve, off_ranges) = project_curve_to_surface(&curve, surface, 1.0e-5).unwrap();
+    assert!(off_ranges.is_empty());
+    assert_eq!(pcurve.range_tuple(), (0.0, 1.0));
+    (0..=100).for_each(|i| {
+        let t = i as f64 / 100.0;
+        assert!(pcurve.subs(t).distance(curve.subs(t)) < 1.0e-5);
+        assert!(pcurve.curve().subs(t).distance(uv_curve.subs(t)) < 1.0e-4);
+    });
+}
+
+#[test]
+fn project_curve_through_pole() {
+    let sphere = Sphere::new(Point3::origin(), 1.0);
+    // the meridian from the north pole to the equator
+    let meridian = Processor::with_transform(
+        TrimmedCurve::new(UnitCircle::<Point3>::new(), (0.0, PI / 2.0)),
+        Matrix4::from_cols(
+            Vector4::new(0.0, 0.0, 1.0, 0.0),
+            Vector4::new(1.0, 0.0, 0.0, 0.0),
+            Vector4::new(0.0, 1.0, 0.0, 0.0),
+            Vector4::new(0.0, 0.0, 0.0, 1.0),
+        ),
+    );
+    assert_near!(meridian.front(), Point3::new(0.0, 0.0, 1.0));
+    let (pcurve, off_ranges) = project_curve_to_surface(&meridian, sphere, 1.0e-5).unwrap();
+    assert!(off_ranges.is_empty());
+    let v = pcurve.curve().back().y;
+    (0..=100).for_each(|i| {
+        let t = PI / 2.0 * i as f64 / 100.0;
+        assert!(pcurve.subs(t).distance(meridian.subs(t)) < 1.0e-5);
+        // the longitude at the pole is the one of the meridian.
+        assert!(f64::abs(pcurve.curve().subs(t).y - v) < 1.0e-3);
+    });
+}
+
+#[test]
+fn project_curve_off_surface() {
+    let plane = BSplineSurface::new(
+        (KnotVec::bezier_knot(1), KnotVec::bezier_knot(1)),
+        vec![
+            vec![Point3::new(0.0, 0.0, 0.0), Point3::new(0.0, 1.0, 0.0)],
+            vec![Point3::new(1.0, 0.0, 0.0), Point3::new(1.0, 1.0, 0.0)],
+        ],
+    );
+    let line = Line(Point3::new(-1.0, 0.5, 0.0), Point3::new(3.0, 0.5, 0.0));
+    let (pcurve, off_ranges) = project_curve_to_surface(&line, plane, 1.0e-5).unwrap();
+    assert_eq!(off_ranges.len(), 2, "{off_ranges:?}");
+    assert_near!(off_ranges[0].0, 0.0);
+    assert!(f64::abs(off_ranges[0].1 - 0.25) < 1.0e-5);
+    assert!(f64::abs(off_ranges[1].0 - 0.5) < 1.0e-5);
+    assert_near!(off_ranges[1].1, 1.0);
+    (0..=100).for_each(|i| {
+        let t = 0.25 + 0.25 * i as f64 / 100.0;
+        assert!(pcurve.subs(t).distance(line.subs(t)) < 1.0e-5);
+    });
+}
@@ -0,0 +1,192 @@
+use std::f64::consts::PI;
+use truck_geometry::prelude::*;
+
+fn sample(range: (f64, f64), n: usize) -> impl Iterator<Item = f64> {
+    (0..=n).map(move |i| range.0 + (range.1 - range.0) * i as f64 / n as f64)
+}
+
+#[test]
+fn conics_to_nurbs() {
+    let range = (0.3, 4.0);
+    let circle = UnitCircle::<Point2>::new().to_nurbs_in(range).unwrap();
+    assert_eq!(circle.range_tuple(), range);
+    assert_eq!(circle.knot_vec().len(), 10);
+    sample(range, 100).for_each(|t| assert_near!(circle.subs(t).to_vec().magnitude(), 1.0));
+    assert_near!(circle.front(), Point2::new(f64::cos(0.3), f64::sin(0.3)));
+    assert_near!(circle.back(), Point2::new(f64::cos(4.0), f64::sin(4.0)));
+    let circle = UnitCircle::<Point3>::new().to_nurbs().unwrap();
+    assert_eq!(circle.range_tuple(), (0.0, 2.0 * PI));
+    [0.0, 0.5, 1.0, 1.5, 2.0].into_iter().for_each(|t| {
+        let t = t * PI;
+        assert_near!(circle.subs(t), UnitCircle::<Point3>::new().subs(t));
+    });
+    assert!(UnitCircle::<Point2>::new()
+        .to_nurbs_in((1.0, 1.0))
+        .is_none());
+
+    let range = (-1.0, 2.0);
+    let hyperbola = UnitHyperbola::<Point2>::new().to_nurbs_in(range).unwrap();
+    sample(range, 100).for_each(|t| {
+        let p = hyperbola.subs(t);
+        assert_near!(p.x * p.x - p.y * p.y, 1.0);
+    });
+    assert_near!(hyperbola.front(), UnitHyperbola::<Point2>::new().subs(-1.0));
+    assert_near!(hyperbola.back(), UnitHyperbola::<Point2>::new().subs(2.0));
+    assert!(UnitHyperbola::<Point3>::new().to_nurbs().is_none());
+
+    let parabola = UnitParabola::<Point3>::new().to_nurbs_in(range).unwrap();
+    sample(range, 100).for_each(|t| {
+        assert_near!(parabola.subs(t), UnitParabola::<Point3>::new().subs(t));
+    });
+    assert!(UnitParabola::<Point2>::new().to_nurbs().is_none());
+}
+
+#[test]
+fn elementary_surfaces_to_nurbs() {
+    let plane = Plane::new(
+        Point3::new(0.0, 1.0, 2.0),
+        Point3::new(3.0, 1.0, 0.0),
+        Point3::new(1.0, 4.0, 1.0),
+    );
+    let range = ((-1.0, 2.0), (0.5, 3.0));
+    let nurbs = plane.to_nurbs_in(range).unwrap();
+    assert_eq!(nurbs.range_tuple(), range);
+    sample(range.0, 10).for_each(|u| {
+        sample(range.1, 10).for_each(|v| assert_near!(nurbs.subs(u, v), plane.subs(u, v)));
+    });
+
+    let sphere = Sphere::new(Point3::new(1.0, -2.0, 3.0), 2.5);
+    let range = ((0.2, 2.0), (1.0, 5.0));
+    let nurbs = sphere.to_nurbs_in(range).unwrap();
+    assert_eq!(nurbs.range_tuple(), range);
+    sample(range.0, 10).for_each(|u| {
+        sample(range.1, 10).for_each(|v| {
+            assert_near!(nurbs.subs(u, v).distance(sphere.center()), sphere.radius());
+        })
+    });
+    let ((u0, u1), (v0, v1)) = range;
+    [(u0, v0), (u0, v1), (u1, v0), (u1, v1)]
+        .into_iter()
+        .for_each(|(u, v)| assert_near!(nurbs.subs(u, v), sphere.subs(u, v)));
+
+    let torus = Torus::new(Point3::new(-1.0, 2.0, 0.5), 3.0, 1.0);
+    let nurbs = torus.to_nurbs().unwrap();
+    assert_eq!(nurbs.range_tuple(), ((0.0, 2.0 * PI), (0.0, 2.0 * PI)));
+    sample((0.0, 2.0 * PI), 20).for_each(|u| {
+        sample((0.0, 2.0 * PI), 20).for_each(|v| {
+            let p = nurbs.subs(u, v) - torus.center();
+            let r = f64::sqrt(p.x * p.x + p.y * p.y) - torus.large_radius();
+            assert_near!(
+                r * r + p.z * p.z,
+                torus.small_radius() * torus.small_radius()
+            );
+        })
+    });
+    assert_near!(nurbs.subs(PI / 2.0, PI), torus.subs(PI / 2.0, PI));
+}
+
+#[test]
+fn decorated_surfaces_to_nurbs() {
+    let curve = BSplineCurve::new(
+        KnotVec::bezier_knot(2),
+        vec![
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(2.0, 1.0, 1.0),
+            Point3::new(1.0, 0.0, 2.0),
+        ],
+    );
+    let axis = Vector3::new(1.0, 1.0, 1.0);
+    let revoluted = RevolutedCurve::by_revolution(curve.clone(), Point3::new(0.0, 1.0, 0.0), axis);
+    let nurbs = revoluted.to_nurbs_in(((0.0, 1.0), (0.5, 3.0))).unwrap();
+    sample((0.0, 1.0), 10).for_each(|u| {
+        [0.5, 1.75, 3.0]
+            .into_iter()
+            .for_each(|v| assert_near!(nurbs.subs(u, v), revoluted.subs(u, v)));
+    });
+
+    let extruded = ExtrudedCurve::by_extrusion(curve.clone(), Vector3::new(0.0, 2.0, 1.0));
+    let nurbs = extruded.to_nurbs().unwrap();
+    sample((0.0, 1.0), 10).for_each(|u| {
+        sample((0.0, 1.0), 10).for_each(|v| assert_near!(nurbs.subs(u, v), extruded.subs(u, v)));
+    });
+
+    let line = Line(Point3::new(0.0, 0.0, 5.0), Point3::new(1.0, 0.0, 5.0));
+    let homotopy = HomotopySurface::new(curve, line);
+    let nurbs = homotopy.to_nurbs_in(((0.25, 0.75), (-1.0, 2.0))).unwrap();
+    sample((0.25, 0.75), 10).for_each(|u| {
+        sample((-1.0, 2.0), 10).for_each(|v| assert_near!(nurbs.subs(u, v), homotopy.subs(u, v)));
+    });
+
+    // the ruled surface between a rational curve and a polynomial one
+    let arc = TrimmedCurve::new(UnitCircle::<Point3>::new(), (-0.5, 0.8));
+    let line = Line(Point3::new(-1.0, -1.0, 2.0), Point3::new(1.0, 1.0, 3.0));
+    let homotopy = HomotopySurface::new(arc, line);
+    let nurbs = homotopy.to_nurbs().unwrap();
+    assert_eq!(nurbs.range_tuple(), ((0.0, 0.8), (0.0, 1.0)));
+    // The ruled lines are kept, however, the parameters of the NURBS arc differ from the angles.
+    let ruled = HomotopySurface::new(
+        arc.to_nurbs_in((0.0, 0.8)).unwrap(),
+        line.to_nurbs_in((0.0, 0.8)).unwrap(),
+    );
+    sample((0.0, 0.8), 20).for_each(|u| {
+        sample((0.0, 1.0), 10).for_each(|v| assert_near!(nurbs.subs(u, v), ruled.subs(u, v)));
+    });
+    [0.0, 0.8].into_iter().for_each(|u| {
+        sample((0.0, 1.0), 10).for_each(|v| assert_near!(nurbs.subs(u, v), homotopy.subs(u, v)));
+    });
+
+    // the ruled surface between a rational curve and a polynomial one of several segments
+    let circle = UnitCircle::<Point3>::new();
+    let mut knot_vec = KnotVec::uniform_knot(3, 3);
+    knot_vec.transform(2.0 * PI, 0.0);
+    let wavy = BSplineCurve::new(
+        knot_vec,
+        (0..6)
+            .map(|i| Point3::new(i as f64, (i % 2) as f64, 2.0))
+            .collect(),
+    );
+    let homotopy = HomotopySurface::new(circle, wavy.clone());
+    let nurbs = homotopy.to_nurbs_in(((0.5, 5.0), (0.0, 1.0))).unwrap();
+    let ruled = HomotopySurface::new(
+        circle.to_nurbs_in((0.5, 5.0)).unwrap(),
+        wavy.to_nurbs_in((0.5, 5.0)).unwrap(),
+    );
+    sample((0.5, 5.0), 40).for_each(|u| {
+        sample((0.0, 1.0), 10).for_each(|v| assert_near!(nurbs.subs(u, v), ruled.subs(u, v)));
+    });
+}
+
+#[test]
+fn processor_to_nurbs() {
+    let mut arc = Processor::with_transform(
+        TrimmedCurve::new(UnitCircle::<Point3>::new(), (0.0, PI)),
+        Matrix4::from_translation(Vector3::new(1.0, 2.0, 3.0)) * Matrix4::from_scale(2.0),
+    );
+    arc.invert();
+    let nurbs = arc.to_nurbs().unwrap();
+    assert_eq!(nurbs.range_tuple(), (0.0, PI));
+    [0.0, PI / 2.0, PI]
+        .into_iter()
+        .for_each(|t| assert_near!(nurbs.subs(t), arc.subs(t)));
+    let nurbs = arc.to_nurbs_in((0.5, 2.5)).unwrap();
+    assert_eq!(nurbs.range_tuple(), (0.5, 2.5));
+    assert_near!(nurbs.front(), arc.subs(0.5));
+    assert_near!(nurbs.back(), arc.subs(2.5));
+
+    let mut sphere = Processor::<_, Matrix4>::new(Sphere::new(Point3::origin(), 1.0));
+    sphere.invert();
+    let range = ((0.0, 2.0 * PI), (0.0, PI));
+    let nurbs = sphere.to_nurbs_in(range).unwrap();
+    assert_eq!(nurbs.range_tuple(), range);
+    [
+        (0.0, 0.0),
+        (PI / 2.0, PI / 2.0),
+        (PI, PI),
+        (1.5 * PI, PI / 2.0),
+    ]
+    .into_iter()
+    .for_each(|(u, v)| assert_near!(nurbs.subs(u, v), sphere.subs(u, v)));
+    let (p, n) = (sphere.subs(1.0, 1.0), sphere.normal(1.0, 1.0));
+    let (u, v) = nurbs.search_nearest_parameter(p, (1.0, 1.0), 100).unwrap();
+    assert_near!(nurbs.normal(u, v), n);
+}
@@ -129,6 +129,250 @@ where
     }
 }
 
+/// The nodes and weights of 5-points Gauss-Legendre quadrature on `[-1, 1]`.
+const GAUSS_LEGENDRE5: [(f64, f64); 5] = [
+    (0.0, 0.568_888_888_888_888_9),
+    (-0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (0.538_469_310_105_683_1, 0.478_628_670_499_366_5),
+    (-0.906_179_845_938_664, 0.236_926_885_056_189_1),
+    (0.906_179_845_938_664, 0.236_926_885_056_189_1),
+];
+
+/// The minimum and maximum depth of the adaptive subdivision in the arc length computation.
+const ARC_LENGTH_DEPTHS: (usize, usize) = (3, 40);
+
+/// The arc length of `curve` in `(t0, t1)` by 5-points Gauss-Legendre quadrature without subdivision.
+/// This is accurate only if the speed of `curve` is smooth in the range.
+pub fn gauss_legendre_length<C>(curve: &C, (t0, t1): (f64, f64)) -> f64
+where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>, {
+    let (m, h) = ((t0 + t1) / 2.0, (t1 - t0) / 2.0);
+    let sum = GAUSS_LEGENDRE5.iter().fold(0.0, |sum, (x, w)| {
+        sum + w * curve.der(m + h * x).magnitude()
+    });
+    sum * h
+}
+
+/// Returns the table `(t, s)` of the parameters `t` and the arc lengths `s` from `range.0`
+/// by the adaptive Gauss-Legendre quadrature. The total error of the lengths is at most `tol`.
+///
+/// The first element is `(range.0, 0.0)` and the last one is `(range.1, length)`.
+/// The lengths are accurate also in each interval of the table by [`gauss_legendre_length`].
+///
+/// # Panics
+///
+/// `tol` must be greater than or equal to `TOLERANCE`.
+pub fn arc_length_table<C>(curve: &C, range: (f64, f64), tol: f64) -> Vec<(f64, f64)>
+where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>, {
+    nonpositive_tolerance!(tol);
+    unchecked_arc_length_table(curve, range, tol)
+}
+
+fn unchecked_arc_length_table<C>(curve: &C, range: (f64, f64), tol: f64) -> Vec<(f64, f64)>
+where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>, {
+    let mut table = vec![(range.0, 0.0)];
+    let whole = gauss_legendre_length(curve, range);
+    sub_arc_length_table(curve, range, whole, tol, 0, &mut table);
+    table
+}
+
+fn sub_arc_length_table<C>(
+    curve: &C,
+    (t0, t1): (f64, f64),
+    whole: f64,
+    tol: f64,
+    depth: usize,
+    table: &mut Vec<(f64, f64)>,
+) where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>,
+{
+    let t = (t0 + t1) / 2.0;
+    let first = gauss_legendre_length(curve, (t0, t));
+    let second = gauss_legendre_length(curve, (t, t1));
+    let converged = depth >= ARC_LENGTH_DEPTHS.0 && f64::abs(whole - first - second) < tol;
+    if converged || depth >= ARC_LENGTH_DEPTHS.1 {
+        let s = table.last().unwrap().1;
+        table.push((t, s + first));
+        table.push((t1, s + first + second));
+    } else {
+        sub_arc_length_table(curve, (t0, t), first, tol / 2.0, depth + 1, table);
+        sub_arc_length_table(curve, (t, t1), second, tol / 2.0, depth + 1, table);
+    }
+}
+
+/// Returns the arc length of `curve` in `range` within the error `tol`.
+/// The length is non-negative even if `range.0 > range.1`.
+///
+/// # Panics
+///
+/// `tol` must be greater than or equal to `TOLERANCE`.
+pub fn length<C>(curve: &C, range: (f64, f64), tol: f64) -> f64
+where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>, {
+    nonpositive_tolerance!(tol);
+    unchecked_length(curve, range, tol)
+}
+
+fn unchecked_length<C>(curve: &C, range: (f64, f64), tol: f64) -> f64
+where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>, {
+    f64::abs(
+        unchecked_arc_length_table(curve, range, tol)
+            .last()
+            .unwrap()
+            .1,
+    )
+}
+
+/// Returns the parameter `t` such that the arc length of `curve` in `(start, t)` is `length`.
+/// Returns `None` if `length` is negative or `t` is out of the parameter range.
+///
+/// # Panics
+///
+/// `tol` must be greater than or equal to `TOLERANCE`.
+pub fn parameter_at_length<C>(curve: &C, start: f64, length: f64, tol: f64) -> Option<f64>
+where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>, {
+    nonpositive_tolerance!(tol);
+    if length < 0.0 {
+        return None;
+    }
+    let end = bound2opt(curve.parameter_range().1).unwrap_or(f64::INFINITY);
+    // the parameters whose arc lengths are less than or greater than `length`
+    let (mut lower, mut upper) = ((start, 0.0), None);
+    let mut t = start;
+    for _ in 0..100 {
+        let s = lower.1 + unchecked_length(curve, (lower.0, t), tol / 2.0);
+        if f64::abs(s - length) < tol {
+            return Some(t);
+        }
+        match s < length {
+            true => lower = (t, s),
+            false => upper = Some((t, s)),
+        }
+        let speed = curve.der(t).magnitude();
+        let newton = t + (length - s) / speed;
+        t = match upper {
+            Some((t1, _)) if !(lower.0 < newton && newton < t1) => (lower.0 + t1) / 2.0,
+            None if !(newton > t && newton.is_finite()) => t + f64::max(t - start, 1.0),
+            _ => newton,
+        };
+        if t > end {
+            match lower.0 < end {
+                true => t = end,
+                false => return None,
+            }
+        }
+    }
+    None
+}
+
+/// The Frenet frame of a space curve.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FrenetFrame {
+    /// the unit tangent vector
+    pub tangent: Vector3,
+    /// the principal normal vector, directed to the center of curvature
+    pub normal: Vector3,
+    /// the binormal vector, `tangent.cross(normal)`
+    pub binormal: Vector3,
+}
+
+/// Returns the curvature of `curve` at `t`.
+/// The value is not finite at the singular points, i.e. the points where `curve.der(t)` vanishes.
+pub fn curvature<C>(curve: &C, t: f64) -> f64
+where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>, {
+    let ders = curve.ders(2, t);
+    let (d1, d2) = (ders[1], ders[2]);
+    let speed2 = d1.magnitude2();
+    let area2 = speed2 * d2.magnitude2() - d1.dot(d2).powi(2);
+    f64::sqrt(f64::max(area2, 0.0)) / (speed2 * f64::sqrt(speed2))
+}
+
+/// Returns the curvature vector, the vector from the point to the center of curvature
+/// with the length of the curvature.
+/// The value is not finite at the singular points, i.e. the points where `curve.der(t)` vanishes.
+pub fn curvature_vector<C>(curve: &C, t: f64) -> C::Vector
+where
+    C: ParametricCurve,
+    C::Vector: InnerSpace<Scalar = f64>, {
+    let ders = curve.ders(2, t);
+    let (d1, d2) = (ders[1], ders[2]);
+    let speed2 = d1.magnitude2();
+    (d2 - d1 * (d1.dot(d2) / speed2)) / speed2
+}
+
+/// Returns the signed curvature of a planar curve at `t`.
+/// The curvature is positive if the curve turns counterclockwise.
+pub fn signed_curvature<C>(curve: &C, t: f64) -> f64
+where C: ParametricCurve<Vector = Vector2> {
+    let ders = curve.ders(2, t);
+    let (d1, d2) = (ders[1], ders[2]);
+    d1.perp_dot(d2) / d1.magnitude().powi(3)
+}
+
+/// Returns the torsion of a space curve at `t`.
+/// The value is not finite at the points where the curvature vanishes.
+pub fn torsion<C>(curve: &C, t: f64) -> f64
+where C: ParametricCurve<Vector = Vector3> {
+    let ders = curve.ders(3, t);
+    let cross = ders[1].cross(ders[2]);
+    cross.dot(ders[3]) / cross.magnitude2()
+}
+
+/// Returns the Frenet frame of a space curve at `t`.
+/// Returns `None` if the tangent or the principal normal is not determined,
+/// i.e. `t` is a singular point or an inflection point.
+pub fn frenet_frame<C>(curve: &C, t: f64) -> Option<FrenetFrame>
+where C: ParametricCurve<Vector = Vector3> {
+    let ders = curve.ders(2, t);
+    let cross = ders[1].cross(ders[2]);
+    if ders[1].so_small() || cross.so_small() {
+        return None;
+    }
+    let tangent = ders[1].normalize();
+    let binormal = cross.normalize();
+    Some(FrenetFrame {
+        tangent,
+        normal: binormal.cross(tangent),
+        binormal,
+    })
+}
+
+/// Returns the curvature comb of `curve`: the pairs of the points on the curve and the tips of the teeth.
+/// The curve is sampled at `division + 1` parameters evenly spaced in `range`, and each tooth is
+/// the curvature vector scaled by `-scale`, i.e. grows outward from the convex side of the curve.
+pub fn curvature_comb<C>(
+    curve: &C,
+    (t0, t1): (f64, f64),
+    division: usize,
+    scale: f64,
+) -> Vec<(C::Point, C::Point)>
+where
+    C: ParametricCurve,
+    C::Point: EuclideanSpace<Scalar = f64, Diff = C::Vector>,
+    C::Vector: InnerSpace<Scalar = f64>,
+{
+    (0..=division)
+        .map(|i| {
+            let t = t0 + (t1 - t0) * i as f64 / division as f64;
+            let pt = curve.subs(t);
+            (pt, pt - curvature_vector(curve, t) * scale)
+        })
+        .collect()
+}
+
 #[derive(Clone, Debug)]
 struct SubSurface<C0, C1> {
     curve0: C0,
@@ -215,6 +215,50 @@ where
     }
 }
 
+/// Searches the parameters of the closest points of `surface` and `curve`.
+/// This method is useful to find the tangential intersection, where [`search_intersection_parameter`] fails.
+pub fn search_closest_parameter<C, S>(
+    surface: &S,
+    hint0: (f64, f64),
+    curve: &C,
+    hint1: f64,
+    trials: usize,
+) -> Option<((f64, f64), f64)>
+where
+    C: ParametricCurve3D,
+    S: ParametricSurface3D,
+{
+    let function = move |Vector3 { x, y, z }| {
+        let diff = surface.subs(x, y) - curve.subs(z);
+        let (su, sv) = (surface.uder(x, y), surface.vder(x, y));
+        let (suu, suv, svv) = (
+            surface.uuder(x, y),
+            surface.uvder(x, y),
+            surface.vvder(x, y),
+        );
+        let (c1, c2) = (curve.der(z), curve.der2(z));
+        CalcOutput {
+            value: Vector3::new(diff.dot(su), diff.dot(sv), diff.dot(c1)),
+            derivation: Matrix3::from_cols(
+                Vector3::new(
+                    su.dot(su) + diff.dot(suu),
+                    su.dot(sv) + diff.dot(suv),
+                    su.dot(c1),
+                ),
+                Vector3::new(
+                    sv.dot(su) + diff.dot(suv),
+                    sv.dot(sv) + diff.dot(svv),
+                    sv.dot(c1),
+                ),
+                Vector3::new(-c1.dot(su), -c1.dot(sv), diff.dot(c2) - c1.dot(c1)),
+            ),
+        }
+    };
+    let hint = Vector3::new(hint0.0, hint0.1, hint1);
+    let Vector3 { x, y, z } = newton::solve(function, hint, trials).ok()?;
+    Some(((x, y), z))
+}
+
 /// Creates the surface division
 ///
 /// # Panics
@@ -301,3 +345,129 @@ where
         sub_parameter_division(surface, (udiv, vdiv), tol);
     }
 }
+
+/// The first and second fundamental forms of a surface at a point.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FundamentalForms {
+    /// the first fundamental form `[[E, F], [F, G]]`
+    pub first: Matrix2,
+    /// the second fundamental form `[[L, M], [M, N]]` with respect to `normal`
+    pub second: Matrix2,
+}
+
+/// The principal curvatures and directions of a surface at a point.
+///
+/// The curvature is positive if the surface bends toward the normal of the surface.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SurfaceCurvature {
+    /// the principal curvatures, the first one is the larger one
+    pub principal_curvatures: (f64, f64),
+    /// the unit principal directions; `(d0, d1, normal)` is a right-handed orthonormal frame
+    pub principal_directions: (Vector3, Vector3),
+}
+
+impl SurfaceCurvature {
+    /// Returns the Gaussian curvature.
+    #[inline(always)]
+    pub fn gaussian(&self) -> f64 { self.principal_curvatures.0 * self.principal_curvatures.1 }
+    /// Returns the mean curvature.
+    #[inline(always)]
+    pub fn mean(&self) -> f64 { (self.principal_curvatures.0 + self.principal_curvatures.1) / 2.0 }
+}
+
+/// Returns the fundamental forms of `surface` at `(u, v)`.
+/// Returns `None` if `(u, v)` is a singular point.
+pub fn fundamental_forms<S: ParametricSurface3D>(
+    surface: &S,
+    u: f64,
+    v: f64,
+) -> Option<FundamentalForms> {
+    let ders = surface.ders(2, u, v);
+    let (su, sv) = (ders[1][0], ders[0][1]);
+    if su.cross(sv).so_small() {
+        return None;
+    }
+    let n = surface.normal(u, v);
+    let (e, f, g) = (su.dot(su), su.dot(sv), sv.dot(sv));
+    let (l, m, nn) = (ders[2][0].dot(n), ders[1][1].dot(n), ders[0][2].dot(n));
+    Some(FundamentalForms {
+        first: Matrix2::new(e, f, f, g),
+        second: Matrix2::new(l, m, m, nn),
+    })
+}
+
+/// Returns the Gaussian curvature of `surface` at `(u, v)`.
+/// Returns `None` if `(u, v)` is a singular point.
+pub fn gaussian_curvature<S: ParametricSurface3D>(surface: &S, u: f64, v: f64) -> Option<f64> {
+    let forms = fundamental_forms(surface, u, v)?;
+    Some(forms.second.determinant() / forms.first.determinant())
+}
+
+/// Returns the mean curvature of `surface` at `(u, v)`.
+/// Returns `None` if `(u, v)` is a singular point.
+pub fn mean_curvature<S: ParametricSurface3D>(surface: &S, u: f64, v: f64) -> Option<f64> {
+    let forms = fundamental_forms(surface, u, v)?;
+    let shape_operator = forms.first.invert()? * forms.second;
+    Some(shape_operator.trace() / 2.0)
+}
+
+/// Returns the principal curvatures and directions of `surface` at `(u, v)`.
+/// Returns `None` if `(u, v)` is a singular point.
+/// At the umbilical points, the first direction is that of `surface.uder(u, v)`.
+pub fn curvature<S: ParametricSurface3D>(surface: &S, u: f64, v: f64) -> Option<SurfaceCurvature> {
+    let FundamentalForms { first, second } = fundamental_forms(surface, u, v)?;
+    let shape_operator = first.invert()? * second;
+    let mean = shape_operator.trace() / 2.0;
+    let gaussian = shape_operator.determinant();
+    let disc = f64::sqrt(f64::max(mean * mean - gaussian, 0.0));
+    let (k0, k1) = (mean + disc, mean - disc);
+
+    // the kernel of `second - k0 * first`
+    let a = second - first * k0;
+    let x0 = Vector2::new(a[1][0], -a[0][0]);
+    let x1 = Vector2::new(a[1][1], -a[0][1]);
+    let x = match x0.magnitude2() > x1.magnitude2() {
+        true => x0,
+        false => x1,
+    };
+    let (su, sv) = (surface.uder(u, v), surface.vder(u, v));
+    let n = surface.normal(u, v);
+    let d0 = match disc.so_small() || x.so_small() {
+        true => su.normalize(),
+        false => (su * x.x + sv * x.y).normalize(),
+    };
+    Some(SurfaceCurvature {
+        principal_curvatures: (k0, k1),
+        principal_directions: (d0, n.cross(d0)),
+    })
+}
+
+/// Returns the zebra stripes of `surface` for the light in `direction`.
+///
+/// The surface is sampled at the grid `udivision + 1` by `vdivision + 1` evenly spaced in `range`,
+/// and the element `[i][j]` is `true` if the sample point `(u_i, v_j)` is in a bright stripe.
+/// The stripes are the bands of the angle between the normal and `direction` with the width `width`
+/// in radian, so that the gaps and kinks of the stripes reveal the discontinuities of the normals
+/// and the curvatures.
+pub fn zebra_stripes<S: ParametricSurface3D>(
+    surface: &S,
+    ((u0, u1), (v0, v1)): ((f64, f64), (f64, f64)),
+    (udivision, vdivision): (usize, usize),
+    direction: Vector3,
+    width: f64,
+) -> Vec<Vec<bool>> {
+    let direction = direction.normalize();
+    (0..=udivision)
+        .map(|i| {
+            let u = u0 + (u1 - u0) * i as f64 / udivision as f64;
+            (0..=vdivision)
+                .map(|j| {
+                    let v = v0 + (v1 - v0) * j as f64 / vdivision as f64;
+                    let cos = surface.normal(u, v).dot(direction);
+                    let angle = f64::acos(cos.clamp(-1.0, 1.0));
+                    ((angle / width) as usize).is_multiple_of(2)
+                })
+                .collect()
+        })
+        .collect()
+}
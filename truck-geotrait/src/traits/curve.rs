@@ -38,6 +38,20 @@ pub trait ParametricCurve: Clone {
     /// `None` in default implementation; `Some(period)` if periodic.
     #[inline(always)]
     fn period(&self) -> Option<f64> { None }
+    /// Returns the arc length of the curve in `range` within the error `tol`.
+    /// See [`algo::curve::length`](crate::algo::curve::length).
+    #[inline(always)]
+    fn length(&self, range: (f64, f64), tol: f64) -> f64
+    where Self::Vector: InnerSpace<Scalar = f64> {
+        crate::algo::curve::length(self, range, tol)
+    }
+    /// Returns the parameter at which the arc length from `start` is `length`.
+    /// See [`algo::curve::parameter_at_length`](crate::algo::curve::parameter_at_length).
+    #[inline(always)]
+    fn parameter_at_length(&self, start: f64, length: f64, tol: f64) -> Option<f64>
+    where Self::Vector: InnerSpace<Scalar = f64> {
+        crate::algo::curve::parameter_at_length(self, start, length, tol)
+    }
 }
 
 /// bounded parametric curves i.e. it is guaranteed that the return value of `parameter_range` is not `Bound::Unbounded`.
@@ -251,6 +265,10 @@ pub enum ConcatError<Point: Debug> {
     /// Failed to concat curves since the end point of the first curve is different from the start point of the second curve.
     #[error("The end point {0:?} of the first curve is different from the start point {1:?} of the second curve.")]
     DisconnectedPoints(Point, Point),
+    /// Failed to concat curves since the curves cannot be represented by one curve,
+    /// e.g. intersection curves on different surfaces.
+    #[error("The curves cannot be represented by one curve.")]
+    IncompatibleCurves,
 }
 
 impl<T: Debug> ConcatError<T> {
@@ -261,6 +279,7 @@ impl<T: Debug> ConcatError<T> {
         match self {
             ConcatError::DisconnectedParameters(a, b) => ConcatError::DisconnectedParameters(a, b),
             ConcatError::DisconnectedPoints(p, q) => ConcatError::DisconnectedPoints(f(p), f(q)),
+            ConcatError::IncompatibleCurves => ConcatError::IncompatibleCurves,
         }
     }
 }
@@ -10,7 +10,7 @@ pub use search_parameter::*;
 
 /// parameter range
 pub type ParameterRange = (Bound<f64>, Bound<f64>);
-fn bound2opt<T>(x: Bound<T>) -> Option<T> {
+pub(crate) fn bound2opt<T>(x: Bound<T>) -> Option<T> {
     match x {
         Bound::Included(x) => Some(x),
         Bound::Excluded(x) => Some(x),
@@ -178,6 +178,16 @@ impl<S: BoundedSurface> BoundedSurface for &S {}
 
 impl<S: BoundedSurface> BoundedSurface for Box<S> {}
 
+/// Concats two surfaces adjacent along the boundaries of the parameter ranges.
+pub trait ConcatSurface: Sized {
+    /// Returns the surface which coincides with `self` on the parameter range of `self`, and with `rhs`
+    /// on the range of `rhs` translated to follow the range of `self` in the `u` or `v` direction.
+    ///
+    /// Returns `None` if the back boundary of `self` does not coincide with the front boundary of `rhs`
+    /// in either direction.
+    fn try_concat_surface(&self, rhs: &Self) -> Option<Self>;
+}
+
 /// Whether the surface includes the boundary curve.
 pub trait IncludeCurve<C: ParametricCurve> {
     /// Returns whether the curve `curve` is included in the surface `self`.
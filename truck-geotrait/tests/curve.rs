@@ -1,6 +1,6 @@
 #![cfg(feature = "polynomial")]
 
-use truck_base::{cgmath64::*, tolerance::*};
+use truck_base::{assert_near, cgmath64::*, tolerance::*};
 use truck_geotrait::{polynomial::PolynomialCurve, *};
 
 #[test]
@@ -185,3 +185,73 @@ fn polycurve_intersection_point() {
     println!("searching intersection point error: {}", 10 - count);
     assert!(count >= 7);
 }
+
+#[test]
+fn polycurve_length() {
+    let coef = vec![
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(0.0, 1.0),
+    ];
+    let poly = PolynomialCurve::<Point2>(coef);
+    let primitive = |t: f64| t * f64::sqrt(1.0 + 4.0 * t * t) / 2.0 + f64::asinh(2.0 * t) / 4.0;
+    let length = poly.length((0.0, 1.0), TOLERANCE);
+    assert!(f64::abs(length - primitive(1.0)) < TOLERANCE);
+    let length = poly.length((-0.5, 0.7), TOLERANCE);
+    assert!(f64::abs(length - primitive(0.7) + primitive(-0.5)) < TOLERANCE);
+
+    let t = poly.parameter_at_length(0.2, 0.5, TOLERANCE).unwrap();
+    assert!(f64::abs(primitive(t) - primitive(0.2) - 0.5) < TOLERANCE);
+    assert!(poly.parameter_at_length(99.0, 1000.0, TOLERANCE).is_none());
+    assert!(poly.parameter_at_length(0.0, -1.0, TOLERANCE).is_none());
+    let t = poly.parameter_at_length(0.0, 0.0, TOLERANCE).unwrap();
+    assert_eq!(t, 0.0);
+}
+
+#[test]
+fn polycurve_curvature() {
+    // twisted cubic (t, t^2, t^3)
+    let coef = vec![
+        Vector3::new(0.0, 0.0, 0.0),
+        Vector3::new(1.0, 0.0, 0.0),
+        Vector3::new(0.0, 1.0, 0.0),
+        Vector3::new(0.0, 0.0, 1.0),
+    ];
+    let poly = PolynomialCurve::<Point3>(coef);
+    for i in 0..=10 {
+        let t = -1.0 + 0.2 * i as f64;
+        let (t2, t4) = (t * t, t * t * t * t);
+        let curvature =
+            2.0 * f64::sqrt(9.0 * t4 + 9.0 * t2 + 1.0) / f64::powf(1.0 + 4.0 * t2 + 9.0 * t4, 1.5);
+        assert_near!(algo::curve::curvature(&poly, t), curvature);
+        assert_near!(
+            algo::curve::curvature_vector(&poly, t).magnitude(),
+            curvature
+        );
+        let torsion = 3.0 / (9.0 * t4 + 9.0 * t2 + 1.0);
+        assert_near!(algo::curve::torsion(&poly, t), torsion);
+
+        let frame = algo::curve::frenet_frame(&poly, t).unwrap();
+        assert_near!(frame.tangent, poly.der(t).normalize());
+        assert_near!(
+            frame.normal * curvature,
+            algo::curve::curvature_vector(&poly, t)
+        );
+        assert_near!(frame.tangent.cross(frame.normal), frame.binormal);
+    }
+
+    // parabola (t, t^2)
+    let coef = vec![
+        Vector2::new(0.0, 0.0),
+        Vector2::new(1.0, 0.0),
+        Vector2::new(0.0, 1.0),
+    ];
+    let poly = PolynomialCurve::<Point2>(coef);
+    let t = 0.5;
+    let curvature = 2.0 / f64::powf(1.0 + 4.0 * t * t, 1.5);
+    assert_near!(algo::curve::signed_curvature(&poly, t), curvature);
+    let comb = algo::curve::curvature_comb(&poly, (-1.0, 1.0), 4, 1.0);
+    assert_eq!(comb.len(), 5);
+    assert_near!(comb[2].0, Point2::origin());
+    assert_near!(comb[2].1, Point2::new(0.0, -2.0));
+}
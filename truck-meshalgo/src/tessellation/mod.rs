@@ -125,6 +125,36 @@ where CompressedShell<P, C, S>: MeshedShape
     }
 }
 
+impl<P, C, S> MeshedShape for Compound<P, C, S>
+where Shell<P, C, S>: MeshedShape
+{
+    fn to_polygon(&self) -> PolygonMesh {
+        let mut polygon = PolygonMesh::default();
+        let solid_shells = self.solids().iter().flat_map(Solid::boundaries);
+        solid_shells.chain(self.shells()).for_each(|shell| {
+            polygon.merge(shell.to_polygon());
+        });
+        polygon
+    }
+}
+
+impl<P, C, S> MeshedShape for CompressedCompound<P, C, S>
+where
+    CompressedShell<P, C, S>: MeshedShape,
+    S: Clone,
+{
+    fn to_polygon(&self) -> PolygonMesh {
+        let faces = self.solids.iter().flatten().chain(&self.shells).flatten();
+        let shell = CompressedShell::<P, C, S> {
+            vertices: Vec::new(),
+            edges: Vec::new(),
+            faces: faces.cloned().collect(),
+            vertex_attributes: Vec::new(),
+        };
+        shell.to_polygon()
+    }
+}
+
 /// Trait for tessellating `Shell` and `Solid`.
 pub trait MeshableShape {
     /// Shape whose edges are made polylines and faces polygon surface.
@@ -357,4 +387,115 @@ impl<C: PolylineableCurve, S: RobustMeshableSurface> RobustMeshableShape
     }
 }
 
+impl<C: PolylineableCurve, S: MeshableSurface> MeshableShape for Compound<Point3, C, S> {
+    type MeshedShape = Compound<Point3, PolylineCurve, Option<PolygonMesh>>;
+    fn triangulation(&self, tol: f64) -> Self::MeshedShape {
+        nonpositive_tolerance!(tol);
+        let solids = self.solids().iter().map(|solid| solid.triangulation(tol));
+        let shells = self.shells().iter().map(|shell| shell.triangulation(tol));
+        compound_with_polylines(self, solids.collect(), shells.collect(), tol)
+    }
+}
+
+impl<C: PolylineableCurve, S: RobustMeshableSurface> RobustMeshableShape
+    for Compound<Point3, C, S>
+{
+    type MeshedShape = Compound<Point3, PolylineCurve, Option<PolygonMesh>>;
+    fn robust_triangulation(&self, tol: f64) -> Self::MeshedShape {
+        nonpositive_tolerance!(tol);
+        let solids = self.solids().iter().map(|s| s.robust_triangulation(tol));
+        let shells = self.shells().iter().map(|s| s.robust_triangulation(tol));
+        compound_with_polylines(self, solids.collect(), shells.collect(), tol)
+    }
+}
+
+impl<C: PolylineableCurve, S: MeshableSurface> MeshableShape for CompressedCompound<Point3, C, S> {
+    type MeshedShape = CompressedCompound<Point3, PolylineCurve, Option<PolygonMesh>>;
+    fn triangulation(&self, tol: f64) -> Self::MeshedShape {
+        nonpositive_tolerance!(tol);
+        ccompound_from_cshell(self, ccompound_to_cshell(self).triangulation(tol))
+    }
+}
+
+impl<C: PolylineableCurve, S: RobustMeshableSurface> RobustMeshableShape
+    for CompressedCompound<Point3, C, S>
+{
+    type MeshedShape = CompressedCompound<Point3, PolylineCurve, Option<PolygonMesh>>;
+    fn robust_triangulation(&self, tol: f64) -> Self::MeshedShape {
+        nonpositive_tolerance!(tol);
+        ccompound_from_cshell(self, ccompound_to_cshell(self).robust_triangulation(tol))
+    }
+}
+
+/// Makes the meshed compound from the meshed solids and shells, by converting the curves of the wires into polylines.
+fn compound_with_polylines<C: PolylineableCurve, S>(
+    compound: &Compound<Point3, C, S>,
+    solids: Vec<Solid<Point3, PolylineCurve, Option<PolygonMesh>>>,
+    shells: Vec<Shell<Point3, PolylineCurve, Option<PolygonMesh>>>,
+    tol: f64,
+) -> Compound<Point3, PolylineCurve, Option<PolygonMesh>> {
+    let loose = Compound::<Point3, C, S>::new(
+        Vec::new(),
+        Vec::new(),
+        compound.wires().clone(),
+        compound.vertices().clone(),
+    );
+    let loose = loose.mapped(
+        |point| *point,
+        |curve| PolylineCurve::from_curve(curve, curve.range_tuple(), tol),
+        |_| None::<PolygonMesh>,
+    );
+    Compound::new(
+        solids,
+        shells,
+        loose.wires().clone(),
+        loose.vertices().clone(),
+    )
+}
+
+/// Returns the compressed shell of all faces of the compound with the shared vertices and edges,
+/// so that the edges are tessellated only once.
+fn ccompound_to_cshell<P: Clone, C: Clone, S: Clone>(
+    ccompound: &CompressedCompound<P, C, S>,
+) -> CompressedShell<P, C, S> {
+    let faces = ccompound.solids.iter().flatten().chain(&ccompound.shells);
+    CompressedShell {
+        vertices: ccompound.vertices.clone(),
+        edges: ccompound.edges.clone(),
+        faces: faces.flatten().cloned().collect(),
+        vertex_attributes: ccompound.vertex_attributes.clone(),
+    }
+}
+
+/// Makes the meshed compressed compound from the meshed shell given by [`ccompound_to_cshell`].
+fn ccompound_from_cshell<C, S, D, T>(
+    ccompound: &CompressedCompound<Point3, C, S>,
+    cshell: CompressedShell<Point3, D, T>,
+) -> CompressedCompound<Point3, D, T> {
+    let CompressedShell {
+        vertices,
+        edges,
+        faces,
+        vertex_attributes,
+    } = cshell;
+    let mut faces = faces.into_iter();
+    let mut take_faces =
+        |shell: &Vec<CompressedFace<S>>| -> Vec<_> { faces.by_ref().take(shell.len()).collect() };
+    let solids = ccompound
+        .solids
+        .iter()
+        .map(|solid| solid.iter().map(&mut take_faces).collect())
+        .collect();
+    let shells = ccompound.shells.iter().map(take_faces).collect();
+    CompressedCompound {
+        vertices,
+        edges,
+        solids,
+        shells,
+        wires: ccompound.wires.clone(),
+        vertex_indices: ccompound.vertex_indices.clone(),
+        vertex_attributes,
+    }
+}
+
 mod triangulation;
@@ -161,6 +161,7 @@ where
         CompressedEdge {
             vertices: edge.vertices,
             curve: PolylineCurve::from_curve(curve, curve.range_tuple(), tol),
+            attributes: edge.attributes.clone(),
         }
     };
     #[cfg(not(target_arch = "wasm32"))]
@@ -187,6 +188,7 @@ where
             boundaries,
             orientation: face.orientation,
             surface: polygon,
+            attributes: face.attributes.clone(),
         }
     };
     #[cfg(not(target_arch = "wasm32"))]
@@ -197,6 +199,7 @@ where
         vertices,
         edges,
         faces,
+        vertex_attributes: shell.vertex_attributes.clone(),
     }
 }
 
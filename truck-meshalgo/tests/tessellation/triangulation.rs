@@ -165,3 +165,36 @@ fn robust_closed() {
         .remove_unused_attrs();
     assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
 }
+
+#[test]
+fn compound_triangulation() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let arc = builder::circle_arc(
+        &builder::vertex(Point3::new(2.0, 0.0, 0.0)),
+        &builder::vertex(Point3::new(2.0, 1.0, 0.0)),
+        Point3::new(2.5, 0.5, 0.0),
+    );
+    let compound = Compound::new(vec![cube], Vec::new(), vec![vec![arc].into()], Vec::new());
+
+    let meshed = compound.triangulation(0.01);
+    let polyline = meshed.wires()[0][0].curve();
+    assert!(polyline.len() > 2);
+    let mut mesh = meshed.to_polygon();
+    mesh.put_together_same_attrs(TOLERANCE)
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+
+    let ccompound = compound.compress();
+    let meshed = ccompound.triangulation(0.01);
+    let index = ccompound.wires[0][0].index;
+    assert_eq!(meshed.edges[index].curve.len(), polyline.len());
+    let mut mesh = meshed.to_polygon();
+    mesh.put_together_same_attrs(TOLERANCE)
+        .remove_degenerate_faces()
+        .remove_unused_attrs();
+    assert_eq!(mesh.shell_condition(), ShellCondition::Closed);
+}
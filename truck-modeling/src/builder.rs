@@ -1,6 +1,7 @@
 use crate::{
     errors::Error,
     geom_impls::{self, ArcConnector, ExtrudeConnector, LineConnector, RevoluteConnector},
+    sweep_history::sweep_history,
     topo_traits::*,
     Result,
 };
@@ -12,6 +13,7 @@ type Edge<C> = truck_topology::Edge<Point3, C>;
 type Wire<C> = truck_topology::Wire<Point3, C>;
 type Face<C, S> = truck_topology::Face<Point3, C, S>;
 type Shell<C, S> = truck_topology::Shell<Point3, C, S>;
+type History<C, S> = history::History<Point3, C, S>;
 
 /// Creates and returns a vertex by a three dimensional point.
 /// # Examples
@@ -171,6 +173,43 @@ where
     Face::new(vec![wire], homotopy.to_same_geometry())
 }
 
+/// Returns a homotopic face from `edge0` to `edge1` with the history.
+///
+/// The face is generated by the two edges, and the edge connecting the fronts or the backs of the two edges
+/// is generated by the two vertices. The edges remain in the result as they are.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertices([(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0), (1.0, 1.0, 1.0)]);
+/// let line0 = builder::line(&v[0], &v[1]);
+/// let line1 = builder::line(&v[2], &v[3]);
+/// let (face, history): (Face, History) = builder::homotopy_with_history(&line0, &line1);
+/// assert_eq!(history.generated(&line0), &[EntityID::from(&face)]);
+/// let side = &face.boundaries()[0][1];
+/// assert_eq!(history.generated(&v[1]), &[EntityID::from(side)]);
+/// assert_eq!(history.generated(&v[3]), &[EntityID::from(side)]);
+/// ```
+pub fn homotopy_with_history<C, S>(
+    edge0: &Edge<C>,
+    edge1: &Edge<C>,
+) -> (Face<C, S>, History<C, S>)
+where
+    C: Invertible,
+    Line<Point3>: ToSameGeometry<C>,
+    HomotopySurface<C, C>: ToSameGeometry<S>,
+{
+    let face = homotopy(edge0, edge1);
+    let mut history = history::History::new();
+    let wire = &face.boundaries()[0];
+    history.add_generated(edge0, &face);
+    history.add_generated(edge1, &face);
+    history.add_generated(edge0.back(), &wire[1]);
+    history.add_generated(edge1.back(), &wire[1]);
+    history.add_generated(edge1.front(), &wire[3]);
+    history.add_generated(edge0.front(), &wire[3]);
+    (face, history)
+}
+
 /// Returns a homotopic shell from `wire0` to `wire1`.
 /// # Examples
 /// ```
@@ -357,6 +396,36 @@ where
     Ok(Face::new_unchecked(wires, plane.to_same_geometry()))
 }
 
+/// Try attaching a plane whose boundary is `wires` with the history.
+///
+/// The face is generated by the edges of `wires`, which remain in the result as they are.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertices([(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (0.0, 1.0, 0.0)]);
+/// let wire: Wire = vec![
+///     builder::line(&v[0], &v[1]),
+///     builder::line(&v[1], &v[2]),
+///     builder::line(&v[2], &v[0]),
+/// ]
+/// .into();
+/// let (face, history): (Face, History) = builder::try_attach_plane_with_history(vec![wire.clone()]).unwrap();
+/// wire.edge_iter()
+///     .for_each(|edge| assert_eq!(history.generated(edge), &[EntityID::from(&face)]));
+/// ```
+pub fn try_attach_plane_with_history<C, S>(
+    wires: impl Into<Vec<Wire<C>>>,
+) -> Result<(Face<C, S>, History<C, S>)>
+where
+    C: ParametricCurve3D + BoundedCurve,
+    Plane: IncludeCurve<C> + ToSameGeometry<S>, {
+    let face = try_attach_plane(wires)?;
+    let mut history = history::History::new();
+    face.edge_iter()
+        .for_each(|edge| history.add_generated(&edge, &face));
+    Ok((face, history))
+}
+
 /// Returns another topology whose points, curves, and surfaces are cloned.
 /// # Examples
 /// ```
@@ -373,26 +442,93 @@ pub fn clone<T: Mapped<()>>(elem: &T) -> T { elem.mapped(()) }
 #[inline(always)]
 pub fn transformed<T: Mapped<Matrix4>>(elem: &T, mat: Matrix4) -> T { elem.mapped(mat) }
 
+/// Returns a transformed vertex, edge, wire, face, shell or solid with the history.
+///
+/// Each entity of `elem` is modified into the transformed one.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertices([(0.0, 0.0, 0.0), (1.0, 0.0, 0.0)]);
+/// let edge: Edge = builder::line(&v[0], &v[1]);
+/// let mat = Matrix4::from_translation(Vector3::unit_y());
+/// let (moved, history): (Edge, History) = builder::transformed_with_history(&edge, mat);
+/// assert_eq!(history.modified(&edge), &[EntityID::from(&moved)]);
+/// assert_eq!(history.modified(&v[0]), &[EntityID::from(moved.front())]);
+/// ```
+pub fn transformed_with_history<C, S, T>(elem: &T, mat: Matrix4) -> (T, History<C, S>)
+where T: Mapped<Matrix4> + history::Entities<Point3, C, S> {
+    let res = elem.mapped(mat);
+    // the transformed element has the same structure as `elem`
+    let history = history::History::from_mapped(elem, &res).unwrap();
+    (res, history)
+}
+
 /// Returns a translated vertex, edge, wire, face, shell or solid.
 #[inline(always)]
 pub fn translated<T: Mapped<Matrix4>>(elem: &T, vector: Vector3) -> T {
     transformed(elem, Matrix4::from_translation(vector))
 }
 
-/// Returns a rotated vertex, edge, wire, face, shell or solid.
-pub fn rotated<T: Mapped<Matrix4>>(elem: &T, origin: Point3, axis: Vector3, angle: Rad<f64>) -> T {
+/// Returns a translated vertex, edge, wire, face, shell or solid with the history.
+///
+/// See [`transformed_with_history`] for the history.
+#[inline(always)]
+pub fn translated_with_history<C, S, T>(elem: &T, vector: Vector3) -> (T, History<C, S>)
+where T: Mapped<Matrix4> + history::Entities<Point3, C, S> {
+    transformed_with_history(elem, Matrix4::from_translation(vector))
+}
+
+fn rotation_matrix(origin: Point3, axis: Vector3, angle: Rad<f64>) -> Matrix4 {
     let mat0 = Matrix4::from_translation(-origin.to_vec());
     let mat1 = Matrix4::from_axis_angle(axis, angle);
     let mat2 = Matrix4::from_translation(origin.to_vec());
-    transformed(elem, mat2 * mat1 * mat0)
+    mat2 * mat1 * mat0
 }
 
-/// Returns a scaled vertex, edge, wire, face, shell or solid.
-pub fn scaled<T: Mapped<Matrix4>>(elem: &T, origin: Point3, scalars: Vector3) -> T {
+/// Returns a rotated vertex, edge, wire, face, shell or solid.
+pub fn rotated<T: Mapped<Matrix4>>(elem: &T, origin: Point3, axis: Vector3, angle: Rad<f64>) -> T {
+    transformed(elem, rotation_matrix(origin, axis, angle))
+}
+
+/// Returns a rotated vertex, edge, wire, face, shell or solid with the history.
+///
+/// See [`transformed_with_history`] for the history.
+pub fn rotated_with_history<C, S, T>(
+    elem: &T,
+    origin: Point3,
+    axis: Vector3,
+    angle: Rad<f64>,
+) -> (T, History<C, S>)
+where
+    T: Mapped<Matrix4> + history::Entities<Point3, C, S>,
+{
+    transformed_with_history(elem, rotation_matrix(origin, axis, angle))
+}
+
+fn scaling_matrix(origin: Point3, scalars: Vector3) -> Matrix4 {
     let mat0 = Matrix4::from_translation(-origin.to_vec());
     let mat1 = Matrix4::from_nonuniform_scale(scalars[0], scalars[1], scalars[2]);
     let mat2 = Matrix4::from_translation(origin.to_vec());
-    transformed(elem, mat2 * mat1 * mat0)
+    mat2 * mat1 * mat0
+}
+
+/// Returns a scaled vertex, edge, wire, face, shell or solid.
+pub fn scaled<T: Mapped<Matrix4>>(elem: &T, origin: Point3, scalars: Vector3) -> T {
+    transformed(elem, scaling_matrix(origin, scalars))
+}
+
+/// Returns a scaled vertex, edge, wire, face, shell or solid with the history.
+///
+/// See [`transformed_with_history`] for the history.
+pub fn scaled_with_history<C, S, T>(
+    elem: &T,
+    origin: Point3,
+    scalars: Vector3,
+) -> (T, History<C, S>)
+where
+    T: Mapped<Matrix4> + history::Entities<Point3, C, S>,
+{
+    transformed_with_history(elem, scaling_matrix(origin, scalars))
 }
 
 /// Sweeps a vertex, an edge, a wire, a face, or a shell by a vector.
@@ -448,6 +584,39 @@ where T: Sweep<Matrix4, LineConnector, ExtrudeConnector, Swept> {
     elem.sweep(trsl, LineConnector, ExtrudeConnector { vector })
 }
 
+/// Sweeps a vertex, an edge, a wire, a face, or a shell along a vector with the history.
+///
+/// Each vertex of `elem` generates the side edge and the copy of the vertex, each edge generates
+/// the side face and the copy of the edge, and each face generates the copy of the face.
+/// The entities of `elem` remain in the result as they are.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// let v = builder::vertices([(0.0, 0.0, 0.0), (1.0, 0.0, 0.0)]);
+/// let edge: Edge = builder::line(&v[0], &v[1]);
+/// let face: Face = builder::tsweep(&edge, Vector3::unit_y());
+/// let (solid, history): (Solid, History) = builder::tsweep_with_history(&face, Vector3::unit_z());
+///
+/// // the side face generated by the edge and the top edge
+/// let side_faces: Vec<_> = solid.face_iter().filter(|f| f.edge_iter().any(|e| e.id() == edge.id())).collect();
+/// assert_eq!(side_faces.len(), 2); // the side face and the bottom face
+/// let generated = history.generated(&edge);
+/// assert_eq!(generated.len(), 2);
+/// assert!(generated.contains(&EntityID::Face(side_faces[1].id())));
+///
+/// // the top face generated by the face
+/// let top = solid.boundaries()[0].last().unwrap();
+/// assert_eq!(history.generated(&face), &[EntityID::from(top)]);
+/// ```
+pub fn tsweep_with_history<C, S, T, Swept>(elem: &T, vector: Vector3) -> (Swept, History<C, S>)
+where
+    T: Sweep<Matrix4, LineConnector, ExtrudeConnector, Swept> + history::Entities<Point3, C, S>,
+    Swept: history::Entities<Point3, C, S>, {
+    let res = tsweep(elem, vector);
+    let history = sweep_history(elem, &res);
+    (res, history)
+}
+
 /// Sweeps a vertex, an edge, a wire, a face, or a shell by the rotation.
 /// # Details
 /// If the absolute value of `angle` is more than 2π rad, then the result is closed shape.
@@ -610,6 +779,41 @@ fn whole_rsweep<T: ClosedSweep<Matrix4, ArcConnector, RevoluteConnector, Swept>,
     )
 }
 
+/// Sweeps a vertex, an edge, a wire, a face, or a shell by the rotation with the history.
+///
+/// Each vertex of `elem` generates the side edges and the copies of the vertex on the way, each edge
+/// generates the side faces and the copies of the edge, and each face generates the last copy of the face.
+/// The entities of `elem` remain in the result as they are.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// const PI: Rad<f64> = Rad(std::f64::consts::PI);
+/// let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+/// let (circle, history): (Wire, History) =
+///     builder::rsweep_with_history(&v, Point3::origin(), Vector3::unit_z(), PI * 2.0);
+///
+/// // all the edges and the other vertices of the circle are generated by the vertex.
+/// let generated = history.generated(&v);
+/// assert_eq!(generated.len(), circle.len() * 2 - 1);
+/// assert!(circle.edge_iter().all(|edge| generated.contains(&EntityID::from(edge))));
+/// ```
+pub fn rsweep_with_history<C, S, T, Swept, R>(
+    elem: &T,
+    origin: Point3,
+    axis: Vector3,
+    angle: R,
+) -> (Swept, History<C, S>)
+where
+    T: ClosedSweep<Matrix4, ArcConnector, RevoluteConnector, Swept>
+        + history::Entities<Point3, C, S>,
+    Swept: history::Entities<Point3, C, S>,
+    R: Into<Rad<f64>>,
+{
+    let res = rsweep(elem, origin, axis, angle);
+    let history = sweep_history(elem, &res);
+    (res, history)
+}
+
 /// Creates a cone by R-sweeping.
 /// # Examples
 /// ```
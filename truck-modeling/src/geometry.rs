@@ -10,6 +10,7 @@ pub use truck_polymesh::PolylineCurve;
 #[derive(
     Clone,
     Debug,
+    PartialEq,
     Serialize,
     Deserialize,
     From,
@@ -64,6 +65,90 @@ impl Transformed<Matrix4> for Curve {
     }
 }
 
+impl ParameterTransform for Curve {
+    /// Transforms the parameter. A line is not transformed, since its range is fixed to `(0.0, 1.0)`.
+    fn parameter_transform(&mut self, scalar: f64, r#move: f64) -> &mut Self {
+        match self {
+            Curve::Line(_) => {}
+            Curve::BSplineCurve(curve) => {
+                curve.parameter_transform(scalar, r#move);
+            }
+            Curve::NurbsCurve(curve) => {
+                curve.parameter_transform(scalar, r#move);
+            }
+            Curve::IntersectionCurve(curve) => {
+                curve.leader_mut().parameter_transform(scalar, r#move);
+            }
+        }
+        self
+    }
+}
+
+impl Concat<Curve> for Curve {
+    type Output = Curve;
+    /// Concats two curves.
+    ///
+    /// - Two collinear lines are concatenated into a line. Lines which are not collinear cannot
+    ///   be concatenated, and the parameter ranges of lines are not compared since they are fixed.
+    /// - A line and a B-spline curve are concatenated as B-spline curves, where the line is
+    ///   parametrized by the range of length 1 adjacent to the other curve.
+    /// - The curves are concatenated as NURBS curves if one of them is rational.
+    /// - Two intersection curves on the same surfaces are concatenated by their leaders. An intersection
+    ///   curve cannot be concatenated with the other curves or the intersection curves on the other surfaces.
+    fn try_concat(&self, rhs: &Curve) -> std::result::Result<Curve, ConcatError<Point3>> {
+        let bspline = |curve: &Curve, t: f64| match curve {
+            Curve::Line(line) => {
+                let mut curve = BSplineCurve::from(*line);
+                curve.knot_translate(t);
+                Some(curve)
+            }
+            Curve::BSplineCurve(curve) => Some(curve.clone()),
+            _ => None,
+        };
+        // the line `self` ends at the start of `rhs`, and the line `rhs` starts at the end of `self`.
+        let (t0, t1) = (rhs.range_tuple().0 - 1.0, self.range_tuple().1);
+        let nurbs = |curve: &Curve, t: f64| match curve {
+            Curve::NurbsCurve(curve) => Some(curve.clone()),
+            _ => bspline(curve, t).map(NurbsCurve::from),
+        };
+        match (self, rhs) {
+            (Curve::Line(line0), Curve::Line(line1)) => {
+                if !line0.1.near(&line1.0) {
+                    return Err(ConcatError::DisconnectedPoints(line0.1, line1.0));
+                }
+                let dir0 = (line0.1 - line0.0).normalize();
+                let dir1 = (line1.1 - line1.0).normalize();
+                match dir0.near(&dir1) {
+                    true => Ok(Curve::Line(Line(line0.0, line1.1))),
+                    false => Err(ConcatError::IncompatibleCurves),
+                }
+            }
+            (Curve::IntersectionCurve(curve0), Curve::IntersectionCurve(curve1))
+                if curve0.surface0() == curve1.surface0()
+                    && curve0.surface1() == curve1.surface1() =>
+            {
+                let leader = curve0.leader().try_concat(&**curve1.leader())?;
+                Ok(Curve::IntersectionCurve(IntersectionCurve::new(
+                    curve0.surface0().clone(),
+                    curve0.surface1().clone(),
+                    Box::new(leader),
+                )))
+            }
+            (Curve::IntersectionCurve(_), _) | (_, Curve::IntersectionCurve(_)) => {
+                Err(ConcatError::IncompatibleCurves)
+            }
+            (Curve::NurbsCurve(_), _) | (_, Curve::NurbsCurve(_)) => {
+                let (curve0, curve1) = (nurbs(self, t0).unwrap(), nurbs(rhs, t1).unwrap());
+                Ok(Curve::NurbsCurve(curve0.try_concat(&curve1)?))
+            }
+            _ => {
+                let (curve0, curve1) = (bspline(self, t0).unwrap(), bspline(rhs, t1).unwrap());
+                Ok(Curve::BSplineCurve(curve0.try_concat(&curve1)?))
+            }
+        }
+    }
+}
+
 impl From<IntersectionCurve<BSplineCurve<Point3>, Surface, Surface>> for Curve {
     fn from(c: IntersectionCurve<BSplineCurve<Point3>, Surface, Surface>) -> Curve {
         let (surface0, surface1, leader) = c.destruct();
@@ -111,15 +196,40 @@ impl Curve {
     }
 }
 
+impl ToNurbs<D1> for Curve {
+    type Range = (f64, f64);
+    type Nurbs = NurbsCurve<Vector4>;
+    /// Returns `None` for the intersection curve, which has no exact NURBS representation.
+    fn to_nurbs_in(&self, range: (f64, f64)) -> Option<NurbsCurve<Vector4>> {
+        match self {
+            Curve::Line(curve) => curve.to_nurbs_in(range),
+            Curve::BSplineCurve(curve) => curve.to_nurbs_in(range),
+            Curve::NurbsCurve(curve) => curve.to_nurbs_in(range),
+            Curve::IntersectionCurve(_) => None,
+        }
+    }
+    /// Returns `None` for the intersection curve, which has no exact NURBS representation.
+    fn to_nurbs(&self) -> Option<NurbsCurve<Vector4>> {
+        match self {
+            Curve::Line(curve) => curve.to_nurbs(),
+            Curve::BSplineCurve(curve) => curve.to_nurbs(),
+            Curve::NurbsCurve(curve) => curve.to_nurbs(),
+            Curve::IntersectionCurve(_) => None,
+        }
+    }
+}
+
 /// 3-dimensional surfaces
 #[derive(
     Clone,
     Debug,
+    PartialEq,
     Serialize,
     Deserialize,
     From,
     TryInto,
     ParametricSurface,
+    BoundedSurface,
     ParameterDivision2D,
     Invertible,
     SearchParameterD2,
@@ -276,6 +386,37 @@ impl SearchNearestParameter<D2> for Surface {
     }
 }
 
+impl ToNurbs<D2> for Surface {
+    type Range = ((f64, f64), (f64, f64));
+    type Nurbs = NurbsSurface<Vector4>;
+    #[inline(always)]
+    fn to_nurbs_in(&self, range: ((f64, f64), (f64, f64))) -> Option<NurbsSurface<Vector4>> {
+        derive_surface_method!(self, ToNurbs::<D2>::to_nurbs_in, range)
+    }
+    #[inline(always)]
+    fn to_nurbs(&self) -> Option<NurbsSurface<Vector4>> {
+        derive_surface_method!(self, ToNurbs::<D2>::to_nurbs,)
+    }
+}
+
+impl ConcatSurface for Surface {
+    /// Concats B-spline and NURBS surfaces. The surfaces are concatenated as NURBS surfaces
+    /// if one of them is rational. Returns `None` for the other surfaces.
+    fn try_concat_surface(&self, rhs: &Surface) -> Option<Surface> {
+        let nurbs = |surface: &Surface| match surface {
+            Surface::BSplineSurface(surface) => Some(NurbsSurface::from(surface.clone())),
+            Surface::NurbsSurface(surface) => Some(surface.clone()),
+            _ => None,
+        };
+        match (self, rhs) {
+            (Surface::BSplineSurface(surface0), Surface::BSplineSurface(surface1)) => {
+                Some(surface0.try_concat_surface(surface1)?.into())
+            }
+            _ => Some(nurbs(self)?.try_concat_surface(&nurbs(rhs)?)?.into()),
+        }
+    }
+}
+
 impl ToSameGeometry<Surface> for HomotopySurface<Curve, Curve> {
     fn to_same_geometry(&self) -> Surface {
         let curve0 = self.first_curve().clone().lift_up();
@@ -109,4 +109,5 @@ mod multi_sweep;
 /// primitive shapes
 pub mod primitive;
 mod sweep;
+mod sweep_history;
 mod topo_impls;
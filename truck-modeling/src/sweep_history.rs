@@ -0,0 +1,109 @@
+use rustc_hash::FxHashSet as HashSet;
+use truck_topology::{history::*, *};
+
+/// Returns the pairs of the input entities and their copies on the next level of sweeping.
+type Level<T> = Vec<(T, T)>;
+
+/// Derives the history of sweeping `input` into `output` from the structure of `output`.
+///
+/// The swept shape consists of the levels of the copies of `input` connected by the side edges and faces.
+/// Starting from `input` itself as the first level, the side edge of each vertex is the new edge incident to
+/// the vertex, and the side face of each edge is the new face containing the edge and the side edges. The copy of the edge on
+/// the next level is the remaining edge in the boundary of the side face. The last copies of the faces are the
+/// new faces whose edges are the last copies of the edges. The side entities and the copies are recorded as
+/// generated by the input entities. The input entities remain in the output as they are.
+pub(super) fn sweep_history<P, C, S, T0, T1>(input: &T0, output: &T1) -> History<P, C, S>
+where
+    T0: Entities<P, C, S> + ?Sized,
+    T1: Entities<P, C, S> + ?Sized, {
+    let mut history = History::new();
+    let (input_edges, input_faces) = (input.edges(), input.faces());
+    let input_edge_ids: HashSet<_> = input_edges.iter().map(Edge::id).collect();
+    let input_face_ids: HashSet<_> = input_faces.iter().map(Face::id).collect();
+    let new_edges: Vec<_> = (output.edges().into_iter())
+        .filter(|edge| !input_edge_ids.contains(&edge.id()))
+        .collect();
+    let new_faces: Vec<_> = (output.faces().into_iter())
+        .filter(|face| !input_face_ids.contains(&face.id()))
+        .collect();
+    let mut used_edges = HashSet::default();
+    let mut used_faces = HashSet::default();
+    let mut vertices: Level<Vertex<P>> = (input.vertices().into_iter())
+        .map(|v| (v.clone(), v))
+        .collect();
+    let mut edges: Level<Edge<P, C>> = input_edges.iter().map(|e| (e.clone(), e.clone())).collect();
+    let mut last_edges = edges.clone();
+    while !vertices.is_empty() {
+        used_edges.extend(edges.iter().map(|(_, e)| e.id()));
+        // side edges
+        let mut side_edges = Vec::new();
+        let next_vertices: Level<Vertex<P>> = vertices
+            .iter()
+            .filter_map(|(v0, v)| {
+                let side_edge = new_edges.iter().find(|edge| {
+                    !used_edges.contains(&edge.id()) && (edge.front() == v || edge.back() == v)
+                })?;
+                used_edges.insert(side_edge.id());
+                side_edges.push((v.id(), side_edge.id()));
+                history.add_generated(v0, side_edge);
+                let w = match side_edge.front() == v {
+                    true => side_edge.back(),
+                    false => side_edge.front(),
+                };
+                if w == v0 {
+                    return None;
+                }
+                history.add_generated(v0, w);
+                Some((v0.clone(), w.clone()))
+            })
+            .collect();
+        let is_side_edge = |edge: &Edge<P, C>| side_edges.iter().any(|(_, id)| *id == edge.id());
+        // side faces
+        let next_edges: Level<Edge<P, C>> = edges
+            .iter()
+            .filter_map(|(e0, e)| {
+                let side_face = new_faces.iter().find(|face| {
+                    !used_faces.contains(&face.id())
+                        && face.edge_iter().any(|edge| edge.id() == e.id())
+                        && face.edge_iter().any(|edge| is_side_edge(&edge))
+                })?;
+                used_faces.insert(side_face.id());
+                history.add_generated(e0, side_face);
+                let copy = side_face
+                    .edge_iter()
+                    .find(|edge| edge.id() != e.id() && !is_side_edge(edge))?;
+                if input_edge_ids.contains(&copy.id()) {
+                    return None;
+                }
+                history.add_generated(e0, &copy);
+                Some((e0.clone(), copy))
+            })
+            .collect();
+        if !next_edges.is_empty() {
+            last_edges = next_edges.clone();
+        }
+        vertices = next_vertices;
+        edges = next_edges;
+    }
+    // the last copies of the faces
+    new_faces
+        .iter()
+        .filter(|face| !used_faces.contains(&face.id()))
+        .for_each(|face| {
+            let originals: HashSet<_> = face
+                .edge_iter()
+                .filter_map(|edge| {
+                    let (e0, _) = last_edges.iter().find(|(_, e)| e.id() == edge.id())?;
+                    Some(e0.id())
+                })
+                .collect();
+            let input_face = input_faces.iter().find(|input_face| {
+                let ids: HashSet<_> = input_face.edge_iter().map(|edge| edge.id()).collect();
+                ids == originals
+            });
+            if let Some(input_face) = input_face {
+                history.add_generated(input_face, face);
+            }
+        });
+    history
+}
@@ -0,0 +1,52 @@
+use truck_modeling::*;
+use truck_topology::{binary, compress::CompressedSolid};
+
+#[test]
+fn binary_round_trip() {
+    let v = builder::vertex(Point3::new(0.5, 0.0, 0.0));
+    let w: Wire = builder::rsweep(&v, Point3::new(0.75, 0.0, 0.0), Vector3::unit_y(), Rad(7.0));
+    let face: Face = builder::try_attach_plane(&[w]).unwrap();
+    let torus: Solid = builder::rsweep(&face, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    torus
+        .face_iter()
+        .next()
+        .unwrap()
+        .set_attribute("name", "top");
+    let compressed = torus.compress();
+
+    let mut bytes = Vec::new();
+    binary::write(&compressed, &mut bytes).unwrap();
+    let json = serde_json::to_vec(&compressed).unwrap();
+    assert!(bytes.len() < json.len());
+
+    let read: CompressedSolid<Point3, Curve, Surface> = binary::read(bytes.as_slice()).unwrap();
+    assert_eq!(read, compressed);
+    assert_eq!(serde_json::to_vec(&read).unwrap(), json);
+    let solid = Solid::extract(read).unwrap();
+    assert!(solid.is_geometric_consistent());
+
+    // truncated data
+    let res = binary::read::<Point3, Curve, Surface, _>(&bytes[..bytes.len() / 2]);
+    assert!(res.is_err());
+}
+
+#[test]
+fn binary_shared_geometry() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    // the shells of the cube and its copy have the same geometries
+    let mut compressed = cube.compress();
+    compressed.boundaries.push(compressed.boundaries[0].clone());
+
+    let mut bytes = Vec::new();
+    binary::write(&compressed, &mut bytes).unwrap();
+    let mut single = Vec::new();
+    binary::write(&cube.compress(), &mut single).unwrap();
+    // the geometries of the copy are written only by the references
+    assert!(bytes.len() < single.len() * 3 / 2);
+
+    let read: CompressedSolid<Point3, Curve, Surface> = binary::read(bytes.as_slice()).unwrap();
+    assert_eq!(read, compressed);
+}
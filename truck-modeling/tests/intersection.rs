@@ -0,0 +1,138 @@
+use truck_modeling::*;
+
+#[test]
+fn intersection_curve_fallback() {
+    let plane0 = Plane::new(
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+    let plane1 = Plane::new(
+        Point3::origin(),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+    );
+    let leader = BSplineCurve::from(Line(Point3::origin(), Point3::new(1.0, 1.0, 0.0)));
+    let curve = Curve::from(IntersectionCurve::new(
+        Surface::Plane(plane0),
+        Surface::Plane(plane1),
+        leader,
+    ));
+    assert!(curve.to_nurbs().is_none());
+
+    let line = Curve::Line(Line(Point3::new(0.0, 1.0, 0.0), Point3::new(1.0, 0.0, 0.0)));
+    let res = intersect_curves(&curve, &line, TOLERANCE);
+    assert_eq!(res.len(), 1);
+    assert_near!(curve.subs(res[0].0), Point3::new(0.5, 0.5, 0.0));
+    assert_near!(line.subs(res[0].1), Point3::new(0.5, 0.5, 0.0));
+
+    let plane = Surface::Plane(Plane::new(
+        Point3::new(0.25, -1.0, -1.0),
+        Point3::new(0.25, 2.0, -1.0),
+        Point3::new(0.25, -1.0, 2.0),
+    ));
+    let res = intersect_curve_surface(&curve, &plane, TOLERANCE);
+    assert_eq!(res.len(), 1);
+    let (t, (u, v)) = res[0];
+    assert_near!(curve.subs(t), Point3::new(0.25, 0.25, 0.0));
+    assert_near!(plane.subs(u, v), Point3::new(0.25, 0.25, 0.0));
+}
+
+#[test]
+fn concat_intersection_curves() {
+    let plane0 = Surface::Plane(Plane::new(
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    ));
+    let plane1 = Surface::Plane(Plane::new(
+        Point3::origin(),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+    ));
+    let plane2 = Surface::Plane(Plane::new(
+        Point3::origin(),
+        Point3::new(1.0, 1.0, 1.0),
+        Point3::new(0.0, 0.0, 1.0),
+    ));
+    let (p0, p1, p2) = (
+        Point3::origin(),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(2.0, 2.0, 0.0),
+    );
+    let leader0 = BSplineCurve::from(Line(p0, p1));
+    let mut leader1 = BSplineCurve::from(Line(p1, p2));
+    leader1.knot_translate(1.0);
+    let curve0 = Curve::from(IntersectionCurve::new(
+        plane0.clone(),
+        plane1.clone(),
+        leader0,
+    ));
+    let curve1 = Curve::from(IntersectionCurve::new(
+        plane0.clone(),
+        plane1.clone(),
+        leader1.clone(),
+    ));
+    let Ok(Curve::IntersectionCurve(curve)) = curve0.try_concat(&curve1) else {
+        panic!("intersection curves on the same surfaces must be concatenated.");
+    };
+    assert_eq!(curve.range_tuple(), (0.0, 2.0));
+    assert_near!(curve.subs(1.5), Point3::new(1.5, 1.5, 0.0));
+
+    // an intersection curve and a line
+    let line = Curve::Line(Line(p1, p2));
+    assert_eq!(
+        curve0.try_concat(&line).unwrap_err(),
+        ConcatError::IncompatibleCurves
+    );
+    // intersection curves on the different surfaces
+    let other = Curve::from(IntersectionCurve::new(plane0, plane2, leader1));
+    assert_eq!(
+        curve0.try_concat(&other).unwrap_err(),
+        ConcatError::IncompatibleCurves
+    );
+}
+
+#[test]
+fn concat_lines() {
+    let (p0, p1, p2) = (
+        Point3::origin(),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(3.0, 3.0, 0.0),
+    );
+    let line0 = Curve::Line(Line(p0, p1));
+    let line1 = Curve::Line(Line(p1, p2));
+    assert_eq!(line0.try_concat(&line1), Ok(Curve::Line(Line(p0, p2))));
+
+    // the lines which are not collinear
+    let line2 = Curve::Line(Line(p1, Point3::new(2.0, 0.0, 0.0)));
+    assert_eq!(
+        line0.try_concat(&line2).unwrap_err(),
+        ConcatError::IncompatibleCurves
+    );
+    // the disconnected lines
+    assert_eq!(
+        line1.try_concat(&line0).unwrap_err(),
+        ConcatError::DisconnectedPoints(p2, p0)
+    );
+
+    // a line and a B-spline curve
+    let mut bspline = BSplineCurve::from(Line(p1, p2));
+    bspline.knot_translate(1.0);
+    let Ok(Curve::BSplineCurve(curve)) = line0.try_concat(&Curve::BSplineCurve(bspline)) else {
+        panic!("a line and a B-spline curve must be concatenated into a B-spline curve.");
+    };
+    assert_eq!(curve.range_tuple(), (0.0, 2.0));
+    assert_near!(curve.subs(1.5), Point3::new(2.0, 2.0, 0.0));
+    let bspline = BSplineCurve::from(Line(p2, p0));
+    let Ok(Curve::BSplineCurve(curve)) = Curve::BSplineCurve(bspline).try_concat(&line0) else {
+        panic!("a B-spline curve and a line must be concatenated into a B-spline curve.");
+    };
+    assert_eq!(curve.range_tuple(), (0.0, 2.0));
+    assert_near!(curve.subs(1.5), Point3::new(0.5, 0.5, 0.0));
+
+    // a line is not reparametrized
+    let mut line = line0.clone();
+    line.parameter_transform(2.0, 1.0);
+    assert_eq!(line, line0);
+}
@@ -0,0 +1,163 @@
+use crate::face_domain::FaceDomain;
+use truck_geometry::prelude::*;
+use truck_topology::*;
+
+/// The number of the directions of rays tried in the classification.
+const RAY_TRIALS: usize = 16;
+
+/// The position of a point relative to a solid.
+#[derive(Debug)]
+pub enum Classification<S> {
+    /// The point is inside the solid.
+    In,
+    /// The point is outside the solid.
+    Out,
+    /// The point is on the face with the ID.
+    On(FaceID<S>),
+}
+
+// The traits are implemented by hand, since `FaceID<S>` implements them for all `S`.
+impl<S> Clone for Classification<S> {
+    #[inline(always)]
+    fn clone(&self) -> Self { *self }
+}
+
+impl<S> Copy for Classification<S> {}
+
+impl<S> PartialEq for Classification<S> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::In, Self::In) | (Self::Out, Self::Out) => true,
+            (Self::On(id0), Self::On(id1)) => id0 == id1,
+            _ => false,
+        }
+    }
+}
+
+impl<S> Eq for Classification<S> {}
+
+/// Classifies points by the exact geometry of solids.
+pub trait Classify<S> {
+    /// Classifies `point` into inside, outside or on the boundary of the solid.
+    ///
+    /// # Details
+    /// If the distance between `point` and a face is less than `tol`, the point is on the face.
+    /// Otherwise, the rays from `point` are intersected with the surfaces, and the intersections are
+    /// restricted to the trimmed domains of the faces. A ray which touches a surface tangentially or
+    /// passes near an edge is discarded and another ray is tried.
+    /// No triangulation is required: each face is only sampled on a grid of its parameter domain,
+    /// and the faces whose bounding boxes do not meet the ray are skipped.
+    ///
+    /// Returns `None` if no rays are determined to be reliable.
+    ///
+    /// # Examples
+    /// ```
+    /// use truck_modeling::*;
+    /// use truck_shapeops::{Classification, Classify};
+    ///
+    /// let v = builder::vertex(Point3::origin());
+    /// let e = builder::tsweep(&v, Vector3::unit_x());
+    /// let f = builder::tsweep(&e, Vector3::unit_y());
+    /// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    ///
+    /// let inside = Point3::new(0.5, 0.5, 0.5);
+    /// assert_eq!(cube.classify(inside, 0.01), Some(Classification::In));
+    /// let outside = Point3::new(0.5, 0.5, 1.5);
+    /// assert_eq!(cube.classify(outside, 0.01), Some(Classification::Out));
+    ///
+    /// let on = Point3::new(0.5, 0.5, 1.0);
+    /// let top = cube.face_iter().find(|face| {
+    ///     let normal = face.oriented_surface().normal(0.5, 0.5);
+    ///     normal.near(&Vector3::unit_z())
+    /// });
+    /// assert_eq!(cube.classify(on, 0.01), Some(Classification::On(top.unwrap().id())));
+    /// ```
+    fn classify(&self, point: Point3, tol: f64) -> Option<Classification<S>>;
+}
+
+/// Returns the `i`-th direction of the spherical Fibonacci lattice, which does not
+/// coincide with the axes for avoiding the rays along the edges of axis-aligned shapes.
+fn ray_direction(i: usize) -> Vector3 {
+    let golden_angle = std::f64::consts::PI * (3.0 - f64::sqrt(5.0));
+    let z = 1.0 - (2.0 * i as f64 + 1.0) / RAY_TRIALS as f64;
+    let r = f64::sqrt(1.0 - z * z);
+    let t = golden_angle * i as f64 + 0.1;
+    Vector3::new(r * f64::cos(t), r * f64::sin(t), z)
+}
+
+/// Returns the winding number of the boundary around `point` counted along the ray,
+/// or `None` if the ray is not reliable.
+fn winding_number<S>(
+    domains: &[(FaceID<S>, FaceDomain<S>)],
+    point: Point3,
+    direction: Vector3,
+    tol: f64,
+) -> Option<i32>
+where
+    S: ParametricSurface3D
+        + ParameterDivision2D
+        + SearchParameter<D2, Point = Point3>
+        + SearchNearestParameter<D2, Point = Point3>,
+{
+    domains
+        .iter()
+        .filter(|(_, domain)| domain.meets_ray(point, direction))
+        .try_fold(0, |winding, (_, domain)| {
+            domain
+                .ray_intersections(point, direction, true)?
+                .into_iter()
+                .filter(|(_, t)| *t > 0.0)
+                .try_fold(winding, |winding, (uv, t)| {
+                    let hit = point + direction * t;
+                    if domain.distance_to_boundary(hit) < tol {
+                        return None;
+                    }
+                    match domain.trimmed(uv) {
+                        Some(uv) if domain.normal(uv).dot(direction) > 0.0 => Some(winding + 1),
+                        Some(_) => Some(winding - 1),
+                        None => Some(winding),
+                    }
+                })
+        })
+}
+
+impl<C, S> Classify<S> for Solid<Point3, C, S>
+where
+    C: ParametricCurve3D + BoundedCurve + ParameterDivision1D<Point = Point3>,
+    S: ParametricSurface3D
+        + ParameterDivision2D
+        + SearchParameter<D2, Point = Point3>
+        + SearchNearestParameter<D2, Point = Point3>,
+{
+    fn classify(&self, point: Point3, tol: f64) -> Option<Classification<S>> {
+        nonpositive_tolerance!(tol);
+        let domains = self
+            .face_iter()
+            .map(|face| Some((face.id(), FaceDomain::try_new(face, tol)?)))
+            .collect::<Option<Vec<_>>>()?;
+        let on_face = domains.iter().find(|(_, domain)| {
+            if !domain.bounding_box().contains(point) {
+                return false;
+            }
+            if domain.distance_to_boundary(point) < tol {
+                return true;
+            }
+            let Some(uv) = domain.nearest_parameter(point) else {
+                return false;
+            };
+            domain.surface().subs(uv.x, uv.y).distance(point) < tol && domain.trimmed(uv).is_some()
+        });
+        if let Some((id, _)) = on_face {
+            return Some(Classification::On(*id));
+        }
+        (0..RAY_TRIALS).find_map(|i| {
+            match winding_number(&domains, point, ray_direction(i), tol)? > 0 {
+                true => Some(Classification::In),
+                false => Some(Classification::Out),
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests;
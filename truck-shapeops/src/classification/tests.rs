@@ -0,0 +1,84 @@
+use super::*;
+use std::f64::consts::PI;
+use truck_modeling::{builder, Solid};
+
+fn cube() -> Solid {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    builder::tsweep(&f, Vector3::unit_z())
+}
+
+#[test]
+fn classify_cube() {
+    let cube = cube();
+    let cls = |x: f64, y: f64, z: f64| cube.classify(Point3::new(x, y, z), 0.01).unwrap();
+    assert_eq!(cls(0.5, 0.5, 0.5), Classification::In);
+    assert_eq!(cls(0.1, 0.9, 0.2), Classification::In);
+    assert_eq!(cls(1.5, 0.5, 0.5), Classification::Out);
+    assert_eq!(cls(-0.5, -0.5, -0.5), Classification::Out);
+    // the rays from these points pass the edges and the vertices along the axes
+    assert_eq!(cls(0.5, 0.5, 3.0), Classification::Out);
+    assert_eq!(cls(0.0, 0.0, 3.0), Classification::Out);
+    assert_eq!(cls(1.0, 1.0, -2.0), Classification::Out);
+    // on the faces, the edges and the vertices
+    assert!(matches!(cls(0.5, 0.5, 1.0), Classification::On(_)));
+    assert!(matches!(cls(0.0, 0.5, 0.0), Classification::On(_)));
+    assert!(matches!(cls(1.0, 1.0, 1.0), Classification::On(_)));
+    assert!(matches!(cls(0.5, 0.5, 1.005), Classification::On(_)));
+}
+
+#[test]
+fn classify_on_face() {
+    let cube = cube();
+    cube.face_iter().for_each(|face| {
+        let surface = face.oriented_surface();
+        let point = surface.subs(0.3, 0.6);
+        let res = cube.classify(point, 0.01).unwrap();
+        assert_eq!(res, Classification::On(face.id()));
+    });
+}
+
+#[test]
+fn classify_punched_cube() {
+    let cube = cube();
+    let v = builder::vertex(Point3::new(0.5, 0.25, -0.5));
+    let w = builder::rsweep(&v, Point3::new(0.5, 0.5, 0.0), Vector3::unit_z(), Rad(7.0));
+    let f = builder::try_attach_plane(&[w]).unwrap();
+    let mut cylinder = builder::tsweep(&f, Vector3::unit_z() * 2.0);
+    cylinder.not();
+    let punched = crate::and(&cube, &cylinder, 0.05).unwrap();
+    let cls = |x: f64, y: f64, z: f64| punched.classify(Point3::new(x, y, z), 0.01).unwrap();
+    assert_eq!(cls(0.5, 0.5, 0.5), Classification::Out);
+    assert_eq!(cls(0.5, 0.6, 0.2), Classification::Out);
+    assert_eq!(cls(0.1, 0.1, 0.5), Classification::In);
+    assert_eq!(cls(0.5, 0.9, 0.5), Classification::In);
+    assert!(matches!(cls(0.5, 0.75, 0.5), Classification::On(_)));
+}
+
+#[test]
+fn classify_sphere() {
+    let (r, c) = (1.5, Point3::new(1.0, -1.0, 0.5));
+    let v = builder::vertex(c + Vector3::new(0.0, 0.0, r));
+    let wire = builder::rsweep(&v, c, Vector3::unit_y(), Rad(PI));
+    let shell = builder::cone(&wire, Vector3::unit_z(), Rad(7.0));
+    let sphere = Solid::new(vec![shell]);
+    let cls = |p: Point3| sphere.classify(p, 0.01).unwrap();
+    assert_eq!(cls(c), Classification::In);
+    assert_eq!(cls(c + Vector3::new(0.5, 0.5, 0.5)), Classification::In);
+    assert_eq!(cls(c + Vector3::new(1.0, 1.0, 1.0)), Classification::Out);
+    assert_eq!(cls(c + Vector3::new(0.0, 0.0, 3.0)), Classification::Out);
+    assert!(matches!(
+        cls(c + Vector3::new(0.0, 0.0, r)),
+        Classification::On(_)
+    ));
+    assert!(matches!(
+        cls(c + Vector3::new(r, 0.0, 0.0)),
+        Classification::On(_)
+    ));
+
+    let mut inverted = sphere.clone();
+    inverted.not();
+    let res = inverted.classify(c, 0.01).unwrap();
+    assert_eq!(res, Classification::Out);
+}
@@ -0,0 +1,326 @@
+use crate::{parameter::closest_period, TRIALS};
+use rustc_hash::FxHashSet as HashSet;
+use truck_geometry::prelude::*;
+use truck_meshalgo::prelude::*;
+use truck_topology::{history::Entities, *};
+
+/// A vertex, an edge or a face with the sample points for the initial guesses.
+/// The parameters are stored in `Point2`, and the parameter of a curve is stored in `x`.
+enum Element<C, S> {
+    Vertex(Point3),
+    Edge {
+        curve: C,
+        range: (f64, f64),
+    },
+    Face {
+        surface: S,
+        triangles: Vec<[Point2; 3]>,
+    },
+}
+
+/// A sample point with its parameter.
+type Sample = (Point2, Point3);
+
+struct SampledElement<C, S> {
+    element: Element<C, S>,
+    samples: Vec<Sample>,
+    bdd_box: BoundingBox<Point3>,
+}
+
+fn include(tri: &[Point2; 3], uv: Point2) -> bool {
+    let area = |a: Point2, b: Point2, c: Point2| (b - a).perp_dot(c - a);
+    let whole = area(tri[0], tri[1], tri[2]);
+    let eps = TOLERANCE * f64::abs(whole);
+    (0..3).all(|i| area(tri[i], tri[(i + 1) % 3], uv) * whole.signum() > -eps)
+}
+
+impl<C, S> SampledElement<C, S>
+where
+    C: PolylineableCurve + SearchNearestParameter<D1, Point = Point3>,
+    S: MeshableSurface + SearchNearestParameter<D2, Point = Point3>,
+{
+    fn from_vertex(vertex: &Vertex<Point3>) -> Self {
+        let point = vertex.point();
+        Self {
+            element: Element::Vertex(point),
+            samples: vec![(Point2::origin(), point)],
+            bdd_box: BoundingBox::from_iter([point]),
+        }
+    }
+
+    fn from_edge(edge: &Edge<Point3, C>, tol: f64) -> Self {
+        let curve = edge.curve();
+        let range = curve.range_tuple();
+        let (params, points) = curve.parameter_division(range, tol);
+        let samples: Vec<_> = params
+            .into_iter()
+            .zip(points)
+            .map(|(t, p)| (Point2::new(t, 0.0), p))
+            .collect();
+        Self {
+            bdd_box: expanded_box(samples.iter().map(|x| x.1), tol),
+            element: Element::Edge { curve, range },
+            samples,
+        }
+    }
+
+    fn from_face(face: &Face<Point3, C, S>, tol: f64) -> Option<Self> {
+        let shell: Shell<Point3, C, S> = vec![face.clone()].into();
+        let mesh = shell.triangulation(tol).face_iter().next()?.surface()?;
+        let (positions, uv_coords) = (mesh.positions(), mesh.uv_coords());
+        let uv = |v: StandardVertex| Some(Point2::from_vec(uv_coords.get(v.uv?)?));
+        let triangles = mesh
+            .faces()
+            .triangle_iter()
+            .map(|tri| Some([uv(tri[0])?, uv(tri[1])?, uv(tri[2])?]))
+            .collect::<Option<Vec<_>>>()?;
+        let mut sampled = HashSet::default();
+        let samples: Vec<_> = mesh
+            .faces()
+            .triangle_iter()
+            .flatten()
+            .filter(|v| sampled.insert(v.pos))
+            .map(|v| Some((uv(v)?, positions[v.pos])))
+            .collect::<Option<_>>()?;
+        Some(Self {
+            bdd_box: expanded_box(samples.iter().map(|x| x.1), tol),
+            element: Element::Face {
+                surface: face.surface(),
+                triangles,
+            },
+            samples,
+        })
+    }
+
+    /// Projects `point` to the element.
+    /// Returns `None` if the foot of the perpendicular is out of the element.
+    fn project(&self, point: Point3, hint: Point2) -> Option<(Point2, Point3)> {
+        match &self.element {
+            Element::Vertex(p) => Some((Point2::origin(), *p)),
+            Element::Edge { curve, range } => {
+                let t = curve.search_nearest_parameter(point, Some(hint.x), TRIALS)?;
+                let t = closest_period(t, hint.x, curve.period());
+                match range.0 - TOLERANCE < t && t < range.1 + TOLERANCE {
+                    true => Some((Point2::new(t, 0.0), curve.subs(t))),
+                    false => None,
+                }
+            }
+            Element::Face { surface, triangles } => {
+                let (u, v) = surface.search_nearest_parameter(point, Some(hint.into()), TRIALS)?;
+                let uv = Point2::new(
+                    closest_period(u, hint.x, surface.u_period()),
+                    closest_period(v, hint.y, surface.v_period()),
+                );
+                match triangles.iter().any(|tri| include(tri, uv)) {
+                    true => Some((uv, surface.subs(uv.x, uv.y))),
+                    false => None,
+                }
+            }
+        }
+    }
+}
+
+fn expanded_box(points: impl IntoIterator<Item = Point3>, tol: f64) -> BoundingBox<Point3> {
+    let mut bdd_box: BoundingBox<Point3> = points.into_iter().collect();
+    if !bdd_box.is_empty() {
+        let diag = Vector3::new(tol, tol, tol);
+        bdd_box.push(bdd_box.min() - diag);
+        bdd_box.push(bdd_box.max() + diag);
+    }
+    bdd_box
+}
+
+/// The lower bound of the distance between the points in the boxes.
+fn box_distance(box0: &BoundingBox<Point3>, box1: &BoundingBox<Point3>) -> f64 {
+    let (min0, max0, min1, max1) = (box0.min(), box0.max(), box1.min(), box1.max());
+    let gap = |i: usize| f64::max(0.0, f64::max(min0[i] - max1[i], min1[i] - max0[i]));
+    Vector3::new(gap(0), gap(1), gap(2)).magnitude()
+}
+
+type ClosestPoints = (f64, Point3, Point3);
+
+/// Refines the closest points by projecting the points to each element alternately.
+fn alternating_projections<C, S>(
+    elem0: &SampledElement<C, S>,
+    elem1: &SampledElement<C, S>,
+    (mut uv0, mut pt0): (Point2, Point3),
+    hint1: Point2,
+) -> Option<ClosestPoints>
+where
+    C: PolylineableCurve + SearchNearestParameter<D1, Point = Point3>,
+    S: MeshableSurface + SearchNearestParameter<D2, Point = Point3>,
+{
+    let (mut uv1, mut pt1) = elem1.project(pt0, hint1)?;
+    for _ in 0..TRIALS {
+        let (new_uv0, new_pt0) = elem0.project(pt1, uv0)?;
+        let (new_uv1, new_pt1) = elem1.project(new_pt0, uv1)?;
+        let converged = new_pt0.near(&pt0) && new_pt1.near(&pt1);
+        (uv0, pt0, uv1, pt1) = (new_uv0, new_pt0, new_uv1, new_pt1);
+        if converged {
+            break;
+        }
+    }
+    Some((pt0.distance(pt1), pt0, pt1))
+}
+
+/// The number of the consecutive samples bounded by a box in [`closest_samples`].
+const CHUNK_SIZE: usize = 16;
+
+/// Returns the closest pair of the samples.
+///
+/// The consecutive samples are close to each other, so they are bounded by a box in chunks,
+/// and the pairs of the chunks far apart are pruned by the boxes.
+fn closest_samples(samples0: &[Sample], samples1: &[Sample]) -> Option<(f64, Sample, Sample)> {
+    fn chunks(samples: &[Sample]) -> Vec<(BoundingBox<Point3>, &[Sample])> {
+        samples
+            .chunks(CHUNK_SIZE)
+            .map(|chunk| (chunk.iter().map(|x| x.1).collect(), chunk))
+            .collect()
+    }
+    let (chunks0, chunks1) = (chunks(samples0), chunks(samples1));
+    let mut pairs: Vec<_> = chunks0
+        .iter()
+        .flat_map(|x| {
+            let iter = chunks1.iter();
+            iter.map(move |y| (box_distance(&x.0, &y.0), x.1, y.1))
+        })
+        .collect();
+    pairs.sort_by(|x, y| x.0.total_cmp(&y.0));
+    let mut res: Option<(f64, Sample, Sample)> = None;
+    for (lower_bound, chunk0, chunk1) in pairs {
+        if matches!(res, Some((dist, _, _)) if dist <= lower_bound) {
+            break;
+        }
+        chunk0.iter().for_each(|x| {
+            chunk1.iter().for_each(|y| {
+                let dist = x.1.distance(y.1);
+                if !matches!(res, Some((min, _, _)) if min <= dist) {
+                    res = Some((dist, *x, *y));
+                }
+            })
+        });
+    }
+    res
+}
+
+fn closest_points<C, S>(
+    elem0: &SampledElement<C, S>,
+    elem1: &SampledElement<C, S>,
+) -> Option<ClosestPoints>
+where
+    C: PolylineableCurve + SearchNearestParameter<D1, Point = Point3>,
+    S: MeshableSurface + SearchNearestParameter<D2, Point = Point3>,
+{
+    let (dist, (uv0, pt0), (uv1, pt1)) = closest_samples(&elem0.samples, &elem1.samples)?;
+    let sampled = (dist, pt0, pt1);
+    let refined = match (&elem0.element, &elem1.element) {
+        (
+            Element::Edge {
+                curve: c0,
+                range: r0,
+            },
+            Element::Edge {
+                curve: c1,
+                range: r1,
+            },
+        ) => algo::curve::search_closest_parameter(c0, c1, (uv0.x, uv1.x), TRIALS)
+            .filter(|(t0, t1)| {
+                let inside = |t: f64, r: (f64, f64)| r.0 - TOLERANCE < t && t < r.1 + TOLERANCE;
+                inside(*t0, *r0) && inside(*t1, *r1)
+            })
+            .map(|(t0, t1)| {
+                let (pt0, pt1) = (c0.subs(t0), c1.subs(t1));
+                (pt0.distance(pt1), pt0, pt1)
+            })
+            .or_else(|| alternating_projections(elem0, elem1, (uv0, pt0), uv1)),
+        _ => alternating_projections(elem0, elem1, (uv0, pt0), uv1),
+    };
+    // If the closest points are on the boundary, the sampled points are the best
+    // among the points of this pair, and the exact ones are given by the boundary elements.
+    match refined {
+        Some(refined) if refined.0 < sampled.0 => Some(refined),
+        _ => Some(sampled),
+    }
+}
+
+fn sampled_elements<C, S>(
+    shape: &impl Entities<Point3, C, S>,
+    tol: f64,
+) -> Option<Vec<SampledElement<C, S>>>
+where
+    C: PolylineableCurve + SearchNearestParameter<D1, Point = Point3>,
+    S: MeshableSurface + SearchNearestParameter<D2, Point = Point3>,
+{
+    let vertices = shape.vertices().into_iter();
+    let edges = shape.edges().into_iter();
+    let faces = shape.faces().into_iter();
+    vertices
+        .map(|v| Some(SampledElement::from_vertex(&v)))
+        .chain(edges.map(|e| Some(SampledElement::from_edge(&e, tol))))
+        .chain(faces.map(|f| SampledElement::from_face(&f, tol)))
+        .collect()
+}
+
+/// Returns the minimum distance between two shapes and the closest points on each shape.
+///
+/// The shapes may be vertices, edges, wires, faces, shells and solids.
+/// The elements are sampled with the accuracy `tol`, and the pairs of the elements far apart are
+/// pruned by their bounding boxes, and so are the pairs of the chunks of the samples. The closest
+/// points of the remaining pairs are refined by `search_nearest_parameter` and `search_closest_parameter`.
+/// Returns `None` if one of the shapes is empty or the tessellation of a face fails.
+///
+/// # Remarks
+/// - Only the boundaries are measured, i.e. the distance between a solid and a shape inside it is not zero.
+/// - If the shapes intersect, the distance is zero up to `tol`.
+///
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+/// let vertex = builder::vertex(Point3::new(0.5, 0.5, 3.0));
+///
+/// let (dist, p, q) = truck_shapeops::distance(&cube, &vertex, 0.05).unwrap();
+/// assert_near!(dist, 2.0);
+/// assert_near!(p, Point3::new(0.5, 0.5, 1.0));
+/// assert_near!(q, Point3::new(0.5, 0.5, 3.0));
+/// ```
+pub fn distance<C, S>(
+    shape0: &impl Entities<Point3, C, S>,
+    shape1: &impl Entities<Point3, C, S>,
+    tol: f64,
+) -> Option<(f64, Point3, Point3)>
+where
+    C: PolylineableCurve + SearchNearestParameter<D1, Point = Point3>,
+    S: MeshableSurface + SearchNearestParameter<D2, Point = Point3>,
+{
+    nonpositive_tolerance!(tol);
+    let elements0 = sampled_elements(shape0, tol)?;
+    let elements1 = sampled_elements(shape1, tol)?;
+    let mut pairs: Vec<_> = elements0
+        .iter()
+        .flat_map(|x| {
+            let iter = elements1.iter();
+            iter.map(move |y| (box_distance(&x.bdd_box, &y.bdd_box), x, y))
+        })
+        .collect();
+    pairs.sort_by(|x, y| x.0.total_cmp(&y.0));
+    let mut res: Option<ClosestPoints> = None;
+    for (lower_bound, elem0, elem1) in pairs {
+        if matches!(res, Some((dist, _, _)) if dist <= lower_bound) {
+            break;
+        }
+        if let Some(closest) = closest_points(elem0, elem1) {
+            if !matches!(res, Some((dist, _, _)) if dist <= closest.0) {
+                res = Some(closest);
+            }
+        }
+    }
+    res
+}
+
+#[cfg(test)]
+mod tests;
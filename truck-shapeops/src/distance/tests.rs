@@ -0,0 +1,96 @@
+use super::*;
+use truck_modeling::{builder, Curve, Solid, Surface};
+
+fn cube(origin: Point3) -> Solid {
+    let v = builder::vertex(origin);
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    builder::tsweep(&f, Vector3::unit_z())
+}
+
+#[test]
+fn vertex_vertex() {
+    let v0 = builder::vertex(Point3::new(0.0, 0.0, 0.0));
+    let v1 = builder::vertex(Point3::new(1.0, 2.0, 2.0));
+    let (dist, p, q) = distance::<Curve, Surface>(&v0, &v1, 0.01).unwrap();
+    assert_near!(dist, 3.0);
+    assert_near!(p, v0.point());
+    assert_near!(q, v1.point());
+}
+
+#[test]
+fn edge_edge() {
+    // skew lines
+    let v0 = builder::vertex(Point3::new(-1.0, 0.0, 0.0));
+    let e0 = builder::tsweep(&v0, Vector3::new(2.0, 0.0, 0.0));
+    let v1 = builder::vertex(Point3::new(0.5, -1.0, 1.0));
+    let e1 = builder::tsweep(&v1, Vector3::new(0.0, 2.0, 0.0));
+    let (dist, p, q) = distance::<Curve, Surface>(&e0, &e1, 0.01).unwrap();
+    assert_near!(dist, 1.0);
+    assert_near!(p, Point3::new(0.5, 0.0, 0.0));
+    assert_near!(q, Point3::new(0.5, 0.0, 1.0));
+}
+
+#[test]
+fn cube_cube() {
+    // the closest points are the corners
+    let cube0 = cube(Point3::origin());
+    let cube1 = cube(Point3::new(2.0, 3.0, 3.0));
+    let (dist, p, q) = distance(&cube0, &cube1, 0.05).unwrap();
+    assert_near!(dist, 3.0);
+    assert_near!(p, Point3::new(1.0, 1.0, 1.0));
+    assert_near!(q, Point3::new(2.0, 3.0, 3.0));
+
+    // the closest points are inner points of the faces
+    let cube1 = cube(Point3::new(0.25, 0.5, 2.5));
+    let (dist, p, q) = distance(&cube0, &cube1, 0.05).unwrap();
+    assert_near!(dist, 1.5);
+    assert_near!(p.z, 1.0);
+    assert_near!(q.z, 2.5);
+    assert_near!(p.x, q.x);
+    assert_near!(p.y, q.y);
+}
+
+#[test]
+fn sphere_cube() {
+    let (r, c) = (0.5, Point3::new(0.5, 0.5, 3.0));
+    let v = builder::vertex(c + Vector3::new(0.0, 0.0, r));
+    let wire = builder::rsweep(&v, c, Vector3::unit_y(), Rad(std::f64::consts::PI));
+    let shell = builder::cone(&wire, Vector3::unit_z(), Rad(7.0));
+    let sphere = Solid::new(vec![shell]);
+    let (dist, p, q) = distance(&sphere, &cube(Point3::origin()), 0.05).unwrap();
+    assert_near!(dist, 1.5);
+    assert_near!(p, Point3::new(0.5, 0.5, 2.5));
+    assert_near!(q, Point3::new(0.5, 0.5, 1.0));
+}
+
+#[test]
+fn touching_cubes() {
+    let cube0 = cube(Point3::origin());
+    let cube1 = cube(Point3::new(1.0, 0.5, 0.5));
+    let (dist, _, _) = distance(&cube0, &cube1, 0.05).unwrap();
+    assert!(dist < TOLERANCE);
+}
+
+#[test]
+fn pruned_samples() {
+    // the samples on two helices
+    let helix = |radius: f64, z: f64| -> Vec<Sample> {
+        (0..1000)
+            .map(|i| {
+                let t = i as f64 / 50.0;
+                let pt = Point3::new(radius * f64::cos(t), radius * f64::sin(t), z + t / 10.0);
+                (Point2::new(t, 0.0), pt)
+            })
+            .collect()
+    };
+    let (samples0, samples1) = (helix(1.0, 0.0), helix(1.5, 0.3));
+    let (dist, x, y) = closest_samples(&samples0, &samples1).unwrap();
+    let exact = samples0
+        .iter()
+        .flat_map(|x| samples1.iter().map(move |y| x.1.distance(y.1)))
+        .min_by(f64::total_cmp)
+        .unwrap();
+    assert_eq!(dist, exact);
+    assert_eq!(x.1.distance(y.1), dist);
+}
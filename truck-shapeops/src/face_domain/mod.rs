@@ -0,0 +1,343 @@
+use crate::{
+    parameter::{closest_period, surface_parameter},
+    TRIALS,
+};
+use truck_geometry::prelude::*;
+use truck_meshalgo::prelude::*;
+use truck_topology::*;
+
+/// The rays whose angle with the tangent plane is smaller than this (in radian) are regarded as tangent.
+const GRAZING: f64 = 1.0e-3;
+
+/// The trimmed domain of a face in the parameter space of the surface with the sampled grid.
+#[derive(Clone, Debug)]
+pub(crate) struct FaceDomain<S> {
+    surface: S,
+    orientation: bool,
+    /// boundaries in the parameter space
+    boundaries: Vec<PolylineCurve<Point2>>,
+    /// boundaries in the model space
+    polylines: Vec<PolylineCurve<Point3>>,
+    /// the division of the bounding box of the boundaries in the parameter space
+    division: (Vec<f64>, Vec<f64>),
+    /// the points on the division
+    grid: Vec<Vec<Point3>>,
+    bdd_box: BoundingBox<Point3>,
+}
+
+fn is_singular<S: ParametricSurface3D>(surface: &S, uv: Point2) -> (bool, bool) {
+    (
+        surface.uder(uv.x, uv.y).so_small(),
+        surface.vder(uv.x, uv.y).so_small(),
+    )
+}
+
+/// Moves `t` by the period so that `t - hint` has the sign `sign`.
+fn directed_period(t: f64, hint: f64, period: f64, sign: f64) -> f64 {
+    let t = closest_period(t, hint, Some(period));
+    match (t - hint) * sign < 0.0 {
+        true => t + sign * period,
+        false => t,
+    }
+}
+
+/// Returns `-1.0` if `t` is the start of `range` and `1.0` if `t` is the end of `range`.
+fn end_sign(t: f64, range: Option<(f64, f64)>) -> Option<f64> {
+    match range {
+        Some((t0, _)) if t.near(&t0) => Some(-1.0),
+        Some((_, t1)) if t.near(&t1) => Some(1.0),
+        _ => None,
+    }
+}
+
+/// Lifts the points on `surface` to the parameter space continuously.
+fn parameter_polyline<S>(surface: &S, points: &[Point3]) -> Option<PolylineCurve<Point2>>
+where S: ParametricSurface3D
+        + SearchParameter<D2, Point = Point3>
+        + SearchNearestParameter<D2, Point = Point3> {
+    let (up, vp) = (surface.u_period(), surface.v_period());
+    let (urange, vrange) = surface.try_range_tuple();
+    let mut previous: Option<Point2> = None;
+    let mut res = Vec::with_capacity(points.len());
+    for pt in points {
+        let hint = previous.map(|uv| (uv.x, uv.y));
+        let (u, v) = surface_parameter(surface, *pt, hint)?;
+        let Some(p) = previous else {
+            res.push(Point2::new(u, v));
+            previous = Some(Point2::new(u, v));
+            continue;
+        };
+        let mut uv = Point2::new(closest_period(u, p.x, up), closest_period(v, p.y, vp));
+        // At a pole, the degenerate parameter moves along the end of the domain with the face on the left,
+        // i.e. decreasing at the start of the other parameter and increasing at the end.
+        match is_singular(surface, p) {
+            (true, _) if !p.x.near(&uv.x) => {
+                if let (Some(period), Some(sign)) = (up, end_sign(p.y, vrange)) {
+                    uv.x = directed_period(u, p.x, period, -sign);
+                }
+                res.push(Point2::new(uv.x, p.y));
+            }
+            (_, true) if !p.y.near(&uv.y) => {
+                if let (Some(period), Some(sign)) = (vp, end_sign(p.x, urange)) {
+                    uv.y = directed_period(v, p.y, period, sign);
+                }
+                res.push(Point2::new(p.x, uv.y));
+            }
+            _ => {}
+        }
+        res.push(uv);
+        previous = Some(uv);
+    }
+    Some(res.into())
+}
+
+impl<S> FaceDomain<S>
+where S: ParametricSurface3D
+        + ParameterDivision2D
+        + SearchParameter<D2, Point = Point3>
+        + SearchNearestParameter<D2, Point = Point3>
+{
+    /// Creates the domain of `face` with the accuracy `tol`.
+    pub(crate) fn try_new<C>(face: &Face<Point3, C, S>, tol: f64) -> Option<Self>
+    where C: ParametricCurve3D + BoundedCurve + ParameterDivision1D<Point = Point3> {
+        let surface = face.surface();
+        let polylines: Vec<PolylineCurve<Point3>> = face
+            .absolute_boundaries()
+            .iter()
+            .map(|wire| {
+                let mut points: Vec<Point3> = wire
+                    .iter()
+                    .flat_map(|edge| {
+                        let curve = edge.curve();
+                        let (_, mut points) = curve.parameter_division(curve.range_tuple(), tol);
+                        if !edge.orientation() {
+                            points.reverse();
+                        }
+                        points.pop();
+                        points
+                    })
+                    .collect();
+                points.push(*points.first()?);
+                Some(points.into())
+            })
+            .collect::<Option<_>>()?;
+        let mut boundaries: Vec<PolylineCurve<Point2>> = polylines
+            .iter()
+            .map(|poly| parameter_polyline(&surface, poly))
+            .collect::<Option<_>>()?;
+        // The domain is moved into one period, since the boundaries are lifted independently.
+        let outer = boundaries.first()?.clone();
+        boundaries.iter_mut().skip(1).for_each(|poly| {
+            let (p, q) = (outer[0], poly[0]);
+            let delta = Vector2::new(
+                closest_period(q.x, p.x, surface.u_period()) - q.x,
+                closest_period(q.y, p.y, surface.v_period()) - q.y,
+            );
+            poly.iter_mut().for_each(|uv| *uv += delta);
+        });
+        let uv_box: BoundingBox<Point2> = boundaries.iter().flat_map(|poly| poly.iter()).collect();
+        let (min, max) = (uv_box.min(), uv_box.max());
+        let division = surface.parameter_division(((min.x, max.x), (min.y, max.y)), tol);
+        let grid: Vec<Vec<Point3>> = division
+            .0
+            .iter()
+            .map(|u| division.1.iter().map(|v| surface.subs(*u, *v)).collect())
+            .collect();
+        let mut bdd_box: BoundingBox<Point3> = grid.iter().flatten().collect();
+        bdd_box += polylines
+            .iter()
+            .flat_map(|poly| poly.iter())
+            .collect::<BoundingBox<_>>();
+        let diag = Vector3::new(tol, tol, tol);
+        bdd_box.push(bdd_box.min() - diag);
+        bdd_box.push(bdd_box.max() + diag);
+        Some(Self {
+            surface,
+            orientation: face.orientation(),
+            boundaries,
+            polylines,
+            division,
+            grid,
+            bdd_box,
+        })
+    }
+
+    /// Returns the bounding box of the face.
+    #[inline(always)]
+    pub(crate) fn bounding_box(&self) -> BoundingBox<Point3> { self.bdd_box }
+
+    /// Returns the boundaries in the parameter space.
+    #[inline(always)]
+    pub(crate) fn boundaries(&self) -> &[PolylineCurve<Point2>] { &self.boundaries }
+
+    /// Whether the ray meets the bounding box of the face.
+    pub(crate) fn meets_ray(&self, origin: Point3, direction: Vector3) -> bool {
+        let (min, max) = (self.bdd_box.min(), self.bdd_box.max());
+        let (mut t0, mut t1) = (0.0, f64::INFINITY);
+        (0..3).all(|i| {
+            if direction[i].so_small() {
+                return min[i] <= origin[i] && origin[i] <= max[i];
+            }
+            let (a, b) = (
+                (min[i] - origin[i]) / direction[i],
+                (max[i] - origin[i]) / direction[i],
+            );
+            t0 = f64::max(t0, f64::min(a, b));
+            t1 = f64::min(t1, f64::max(a, b));
+            t0 <= t1
+        })
+    }
+
+    /// Returns the surface of the face.
+    #[inline(always)]
+    pub(crate) fn surface(&self) -> &S { &self.surface }
+
+    /// Returns the normal of the face, i.e. the normal of the surface considering the orientation.
+    pub(crate) fn normal(&self, uv: Point2) -> Vector3 {
+        let normal = self.surface.normal(uv.x, uv.y);
+        match self.orientation {
+            true => normal,
+            false => -normal,
+        }
+    }
+
+    /// Returns the parameter equivalent to `uv` in the domain, if exists.
+    pub(crate) fn trimmed(&self, uv: Point2) -> Option<Point2> {
+        let (u0, v0) = (self.division.0[0], self.division.1[0]);
+        let shift = |t: f64, t0: f64, period: Option<f64>| match period {
+            Some(p) => t - p * f64::floor((t - t0 + TOLERANCE) / p),
+            None => t,
+        };
+        let uv = Point2::new(
+            shift(uv.x, u0, self.surface.u_period()),
+            shift(uv.y, v0, self.surface.v_period()),
+        );
+        match polyline_curve::include(&self.boundaries, uv) {
+            true => Some(uv),
+            false => None,
+        }
+    }
+
+    /// Returns the distance between `point` and the boundary of the face.
+    pub(crate) fn distance_to_boundary(&self, point: Point3) -> f64 {
+        self.polylines
+            .iter()
+            .flat_map(|poly| poly.windows(2))
+            .map(|seg| {
+                let (a, b) = (seg[0], seg[1]);
+                let t = (point - a).dot(b - a) / f64::max((b - a).magnitude2(), TOLERANCE2);
+                let t = t.clamp(0.0, 1.0);
+                point.distance(a + (b - a) * t)
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    /// Returns the parameter of the point on the untrimmed surface nearest to `point`.
+    pub(crate) fn nearest_parameter(&self, point: Point3) -> Option<Point2> {
+        let (i, j) = (0..self.grid.len())
+            .flat_map(|i| (0..self.grid[i].len()).map(move |j| (i, j)))
+            .min_by(|(i0, j0), (i1, j1)| {
+                let d0 = self.grid[*i0][*j0].distance2(point);
+                let d1 = self.grid[*i1][*j1].distance2(point);
+                d0.total_cmp(&d1)
+            })?;
+        let hint = (self.division.0[i], self.division.1[j]);
+        let (u, v) = self.surface.search_nearest_parameter(point, hint, TRIALS)?;
+        Some(Point2::new(u, v))
+    }
+
+    /// Returns the intersections of the ray and the untrimmed surface in the bounding box
+    /// of the domain as the pairs of the parameter of the surface and the one of the ray.
+    /// If `strict`, returns `None` if the ray touches the surface tangentially or the search of
+    /// an intersection fails. Otherwise, the failed intersections are skipped.
+    pub(crate) fn ray_intersections(
+        &self,
+        origin: Point3,
+        direction: Vector3,
+        strict: bool,
+    ) -> Option<Vec<(Point2, f64)>> {
+        let ray = Line(origin, origin + direction);
+        let (udiv, vdiv) = &self.division;
+        let mut res: Vec<(Point2, f64)> = Vec::new();
+        for i in 1..udiv.len() {
+            for j in 1..vdiv.len() {
+                let (p00, p01) = (self.grid[i - 1][j - 1], self.grid[i - 1][j]);
+                let (p10, p11) = (self.grid[i][j - 1], self.grid[i][j]);
+                let triangles = [
+                    ([p00, p10, p11], [(i - 1, j - 1), (i, j - 1), (i, j)]),
+                    ([p00, p11, p01], [(i - 1, j - 1), (i, j), (i - 1, j)]),
+                ];
+                for (tri, idcs) in triangles {
+                    let Some((s, t, ray_t)) = ray_triangle(origin, direction, tri) else {
+                        continue;
+                    };
+                    let param = |k: usize| Point2::new(udiv[idcs[k].0], vdiv[idcs[k].1]);
+                    let hint = param(0) + (param(1) - param(0)) * s + (param(2) - param(0)) * t;
+                    let searched = algo::surface::search_intersection_parameter(
+                        &self.surface,
+                        (hint.x, hint.y),
+                        &ray,
+                        ray_t,
+                        TRIALS,
+                    );
+                    let ((u, v), ray_t) = match searched {
+                        Some(x) => x,
+                        // The ray passes near the triangle but does not meet the surface.
+                        None if !strict || s < 0.0 || t < 0.0 || s + t > 1.0 => continue,
+                        None => return None,
+                    };
+                    let uv = Point2::new(u, v);
+                    let cos = self.surface.normal(u, v).dot(direction.normalize());
+                    if strict && cos.abs() < GRAZING {
+                        return None;
+                    }
+                    let is_new = res.iter().all(|(uv0, t0)| {
+                        !(ray.subs(*t0).near(&ray.subs(ray_t)) && self.same_point(*uv0, uv))
+                    });
+                    if is_new {
+                        res.push((uv, ray_t));
+                    }
+                }
+            }
+        }
+        Some(res)
+    }
+
+    fn same_point(&self, uv0: Point2, uv1: Point2) -> bool {
+        let diff = |t0: f64, t1: f64, period: Option<f64>| {
+            let d = t1 - t0;
+            match period {
+                Some(p) => d - p * f64::round(d / p),
+                None => d,
+            }
+        };
+        let du = diff(uv0.x, uv1.x, self.surface.u_period());
+        let dv = diff(uv0.y, uv1.y, self.surface.v_period());
+        Vector2::new(du, dv).so_small()
+            || self
+                .surface
+                .subs(uv0.x, uv0.y)
+                .near(&self.surface.subs(uv1.x, uv1.y))
+    }
+}
+
+/// Intersects the ray and the slightly enlarged triangle.
+/// Returns the barycentric coordinates and the parameter of the ray.
+fn ray_triangle(origin: Point3, dir: Vector3, tri: [Point3; 3]) -> Option<(f64, f64, f64)> {
+    const MARGIN: f64 = 0.05;
+    let (e0, e1) = (tri[1] - tri[0], tri[2] - tri[0]);
+    let p = dir.cross(e1);
+    let det = e0.dot(p);
+    if det.so_small2() {
+        return None;
+    }
+    let r = origin - tri[0];
+    let s = r.dot(p) / det;
+    let q = r.cross(e0);
+    let t = dir.dot(q) / det;
+    let inside = -MARGIN < s && -MARGIN < t && s + t < 1.0 + MARGIN;
+    match inside {
+        true => Some((s, t, e1.dot(q) / det)),
+        false => None,
+    }
+}
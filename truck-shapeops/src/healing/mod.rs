@@ -12,12 +12,18 @@ type Shell<P, C, S> = CompressedShell<P, C, S>;
 trait SP<S>: Fn(&S, Point3, Option<(f64, f64)>) -> Option<(f64, f64)> {}
 impl<S, F> SP<S> for F where F: Fn(&S, Point3, Option<(f64, f64)>) -> Option<(f64, f64)> {}
 
+mod remove_degenerate_edges;
+use remove_degenerate_edges::remove_degenerate_edges;
+
 mod split_closed_edges;
 use split_closed_edges::split_closed_edges;
 
 mod split_closed_faces;
 use split_closed_faces::split_closed_faces;
 
+mod sew;
+pub use sew::sew;
+
 /// Splits closed edges and faces
 ///
 /// # Details
@@ -29,9 +35,18 @@ use split_closed_faces::split_closed_faces;
 /// When such shapes are handled by truck, this method is applied at the stage
 /// of `CompressedShell` and `CompressedSolid`, which are intermediate forms.
 ///
+/// The following cases are handled.
+/// - A face whose boundary goes around a pole once, e.g. a sphere cap or a cone with the apex,
+///   is split into two faces by the edges from the boundary to a new vertex at the pole.
+/// - A face bounded by a seam between two poles, e.g. a whole sphere, is split into two faces
+///   by the edge on the opposite side of the seam.
+/// - A face covering a doubly periodic surface, e.g. a whole torus, is split into four faces.
+/// - Faces on surfaces periodic in one direction, e.g. cylinders, are split until the boundaries
+///   become simple.
+///
 /// # Remarks
-/// Boundary simplification is still only implemented for cylinders.
-/// It has not yet been implemented for cases involving singularities, such as spherical surfaces.
+/// A face around a pole is split only if it has no other boundaries.
+/// The degenerate edges at the poles have to be removed by [`RemoveDegenerateEdges`] in advance.
 pub trait SplitClosedEdgesAndFaces {
     /// Splits closed edges and faces
     fn split_closed_edges_and_faces(&mut self, tol: f64);
@@ -128,5 +143,33 @@ where
     }
 }
 
+/// Removes degenerate edges
+///
+/// # Details
+/// The edges shorter than `tol`, e.g. the edges at the poles of spheres exported by some CAD systems,
+/// are removed and their end vertices are merged. The faces bounded only by degenerate edges lose
+/// their boundaries. This pass should be applied before [`SplitClosedEdgesAndFaces`] if the shape
+/// has such edges.
+pub trait RemoveDegenerateEdges {
+    /// Removes degenerate edges
+    fn remove_degenerate_edges(&mut self, tol: f64);
+}
+
+impl<C, S> RemoveDegenerateEdges for CompressedShell<Point3, C, S>
+where C: BoundedCurve<Point = Point3> + ParameterDivision1D<Point = Point3>
+{
+    fn remove_degenerate_edges(&mut self, tol: f64) { remove_degenerate_edges(self, tol) }
+}
+
+impl<C, S> RemoveDegenerateEdges for CompressedSolid<Point3, C, S>
+where C: BoundedCurve<Point = Point3> + ParameterDivision1D<Point = Point3>
+{
+    fn remove_degenerate_edges(&mut self, tol: f64) {
+        self.boundaries
+            .iter_mut()
+            .for_each(|shell| remove_degenerate_edges(shell, tol))
+    }
+}
+
 #[cfg(test)]
 mod tests;
@@ -0,0 +1,72 @@
+use super::*;
+
+fn find_root(vmap: &[usize], mut v: usize) -> usize {
+    while vmap[v] != v {
+        v = vmap[v];
+    }
+    v
+}
+
+/// Removes the edges shorter than `tol`, e.g. the degenerate edges at the poles of spheres,
+/// and merges their end vertices.
+pub(super) fn remove_degenerate_edges<C, S>(shell: &mut Shell<Point3, C, S>, tol: f64)
+where C: BoundedCurve<Point = Point3> + ParameterDivision1D<Point = Point3> {
+    let Shell {
+        vertices,
+        edges,
+        faces,
+        ..
+    } = shell;
+    let degenerate: Vec<bool> = edges
+        .iter()
+        .map(|edge| {
+            let curve = &edge.curve;
+            let (_, points) = curve.parameter_division(curve.range_tuple(), tol);
+            let length: f64 = points.windows(2).map(|p| p[0].distance(p[1])).sum();
+            length < tol
+        })
+        .collect();
+    if !degenerate.contains(&true) {
+        return;
+    }
+    let mut vmap: Vec<usize> = (0..vertices.len()).collect();
+    edges
+        .iter()
+        .zip(&degenerate)
+        .filter(|(_, d)| **d)
+        .for_each(|(edge, _)| {
+            let (v0, v1) = (
+                find_root(&vmap, edge.vertices.0),
+                find_root(&vmap, edge.vertices.1),
+            );
+            vmap[v1] = v0;
+        });
+    let mut emap = Vec::with_capacity(edges.len());
+    let mut idx = 0;
+    degenerate.iter().for_each(|d| {
+        emap.push(idx);
+        idx += !d as usize;
+    });
+    let old_edges = std::mem::take(edges);
+    *edges = old_edges
+        .into_iter()
+        .zip(&degenerate)
+        .filter(|(_, d)| !**d)
+        .map(|(edge, _)| Edge {
+            vertices: (
+                find_root(&vmap, edge.vertices.0),
+                find_root(&vmap, edge.vertices.1),
+            ),
+            curve: edge.curve,
+            attributes: edge.attributes,
+        })
+        .collect();
+    faces.iter_mut().for_each(|face| {
+        face.boundaries.iter_mut().for_each(|wire| {
+            wire.retain(|edge| !degenerate[edge.index]);
+            wire.iter_mut()
+                .for_each(|edge| edge.index = emap[edge.index]);
+        });
+        face.boundaries.retain(|wire| !wire.is_empty());
+    });
+}
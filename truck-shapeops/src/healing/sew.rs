@@ -0,0 +1,261 @@
+use super::*;
+use crate::TRIALS;
+use truck_topology::attribute::Attributes;
+
+/// The ratios of the sample parameters in the ranges of the curves.
+const SAMPLE_RATIOS: [f64; 3] = [0.25, 0.5, 0.75];
+
+/// Returns the map from the old vertex indices to the merged ones and the merged points.
+///
+/// The merged points are registered in the grid of cells of size `tol`, so that only the points in
+/// the neighboring cells are compared.
+fn merge_vertices(vertices: &[Point3], tol: f64) -> (Vec<usize>, Vec<Point3>) {
+    let cell = |p: &Point3| {
+        let q = (p / tol).map(f64::floor);
+        (q.x as i64, q.y as i64, q.z as i64)
+    };
+    let mut grid = HashMap::<(i64, i64, i64), Vec<usize>>::default();
+    let mut points = Vec::<Point3>::new();
+    let map = vertices
+        .iter()
+        .map(|p| {
+            let (x, y, z) = cell(p);
+            let neighbor = itertools::iproduct!(x - 1..=x + 1, y - 1..=y + 1, z - 1..=z + 1)
+                .filter_map(|key| grid.get(&key))
+                .flatten()
+                .filter(|idx| p.distance(points[**idx]) < tol)
+                .min();
+            match neighbor {
+                Some(idx) => *idx,
+                None => {
+                    points.push(*p);
+                    grid.entry((x, y, z)).or_default().push(points.len() - 1);
+                    points.len() - 1
+                }
+            }
+        })
+        .collect();
+    (map, points)
+}
+
+/// Returns the parameter of `curve` nearest to `point` if the distance is less than `tol`.
+fn parameter_on<C>(curve: &C, point: Point3, tol: f64) -> Option<f64>
+where C: BoundedCurve<Point = Point3> + SearchNearestParameter<D1, Point = Point3> {
+    let (t0, t1) = curve.range_tuple();
+    let t = curve.search_nearest_parameter(point, SPHint1D::Range(t0, t1), TRIALS)?;
+    (curve.subs(t).distance(point) < tol).then_some(t)
+}
+
+/// Splits `edge` at the vertices lying on the interior of the curve, i.e. at the T-junctions.
+/// Returns the pieces of the edge from the front to the back.
+fn split_at_vertices<C>(edge: &Edge<C>, vertices: &[Point3], tol: f64) -> Vec<Edge<C>>
+where C: BoundedCurve<Point = Point3>
+        + ParameterDivision1D<Point = Point3>
+        + SearchNearestParameter<D1, Point = Point3>
+        + Cut
+        + Clone {
+    let (t0, t1) = edge.curve.range_tuple();
+    // The curve is in the `tol`-neighborhood of the polyline, so the vertices on the curve are in
+    // the bounding box of the polyline expanded by `2 * tol`.
+    let (_, polyline) = edge.curve.parameter_division((t0, t1), tol);
+    let bdb = polyline.into_iter().collect::<BoundingBox<Point3>>();
+    let margin = Vector3::new(2.0 * tol, 2.0 * tol, 2.0 * tol);
+    let (min, max) = (bdb.min() - margin, bdb.max() + margin);
+    let mut cuts = vertices
+        .iter()
+        .enumerate()
+        .filter(|(idx, p)| {
+            *idx != edge.vertices.0
+                && *idx != edge.vertices.1
+                && (0..3).all(|i| min[i] <= p[i] && p[i] <= max[i])
+        })
+        .filter_map(|(idx, p)| {
+            let t = parameter_on(&edge.curve, *p, tol)?;
+            (t0 < t && t < t1).then_some((t, idx))
+        })
+        .collect::<Vec<_>>();
+    cuts.sort_by(|x, y| x.0.total_cmp(&y.0));
+    let mut curve = edge.curve.clone();
+    let mut pieces = Vec::new();
+    let mut back = edge.vertices.1;
+    cuts.into_iter().rev().for_each(|(t, idx)| {
+        pieces.push(Edge {
+            vertices: (idx, back),
+            curve: curve.cut(t),
+            attributes: edge.attributes.clone(),
+        });
+        back = idx;
+    });
+    pieces.push(Edge {
+        vertices: (edge.vertices.0, back),
+        curve,
+        attributes: edge.attributes.clone(),
+    });
+    pieces.reverse();
+    pieces
+}
+
+/// Returns `Some(true)` if the two curves coincide with the same direction, and `Some(false)` if
+/// they coincide with the opposite direction.
+fn coincident<C>(curve0: &C, curve1: &C, tol: f64) -> Option<bool>
+where C: BoundedCurve<Point = Point3> + SearchNearestParameter<D1, Point = Point3> {
+    let samples = |curve: &C| {
+        let (t0, t1) = curve.range_tuple();
+        SAMPLE_RATIOS.map(|s| curve.subs(t0 + (t1 - t0) * s))
+    };
+    samples(curve0)
+        .into_iter()
+        .try_for_each(|pt| parameter_on(curve1, pt, tol).map(drop))?;
+    let [p0, _, p1] = samples(curve1);
+    let (s0, s1) = (
+        parameter_on(curve0, p0, tol)?,
+        parameter_on(curve0, p1, tol)?,
+    );
+    Some(s0 < s1)
+}
+
+/// Sews the loose faces into a shell by merging coincident vertices and edges.
+///
+/// # Details
+/// The faces imported from other CAD systems and built by hand often share the geometry of the boundaries
+/// but not the topology. This function merges the vertices nearer than `tol`, splits the edges at the vertices
+/// lying on their interiors, i.e. at the T-junctions, and then merges the edges whose end vertices are merged
+/// and whose curves coincide within `tol`, re-linking the boundaries of the faces.
+/// Each edge is merged with at most one other edge, since an edge of a manifold shell is shared by two faces.
+/// The edges are split only at the existing vertices, so the faces whose boundaries cross each other
+/// without any vertices are not sewn, and the shell is left open.
+/// The surfaces, the orientations and the user attributes of the faces are kept, so a closed shell is obtained
+/// if the faces are consistently oriented and form a closed surface. A merged vertex keeps the first non-empty
+/// attributes of the original vertices, and a merged edge keeps the attributes of the first edge.
+///
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use truck_topology::{compress::*, shell::ShellCondition};
+///
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// // loose faces: each face has its own vertices and edges.
+/// let mut faces = CompressedShell::new(Vec::new(), Vec::new(), Vec::new());
+/// cube.face_iter().for_each(|face| {
+///     let CompressedShell { vertices, edges, faces: mut face, .. } = Shell::from(vec![face.clone()]).compress();
+///     let (vlen, elen) = (faces.vertices.len(), faces.edges.len());
+///     faces.vertices.extend(vertices);
+///     faces.edges.extend(edges.into_iter().map(|edge| CompressedEdge {
+///         vertices: (edge.vertices.0 + vlen, edge.vertices.1 + vlen),
+///         curve: edge.curve,
+///         attributes: edge.attributes,
+///     }));
+///     face[0].boundaries.iter_mut().flatten().for_each(|edge| edge.index += elen);
+///     faces.faces.extend(face);
+/// });
+/// assert_eq!(faces.vertices.len(), 24);
+/// assert_eq!(faces.edges.len(), 24);
+///
+/// let sewn = truck_shapeops::sew(&faces, 0.01);
+/// assert_eq!(sewn.vertices.len(), 8);
+/// assert_eq!(sewn.edges.len(), 12);
+/// let shell: Shell = Shell::extract(sewn).unwrap();
+/// assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+/// ```
+pub fn sew<C, S>(faces: &CompressedShell<Point3, C, S>, tol: f64) -> CompressedShell<Point3, C, S>
+where
+    C: BoundedCurve<Point = Point3>
+        + ParameterDivision1D<Point = Point3>
+        + SearchNearestParameter<D1, Point = Point3>
+        + Cut
+        + Clone,
+    S: Clone, {
+    nonpositive_tolerance!(tol);
+    let (vmap, vertices) = merge_vertices(&faces.vertices, tol);
+    let mut vertex_attributes = Vec::<Attributes>::new();
+    faces
+        .vertex_attributes
+        .iter()
+        .zip(&vmap)
+        .for_each(|(attributes, &idx)| {
+            if vertex_attributes.len() <= idx {
+                vertex_attributes.resize(idx + 1, Attributes::new());
+            }
+            if vertex_attributes[idx].is_empty() {
+                vertex_attributes[idx] = attributes.clone();
+            }
+        });
+    let mut edges = Vec::<Edge<C>>::new();
+    // the unsewn edges keyed by the sorted end vertices
+    let mut unsewn = HashMap::<(usize, usize), Vec<usize>>::default();
+    // the old edge indices to the sewn pieces of the edges
+    let emap: Vec<Vec<EdgeIndex>> = faces
+        .edges
+        .iter()
+        .map(|edge| {
+            let edge = Edge {
+                vertices: (vmap[edge.vertices.0], vmap[edge.vertices.1]),
+                curve: edge.curve.clone(),
+                attributes: edge.attributes.clone(),
+            };
+            let pieces = split_at_vertices(&edge, &vertices, tol).into_iter();
+            pieces
+                .map(|piece| {
+                    let (v0, v1) = piece.vertices;
+                    let key = (usize::min(v0, v1), usize::max(v0, v1));
+                    let candidates = unsewn.entry(key).or_default();
+                    let partner = candidates.iter().enumerate().find_map(|(k, &i)| {
+                        let orientation = coincident(&piece.curve, &edges[i].curve, tol)?;
+                        Some((k, i, orientation))
+                    });
+                    match partner {
+                        Some((k, index, orientation)) => {
+                            candidates.remove(k);
+                            EdgeIndex { index, orientation }
+                        }
+                        None => {
+                            candidates.push(edges.len());
+                            edges.push(piece);
+                            EdgeIndex {
+                                index: edges.len() - 1,
+                                orientation: true,
+                            }
+                        }
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    let faces = faces
+        .faces
+        .iter()
+        .map(|face| Face {
+            boundaries: face
+                .boundaries
+                .iter()
+                .map(|wire| {
+                    let relink = |edge: &EdgeIndex| {
+                        let pieces = emap[edge.index].iter().map(|piece| EdgeIndex {
+                            index: piece.index,
+                            orientation: piece.orientation == edge.orientation,
+                        });
+                        let mut pieces = pieces.collect::<Vec<_>>();
+                        if !edge.orientation {
+                            pieces.reverse();
+                        }
+                        pieces
+                    };
+                    wire.iter().flat_map(relink).collect()
+                })
+                .collect(),
+            orientation: face.orientation,
+            surface: face.surface.clone(),
+            attributes: face.attributes.clone(),
+        })
+        .collect();
+    Shell {
+        vertices,
+        edges,
+        faces,
+        vertex_attributes,
+    }
+}
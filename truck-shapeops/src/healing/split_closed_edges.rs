@@ -6,6 +6,7 @@ fn cut_edge<P, C>(
     Edge {
         vertices: (_, v1),
         curve,
+        attributes,
     }: &mut Edge<C>,
 ) -> Edge<C>
 where
@@ -22,6 +23,7 @@ where
     Edge {
         vertices: (v1, v2),
         curve: curve0,
+        attributes: attributes.clone(),
     }
 }
 
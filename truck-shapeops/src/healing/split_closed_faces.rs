@@ -13,6 +13,17 @@ where
     let to_poly = closure_to_poly(tol);
     let mut poly_edges: Vec<_> = shell.edges.iter().map(to_poly).collect();
     let len = shell.faces.len();
+    (0..len).for_each(|i| {
+        let new_faces = split_doubly_periodic_face(i, shell, &mut poly_edges, &sp, tol);
+        match new_faces {
+            Some(new_faces) => shell.faces.extend(new_faces),
+            None => {
+                let new_face = split_face_around_pole(i, shell, &mut poly_edges, &sp, tol);
+                shell.faces.extend(new_face);
+            }
+        }
+    });
+    let len = shell.faces.len();
     (0..len).for_each(|i| {
         split_face_with_non_closed_boundary(i, shell, &mut poly_edges, &sp, tol);
     });
@@ -22,6 +33,379 @@ where
     shell.faces.extend(new_faces);
 }
 
+// --- split_face_around_pole ---
+
+/// Splits the face around poles, e.g. a sphere cap or a cone with the apex.
+///
+/// The pairs of an edge and its inverse adjacent in the boundary, e.g. the seam edges to the apex of a cone,
+/// are removed. If the boundary is a seam between two poles, the face is split by a new edge on the
+/// opposite side of the seam. Otherwise, if the boundary goes around the periodic direction once in the
+/// parameter space and the left side of the boundary contains a pole, the face is split by two new edges
+/// from the boundary to the pole.
+fn split_face_around_pole<C, S>(
+    face_index: usize,
+    shell: &mut Shell<Point3, C, S>,
+    poly_edges: &mut Vec<PolylineCurve<Point3>>,
+    sp: impl SP<S>,
+    tol: f64,
+) -> Option<Face<S>>
+where
+    C: TryFrom<PCurve<Line<Point2>, S>>,
+    S: ParametricSurface3D,
+{
+    let [boundary] = shell.faces[face_index].boundaries.as_slice() else {
+        return None;
+    };
+    let mut wire = boundary.clone();
+    let turning_vertices = remove_turning_back_edges(&mut wire, &shell.edges);
+    match wire.is_empty() {
+        true => split_face_by_meridian(face_index, shell, poly_edges, sp, tol),
+        false => split_cap(
+            face_index,
+            wire,
+            turning_vertices,
+            shell,
+            poly_edges,
+            sp,
+            tol,
+        ),
+    }
+}
+
+/// Removes the pairs of an edge and its inverse adjacent in `wire`, and returns the vertices at which `wire` turns back.
+fn remove_turning_back_edges<C>(wire: &mut Wire, edges: &[Edge<C>]) -> Vec<usize> {
+    let take_back = closure_take_back(edges);
+    let mut turning_vertices = Vec::new();
+    let mut i = 0;
+    while wire.len() >= 2 && i < wire.len() {
+        let j = (i + 1) % wire.len();
+        let (edge0, edge1) = (wire[i], wire[j]);
+        if edge0.index == edge1.index && edge0.orientation != edge1.orientation {
+            turning_vertices.push(take_back(edge0));
+            wire.remove(usize::max(i, j));
+            wire.remove(usize::min(i, j));
+            i = i.saturating_sub(1);
+        } else {
+            i += 1;
+        }
+    }
+    turning_vertices
+}
+
+/// Whether the line `uv[1 - axis] == param` in the parameter space is mapped to a point.
+fn is_pole<S: ParametricSurface3D>(
+    surface: &S,
+    axis: usize,
+    param: f64,
+    period: f64,
+    tol: f64,
+) -> bool {
+    let subs = |t: f64| {
+        let mut uv = Point2::origin();
+        (uv[axis], uv[1 - axis]) = (t, param);
+        surface.subs(uv.x, uv.y)
+    };
+    let p = subs(0.0);
+    (1..4).all(|i| subs(period * i as f64 / 4.0).distance(p) < tol)
+}
+
+/// Splits the face bounded by a seam between two poles and its inverse, e.g. a whole sphere.
+fn split_face_by_meridian<C, S>(
+    face_index: usize,
+    Shell { edges, faces, .. }: &mut Shell<Point3, C, S>,
+    poly_edges: &mut Vec<PolylineCurve<Point3>>,
+    sp: impl SP<S>,
+    tol: f64,
+) -> Option<Face<S>>
+where
+    C: TryFrom<PCurve<Line<Point2>, S>>,
+    S: ParametricSurface3D,
+{
+    let Face {
+        boundaries,
+        surface,
+        orientation,
+        attributes,
+    } = &faces[face_index];
+    let (seam, inverse) = (boundaries[0][0], boundaries[0][1]);
+    let param_seam = boundary_to_param_polys(&vec![seam], surface, poly_edges, sp)?;
+    let (p, q) = (param_seam[0][0], *param_seam[0].last()?);
+    let (axis, period) = [surface.u_period(), surface.v_period()]
+        .into_iter()
+        .enumerate()
+        .find_map(|(axis, period)| Some((axis, period?)))
+        .filter(|(axis, _)| f64::abs(q[*axis] - p[*axis]) < tol)?;
+    if !is_pole(surface, axis, p[1 - axis], period, tol)
+        || !is_pole(surface, axis, q[1 - axis], period, tol)
+    {
+        return None;
+    }
+    // the shift to the left side of the seam
+    let diff = q[1 - axis] - p[1 - axis];
+    let mut shift = Vector2::zero();
+    shift[axis] = match axis {
+        1 => f64::signum(diff) * period / 2.0,
+        _ => -f64::signum(diff) * period / 2.0,
+    };
+    let pcurve = PCurve::new(Line(p + shift, q + shift), surface.clone());
+    let poly = PolylineCurve::from_curve(&pcurve, (0.0, 1.0), tol);
+    let meridian = Edge {
+        vertices: (
+            closure_take_front(edges)(seam),
+            closure_take_back(edges)(seam),
+        ),
+        curve: C::try_from(pcurve).ok()?,
+        attributes: Default::default(),
+    };
+    let index = edges.len();
+    edges.push(meridian);
+    poly_edges.push(poly);
+    let ei = |orientation| EdgeIndex { index, orientation };
+    let new_face = Face {
+        boundaries: vec![vec![ei(true), inverse]],
+        surface: surface.clone(),
+        orientation: *orientation,
+        attributes: attributes.clone(),
+    };
+    faces[face_index].boundaries = vec![vec![seam, ei(false)]];
+    Some(new_face)
+}
+
+/// Splits the face whose boundary `wire` goes around a pole once, e.g. a sphere cap.
+fn split_cap<C, S>(
+    face_index: usize,
+    wire: Wire,
+    turning_vertices: Vec<usize>,
+    Shell {
+        vertices,
+        edges,
+        faces,
+        ..
+    }: &mut Shell<Point3, C, S>,
+    poly_edges: &mut Vec<PolylineCurve<Point3>>,
+    sp: impl SP<S>,
+    tol: f64,
+) -> Option<Face<S>>
+where
+    C: TryFrom<PCurve<Line<Point2>, S>>,
+    S: ParametricSurface3D,
+{
+    let Face {
+        surface,
+        orientation,
+        attributes,
+        ..
+    } = &faces[face_index];
+    let param_boundary = boundary_to_param_polys(&wire, surface, poly_edges, sp)?;
+    let p = param_boundary[0][0];
+    let diff = *param_boundary.last()?.last()? - p;
+    let (urange, vrange) = surface.try_range_tuple();
+    let around = |period: Option<f64>, dir: Vector2| {
+        let period = period?;
+        [period, -period]
+            .into_iter()
+            .find(|period| (diff - dir * *period).magnitude() < tol)
+    };
+    // the axis going around and the parameter of the pole on the left side
+    let (axis, pole) = if let Some(up) = around(surface.u_period(), Vector2::unit_x()) {
+        let (v0, v1) = vrange?;
+        (0, if up > 0.0 { v1 } else { v0 })
+    } else if let Some(vp) = around(surface.v_period(), Vector2::unit_y()) {
+        let (u0, u1) = urange?;
+        (1, if vp > 0.0 { u0 } else { u1 })
+    } else {
+        return None;
+    };
+    if !is_pole(surface, axis, pole, diff[axis].abs(), tol) {
+        return None;
+    }
+    let on_pole = |mut uv: Point2| {
+        uv[1 - axis] = pole;
+        uv
+    };
+    let pole_point = surface.subs(on_pole(p).x, on_pole(p).y);
+    // the vertex on the opposite side of the front vertex
+    let half = p[axis] + diff[axis] / 2.0;
+    let (i, q) = param_boundary
+        .iter()
+        .enumerate()
+        .skip(1)
+        .map(|(i, poly)| (i, poly[0]))
+        .min_by(|(_, q0), (_, q1)| {
+            f64::abs(q0[axis] - half).total_cmp(&f64::abs(q1[axis] - half))
+        })?;
+    let (a, b) = {
+        let take_front = closure_take_front(edges);
+        (take_front(wire[0]), take_front(wire[i]))
+    };
+    let pole_vertex = turning_vertices
+        .into_iter()
+        .find(|v| vertices[*v].distance(pole_point) < tol)
+        .unwrap_or(vertices.len());
+    let new_edge = |v: usize, uv: Point2| {
+        let pcurve = PCurve::new(Line(uv, on_pole(uv)), surface.clone());
+        let poly = PolylineCurve::from_curve(&pcurve, (0.0, 1.0), tol);
+        let edge = Edge {
+            vertices: (v, pole_vertex),
+            curve: C::try_from(pcurve).ok()?,
+            attributes: Default::default(),
+        };
+        Some((edge, poly))
+    };
+    let ((edge_a, poly_a), (edge_b, poly_b)) = (new_edge(a, p)?, new_edge(b, q)?);
+    let (index_a, index_b) = (edges.len(), edges.len() + 1);
+    if pole_vertex == vertices.len() {
+        vertices.push(pole_point);
+    }
+    edges.extend([edge_a, edge_b]);
+    poly_edges.extend([poly_a, poly_b]);
+    let ei = |index, orientation| EdgeIndex { index, orientation };
+    let mut wire0 = wire[..i].to_vec();
+    wire0.extend([ei(index_b, true), ei(index_a, false)]);
+    let mut wire1 = wire[i..].to_vec();
+    wire1.extend([ei(index_a, true), ei(index_b, false)]);
+    let new_face = Face {
+        boundaries: vec![wire1],
+        surface: surface.clone(),
+        orientation: *orientation,
+        attributes: attributes.clone(),
+    };
+    faces[face_index].boundaries = vec![wire0];
+    Some(new_face)
+}
+
+// --- split_doubly_periodic_face ---
+
+/// Splits the face covering a doubly periodic surface, e.g. a whole torus, into four faces.
+///
+/// In the parameter space, the boundary is a rectangle of one period in both directions, and it passes through
+/// a vertex at the bottom and top sides and through another vertex at the left and right sides, e.g. the middle
+/// points added by splitting the closed edges. The face is split by the four new edges from these points to
+/// the crossing point of the lines between them.
+fn split_doubly_periodic_face<C, S>(
+    face_index: usize,
+    Shell {
+        vertices,
+        edges,
+        faces,
+        ..
+    }: &mut Shell<Point3, C, S>,
+    poly_edges: &mut Vec<PolylineCurve<Point3>>,
+    sp: impl SP<S>,
+    tol: f64,
+) -> Option<Vec<Face<S>>>
+where
+    C: TryFrom<PCurve<Line<Point2>, S>>,
+    S: ParametricSurface3D,
+{
+    let Face {
+        boundaries,
+        surface,
+        orientation,
+        attributes,
+    } = &faces[face_index];
+    let (Some(up), Some(vp), [wire]) = (surface.u_period(), surface.v_period(), &boundaries[..])
+    else {
+        return None;
+    };
+    let param_boundary = boundary_to_param_polys(wire, surface, poly_edges, sp)?;
+    let fronts: Vec<usize> = wire
+        .iter()
+        .copied()
+        .map(closure_take_front(edges))
+        .collect();
+    // the positions in the wire of the vertex appearing at the two points differing by `period`
+    let find_pair = |period: Vector2| {
+        (0..wire.len()).find_map(|i| {
+            let mut positions = (0..wire.len()).filter(|j| fronts[*j] == fronts[i]);
+            let (Some(i), Some(j), None) = (positions.next(), positions.next(), positions.next())
+            else {
+                return None;
+            };
+            let diff = param_boundary[j][0] - param_boundary[i][0];
+            if (diff - period).magnitude() < tol {
+                Some((i, j))
+            } else if (diff + period).magnitude() < tol {
+                Some((j, i))
+            } else {
+                None
+            }
+        })
+    };
+    let (bottom, top) = find_pair(Vector2::new(0.0, vp))?;
+    let (left, right) = find_pair(Vector2::new(up, 0.0))?;
+    // The points are in the order of bottom, right, top and left along the boundary.
+    let mut order = [bottom, right, top, left];
+    let start = (0..4).min_by_key(|k| order[*k])?;
+    order.rotate_left(start);
+    if !order.is_sorted() {
+        return None;
+    }
+    let (p, q) = (param_boundary[bottom][0], param_boundary[left][0]);
+    let center = Point2::new(p.x, q.y);
+    if !(q.x < p.x && p.x < q.x + up && p.y < q.y && q.y < p.y + vp) {
+        return None;
+    }
+    let center_vertex = vertices.len();
+    let new_edge = |uv0: Point2, uv1: Point2, ends: (usize, usize)| {
+        let pcurve = PCurve::new(Line(uv0, uv1), surface.clone());
+        let poly = PolylineCurve::from_curve(&pcurve, (0.0, 1.0), tol);
+        let edge = Edge {
+            vertices: ends,
+            curve: C::try_from(pcurve).ok()?,
+            attributes: Default::default(),
+        };
+        Some((edge, poly))
+    };
+    let (v, w) = (fronts[bottom], fronts[left]);
+    let new_edges = [
+        new_edge(p, center, (v, center_vertex))?,
+        new_edge(center, p + Vector2::new(0.0, vp), (center_vertex, v))?,
+        new_edge(q, center, (w, center_vertex))?,
+        new_edge(center, q + Vector2::new(up, 0.0), (center_vertex, w))?,
+    ];
+    let index = edges.len();
+    vertices.push(surface.subs(center.x, center.y));
+    new_edges.into_iter().for_each(|(edge, poly)| {
+        edges.push(edge);
+        poly_edges.push(poly);
+    });
+    let ei = |i, orientation| EdgeIndex {
+        index: index + i,
+        orientation,
+    };
+    let (to_bottom, to_top, to_left, to_right) =
+        (ei(0, false), ei(1, true), ei(2, false), ei(3, true));
+    let (from_bottom, from_top, from_left, from_right) =
+        (ei(0, true), ei(1, false), ei(2, true), ei(3, false));
+    let sub_wire = |i: usize, j: usize, closing: [EdgeIndex; 2]| {
+        let mut sub_wire: Wire = match i < j {
+            true => wire[i..j].to_vec(),
+            false => wire[i..].iter().chain(&wire[..j]).copied().collect(),
+        };
+        sub_wire.extend(closing);
+        sub_wire
+    };
+    let new_boundaries = [
+        sub_wire(bottom, right, [from_right, to_bottom]),
+        sub_wire(right, top, [from_top, to_right]),
+        sub_wire(top, left, [from_left, to_top]),
+        sub_wire(left, bottom, [from_bottom, to_left]),
+    ];
+    let [boundary, new_boundaries @ ..] = new_boundaries;
+    let new_faces = new_boundaries
+        .into_iter()
+        .map(|boundary| Face {
+            boundaries: vec![boundary],
+            surface: surface.clone(),
+            orientation: *orientation,
+            attributes: attributes.clone(),
+        })
+        .collect();
+    faces[face_index].boundaries = vec![boundary];
+    Some(new_faces)
+}
+
 fn split_face_with_non_closed_boundary<C, S>(
     face_index: usize,
     shell: &mut Shell<Point3, C, S>,
@@ -130,6 +514,7 @@ fn non_closed_wires_in_param_divisor<C, S>(
         edges,
         faces,
         ref vertices,
+        ..
     }: &mut Shell<Point3, C, S>,
     poly_edges: &[PolylineCurve<Point3>],
     sp: impl SP<S>,
@@ -264,6 +649,7 @@ fn take_vertices_to_intersections<C, S>(
         vertices,
         edges,
         faces,
+        ..
     }: &mut Shell<Point3, C, S>,
     poly_edges: &mut Vec<PolylineCurve<Point3>>,
     sp: impl SP<S>,
@@ -436,6 +822,7 @@ where
     let closure = |(i, (t, _))| Edge {
         vertices: (vfirst + i, vfirst + i + 1),
         curve: edge.curve.cut(t),
+        attributes: edge.attributes.clone(),
     };
     let mut new_edges: Vec<_> = intersections_iter.map(closure).collect();
     let to_poly = closure_to_poly(tol);
@@ -682,6 +1069,7 @@ where
         Some(Edge {
             vertices: (v0, v1),
             curve: C::try_from(pcurve).ok()?,
+            attributes: Default::default(),
         })
     };
     vertices_on_divisor.chunks(2).map(make_edge).collect()
@@ -769,6 +1157,7 @@ fn divide_face<S: ParametricSurface3D>(
         ref mut boundaries,
         ref surface,
         ref orientation,
+        ref attributes,
     }: &mut Face<S>,
     new_boundaries: Vec<Wire>,
     poly_edges: &[PolylineCurve<Point3>],
@@ -780,6 +1169,7 @@ fn divide_face<S: ParametricSurface3D>(
         boundaries,
         surface: surface.clone(),
         orientation: *orientation,
+        attributes: attributes.clone(),
     };
     Some(face_boundaries.map(create_face).collect())
 }
@@ -9,6 +9,7 @@ fn test_split_closed_edges() {
     let edges = vec![CompressedEdge {
         vertices: (0, 0),
         curve,
+        attributes: Default::default(),
     }];
     let faces = vec![
         CompressedFace {
@@ -18,6 +19,7 @@ fn test_split_closed_edges() {
                 index: 0,
                 orientation: true,
             }]],
+            attributes: Default::default(),
         },
         CompressedFace {
             surface: (),
@@ -26,12 +28,14 @@ fn test_split_closed_edges() {
                 index: 0,
                 orientation: false,
             }]],
+            attributes: Default::default(),
         },
     ];
     let mut shell = CompressedShell {
         vertices,
         edges,
         faces,
+        vertex_attributes: Vec::new(),
     };
     assert!(Shell::extract(shell.clone()).is_err());
 
@@ -42,6 +46,7 @@ fn test_split_closed_edges() {
         vertices,
         edges,
         faces,
+        ..
     } = &shell;
     assert_eq!(vertices.len(), 2);
     assert_near!(vertices[0], Point2::new(1.0, 0.0));
@@ -67,6 +72,7 @@ fn test_split_closed_edges() {
                         orientation: true,
                     }
                 ]],
+                attributes: Default::default(),
             },
             CompressedFace {
                 surface: (),
@@ -81,6 +87,7 @@ fn test_split_closed_edges() {
                         orientation: false,
                     }
                 ]],
+                attributes: Default::default(),
             },
         ]
     );
@@ -123,6 +130,7 @@ fn test_split_closed_face_simple_cylinder_case() {
                 Processor::new(UnitCircle::new()),
                 (0.0, PI),
             )),
+            attributes: Default::default(),
         },
         CompressedEdge {
             vertices: (1, 0),
@@ -130,10 +138,12 @@ fn test_split_closed_face_simple_cylinder_case() {
                 Processor::new(UnitCircle::new()),
                 (PI, 2.0 * PI),
             )),
+            attributes: Default::default(),
         },
         CompressedEdge {
             vertices: (0, 2),
             curve: Curve::Line(Line(vertices[0], vertices[2])),
+            attributes: Default::default(),
         },
         CompressedEdge {
             vertices: (2, 3),
@@ -141,6 +151,7 @@ fn test_split_closed_face_simple_cylinder_case() {
                 Processor::new(UnitCircle::new()).transformed(translate),
                 (0.0, PI),
             )),
+            attributes: Default::default(),
         },
         CompressedEdge {
             vertices: (3, 2),
@@ -148,6 +159,7 @@ fn test_split_closed_face_simple_cylinder_case() {
                 Processor::new(UnitCircle::new()).transformed(translate),
                 (PI, 2.0 * PI),
             )),
+            attributes: Default::default(),
         },
     ];
     let surface = RevolutedCurve::by_revolution(
@@ -184,11 +196,13 @@ fn test_split_closed_face_simple_cylinder_case() {
             },
         ]],
         orientation: true,
+        attributes: Default::default(),
     }];
     let mut shell = CompressedShell {
         vertices,
         edges,
         faces,
+        vertex_attributes: Vec::new(),
     };
 
     assert!(Shell::extract(shell.clone()).is_err());
@@ -200,6 +214,7 @@ fn test_split_closed_face_simple_cylinder_case() {
         ref vertices,
         ref edges,
         ref mut faces,
+        ..
     } = shell;
     assert_eq!(vertices.len(), 4);
     assert_eq!(edges.len(), 6);
@@ -270,6 +285,7 @@ fn test_split_closed_face_simple_cylinder_case() {
                 ]],
                 surface,
                 orientation: true,
+                attributes: Default::default(),
             },
             Face {
                 boundaries: vec![vec![
@@ -292,6 +308,7 @@ fn test_split_closed_face_simple_cylinder_case() {
                 ]],
                 surface,
                 orientation: true,
+                attributes: Default::default(),
             },
         ]
     );
@@ -359,6 +376,7 @@ fn test_split_closed_face_cylinder_with_hole() {
                 Processor::new(UnitCircle::new()),
                 (0.0, PI),
             )),
+            attributes: Default::default(),
         },
         CompressedEdge {
             vertices: (1, 0),
@@ -366,10 +384,12 @@ fn test_split_closed_face_cylinder_with_hole() {
                 Processor::new(UnitCircle::new()),
                 (PI, 2.0 * PI),
             )),
+            attributes: Default::default(),
         },
         CompressedEdge {
             vertices: (0, 2),
             curve: Curve::Line(Line(vertices[0], vertices[2])),
+            attributes: Default::default(),
         },
         CompressedEdge {
             vertices: (2, 3),
@@ -377,6 +397,7 @@ fn test_split_closed_face_cylinder_with_hole() {
                 Processor::new(UnitCircle::new()).transformed(translate),
                 (0.0, PI),
             )),
+            attributes: Default::default(),
         },
         CompressedEdge {
             vertices: (3, 2),
@@ -384,6 +405,7 @@ fn test_split_closed_face_cylinder_with_hole() {
                 Processor::new(UnitCircle::new()).transformed(translate),
                 (PI, 2.0 * PI),
             )),
+            attributes: Default::default(),
         },
         CompressedEdge {
             vertices: (4, 5),
@@ -394,6 +416,7 @@ fn test_split_closed_face_cylinder_with_hole() {
                 )),
                 surface,
             )),
+            attributes: Default::default(),
         },
         CompressedEdge {
             vertices: (5, 4),
@@ -404,6 +427,7 @@ fn test_split_closed_face_cylinder_with_hole() {
                 )),
                 surface,
             )),
+            attributes: Default::default(),
         },
     ];
     let faces = vec![Face {
@@ -447,11 +471,13 @@ fn test_split_closed_face_cylinder_with_hole() {
             ],
         ],
         orientation: true,
+        attributes: Default::default(),
     }];
     let mut shell = CompressedShell {
         vertices,
         edges,
         faces,
+        vertex_attributes: Vec::new(),
     };
 
     assert!(Shell::extract(shell.clone()).is_err());
@@ -462,6 +488,7 @@ fn test_split_closed_face_cylinder_with_hole() {
         ref vertices,
         ref edges,
         ref mut faces,
+        ..
     } = shell;
     assert_eq!(vertices.len(), 6);
     assert_eq!(edges.len(), 9);
@@ -547,6 +574,7 @@ fn test_split_closed_face_cylinder_with_hole() {
                 ]],
                 surface,
                 orientation: true,
+                attributes: Default::default(),
             },
             Face {
                 boundaries: vec![vec![
@@ -577,6 +605,7 @@ fn test_split_closed_face_cylinder_with_hole() {
                 ]],
                 surface,
                 orientation: true,
+                attributes: Default::default(),
             },
         ]
     );
@@ -643,6 +672,7 @@ fn test_split_closed_face_cylinder_with_rotated_hole() {
                 Processor::new(UnitCircle::new()),
                 (0.0, PI),
             )),
+            attributes: Default::default(),
         },
         CompressedEdge {
             vertices: (1, 0),
@@ -650,10 +680,12 @@ fn test_split_closed_face_cylinder_with_rotated_hole() {
                 Processor::new(UnitCircle::new()),
                 (PI, 2.0 * PI),
             )),
+            attributes: Default::default(),
         },
         CompressedEdge {
             vertices: (0, 2),
             curve: Curve::Line(Line(vertices[0], vertices[2])),
+            attributes: Default::default(),
         },
         CompressedEdge {
             vertices: (2, 3),
@@ -661,6 +693,7 @@ fn test_split_closed_face_cylinder_with_rotated_hole() {
                 Processor::new(UnitCircle::new()).transformed(translate),
                 (0.0, PI),
             )),
+            attributes: Default::default(),
         },
         CompressedEdge {
             vertices: (3, 2),
@@ -668,6 +701,7 @@ fn test_split_closed_face_cylinder_with_rotated_hole() {
                 Processor::new(UnitCircle::new()).transformed(translate),
                 (PI, 2.0 * PI),
             )),
+            attributes: Default::default(),
         },
         CompressedEdge {
             vertices: (4, 5),
@@ -678,6 +712,7 @@ fn test_split_closed_face_cylinder_with_rotated_hole() {
                 )),
                 surface,
             )),
+            attributes: Default::default(),
         },
         CompressedEdge {
             vertices: (5, 4),
@@ -688,6 +723,7 @@ fn test_split_closed_face_cylinder_with_rotated_hole() {
                 )),
                 surface,
             )),
+            attributes: Default::default(),
         },
     ];
     let faces = vec![Face {
@@ -731,11 +767,13 @@ fn test_split_closed_face_cylinder_with_rotated_hole() {
             ],
         ],
         orientation: true,
+        attributes: Default::default(),
     }];
     let mut shell = CompressedShell {
         vertices,
         edges,
         faces,
+        vertex_attributes: Vec::new(),
     };
 
     assert!(Shell::extract(shell.clone()).is_err());
@@ -746,6 +784,7 @@ fn test_split_closed_face_cylinder_with_rotated_hole() {
         ref vertices,
         ref edges,
         ref mut faces,
+        ..
     } = shell;
     assert_eq!(vertices.len(), 8);
     assert_eq!(edges.len(), 11);
@@ -839,6 +878,7 @@ fn test_split_closed_face_cylinder_with_rotated_hole() {
                 ]],
                 surface,
                 orientation: true,
+                attributes: Default::default(),
             },
             Face {
                 boundaries: vec![vec![
@@ -873,6 +913,7 @@ fn test_split_closed_face_cylinder_with_rotated_hole() {
                 ]],
                 surface,
                 orientation: true,
+                attributes: Default::default(),
             },
         ]
     );
@@ -910,10 +951,12 @@ fn too_simple_cylinder() {
         CompressedEdge {
             vertices: (0, 0),
             curve: Curve::Arc(circle0),
+            attributes: Default::default(),
         },
         CompressedEdge {
             vertices: (1, 1),
             curve: Curve::Arc(circle1),
+            attributes: Default::default(),
         },
     ];
 
@@ -935,12 +978,14 @@ fn too_simple_cylinder() {
         ],
         surface,
         orientation: true,
+        attributes: Default::default(),
     }];
 
     let mut shell = CompressedShell {
         vertices,
         edges,
         faces,
+        vertex_attributes: Vec::new(),
     };
 
     assert!(Shell::extract(shell.clone()).is_err());
@@ -952,6 +997,7 @@ fn too_simple_cylinder() {
         ref vertices,
         ref edges,
         ref mut faces,
+        ..
     } = shell;
 
     assert_eq!(vertices.len(), 4);
@@ -1026,6 +1072,7 @@ fn too_simple_cylinder() {
                 ]],
                 surface,
                 orientation: true,
+                attributes: Default::default(),
             },
             Face {
                 boundaries: vec![vec![
@@ -1048,6 +1095,7 @@ fn too_simple_cylinder() {
                 ]],
                 surface,
                 orientation: true,
+                attributes: Default::default(),
             },
         ]
     );
@@ -1109,6 +1157,7 @@ fn double_closed_boundary_cylinder() {
                 Processor::new(UnitCircle::new()),
                 (0.0, 2.0 * PI),
             )),
+            attributes: Default::default(),
         },
         CompressedEdge {
             vertices: (1, 1),
@@ -1117,6 +1166,7 @@ fn double_closed_boundary_cylinder() {
                     .transformed(Matrix4::from_translation(Vector3::unit_z())),
                 (0.0, 2.0 * PI),
             )),
+            attributes: Default::default(),
         },
         CompressedEdge {
             vertices: (2, 2),
@@ -1130,6 +1180,7 @@ fn double_closed_boundary_cylinder() {
                 )),
                 surface,
             )),
+            attributes: Default::default(),
         },
         CompressedEdge {
             vertices: (3, 3),
@@ -1143,6 +1194,7 @@ fn double_closed_boundary_cylinder() {
                 )),
                 surface,
             )),
+            attributes: Default::default(),
         },
     ];
     let faces = vec![CompressedFace {
@@ -1166,11 +1218,13 @@ fn double_closed_boundary_cylinder() {
         ],
         surface,
         orientation: true,
+        attributes: Default::default(),
     }];
     let mut shell = CompressedShell {
         vertices,
         edges,
         faces,
+        vertex_attributes: Vec::new(),
     };
 
     assert!(Shell::extract(shell.clone()).is_err());
@@ -1182,6 +1236,7 @@ fn double_closed_boundary_cylinder() {
         ref vertices,
         ref edges,
         ref mut faces,
+        ..
     } = shell;
 
     assert_eq!(vertices.len(), 8);
@@ -1283,6 +1338,7 @@ fn double_closed_boundary_cylinder() {
                 ]],
                 surface,
                 orientation: true,
+                attributes: Default::default(),
             },
             CompressedFace {
                 boundaries: vec![vec![
@@ -1321,6 +1377,7 @@ fn double_closed_boundary_cylinder() {
                 ]],
                 surface,
                 orientation: true,
+                attributes: Default::default(),
             },
         ]
     );
@@ -1386,6 +1443,7 @@ fn many_closed_boundary_cylinder() {
                     Processor::new(UnitCircle::new()),
                     (0.0, 2.0 * PI),
                 )),
+                attributes: Default::default(),
             },
             CompressedEdge {
                 vertices: (1, 1),
@@ -1394,6 +1452,7 @@ fn many_closed_boundary_cylinder() {
                         .transformed(Matrix4::from_translation(Vector3::unit_z())),
                     (0.0, 2.0 * PI),
                 )),
+                attributes: Default::default(),
             },
         ];
         edges.extend((0..NUM_OF_CIRCLES).map(|i| {
@@ -1410,6 +1469,7 @@ fn many_closed_boundary_cylinder() {
                     )),
                     surface,
                 )),
+                attributes: Default::default(),
             }
         }));
         edges
@@ -1428,11 +1488,13 @@ fn many_closed_boundary_cylinder() {
         boundaries,
         surface,
         orientation: true,
+        attributes: Default::default(),
     }];
     let mut shell = CompressedShell {
         vertices,
         edges,
         faces,
+        vertex_attributes: Vec::new(),
     };
 
     assert!(Shell::extract(shell.clone()).is_err());
@@ -1444,6 +1506,7 @@ fn many_closed_boundary_cylinder() {
         ref vertices,
         ref edges,
         ref mut faces,
+        ..
     } = shell;
 
     assert_eq!(vertices.len(), (2 + NUM_OF_CIRCLES) * 2);
@@ -1533,3 +1596,314 @@ fn step_import() {
         });
     });
 }
+
+/// Returns the faces of `shell` which do not share any vertices and edges with each other.
+fn loosen<C: Clone, S: Clone>(shell: &Shell<Point3, C, S>) -> CompressedShell<Point3, C, S> {
+    let mut loose = CompressedShell::new(Vec::new(), Vec::new(), Vec::new());
+    shell.face_iter().for_each(|face| {
+        let cshell = Shell::from(vec![face.clone()]).compress();
+        let (vlen, elen) = (loose.vertices.len(), loose.edges.len());
+        loose.vertices.extend(cshell.vertices);
+        loose.edges.extend(cshell.edges.into_iter().map(|edge| {
+            CompressedEdge::new((edge.vertices.0 + vlen, edge.vertices.1 + vlen), edge.curve)
+        }));
+        loose.faces.extend(cshell.faces.into_iter().map(|mut face| {
+            face.boundaries
+                .iter_mut()
+                .flatten()
+                .for_each(|edge| edge.index += elen);
+            face
+        }));
+    });
+    loose
+}
+
+#[test]
+fn sew_perturbed_cube() {
+    use truck_modeling::*;
+    use truck_topology::shell::ShellCondition;
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+
+    let mut loose = loosen(&cube.boundaries()[0]);
+    assert_eq!(loose.vertices.len(), 24);
+    assert_eq!(loose.edges.len(), 24);
+    loose.vertices.iter_mut().enumerate().for_each(|(i, p)| {
+        *p += Vector3::new(1.0e-4, -1.0e-4, 1.0e-4) * (i % 3) as f64;
+    });
+    let cshell = sew(&loose, 0.01);
+    assert_eq!(cshell.vertices.len(), 8);
+    assert_eq!(cshell.edges.len(), 12);
+    let shell: Shell = Shell::extract(cshell).unwrap();
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+
+    // the vertices are too far to be merged
+    let shell: Shell = Shell::extract(sew(&loose, 1.0e-5)).unwrap();
+    assert_ne!(shell.shell_condition(), ShellCondition::Closed);
+}
+
+#[test]
+fn sew_cylinder() {
+    use truck_modeling::*;
+    use truck_topology::shell::ShellCondition;
+    let v = builder::vertex(Point3::new(1.0, 0.0, 0.0));
+    let circle: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    let disk = builder::try_attach_plane(&[circle]).unwrap();
+    let cylinder: Solid = builder::tsweep(&disk, Vector3::unit_z());
+
+    let loose = loosen(&cylinder.boundaries()[0]);
+    let cshell = sew(&loose, 0.01);
+    let original = cylinder.boundaries()[0].compress();
+    assert_eq!(cshell.vertices.len(), original.vertices.len());
+    assert_eq!(cshell.edges.len(), original.edges.len());
+    let shell: Shell = Shell::extract(cshell).unwrap();
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+}
+
+#[test]
+fn sew_t_junctions() {
+    use truck_modeling::*;
+    use truck_topology::shell::ShellCondition;
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::new(2.0, 0.0, 0.0));
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cuboid: Solid = builder::tsweep(&f, Vector3::unit_z());
+
+    // the top face is replaced by two halves, whose vertices lie on the edges of the side faces.
+    let is_top = |face: &Face| face.vertex_iter().all(|v| v.point().z == 1.0);
+    let mut faces: Vec<Face> = cuboid.face_iter().filter(|f| !is_top(f)).cloned().collect();
+    let halves = [0.0, 1.0].map(|x| {
+        let v = builder::vertex(Point3::new(x, 0.0, 1.0));
+        let e = builder::tsweep(&v, Vector3::unit_x());
+        builder::tsweep(&e, Vector3::unit_y())
+    });
+    faces.extend(halves);
+
+    let loose = loosen(&faces.into());
+    let cshell = sew(&loose, 0.01);
+    assert_eq!(cshell.vertices.len(), 10);
+    assert_eq!(cshell.edges.len(), 15);
+    let shell: Shell = Shell::extract(cshell).unwrap();
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+}
+
+#[derive(
+    Clone, Debug, ParametricCurve, BoundedCurve, ParameterDivision1D, Cut, SearchNearestParameterD1,
+)]
+enum PoleCurve {
+    Line(Line<Point3>),
+    Arc(TrimmedCurve<Processor<UnitCircle<Point3>, Matrix4>>),
+    Sphere(PCurve<Line<Point2>, Sphere>),
+    Cone(PCurve<Line<Point2>, RevolutedCurve<Line<Point3>>>),
+    Torus(PCurve<Line<Point2>, Torus>),
+}
+
+impl From<PCurve<Line<Point2>, Sphere>> for PoleCurve {
+    fn from(value: PCurve<Line<Point2>, Sphere>) -> Self { Self::Sphere(value) }
+}
+
+impl From<PCurve<Line<Point2>, RevolutedCurve<Line<Point3>>>> for PoleCurve {
+    fn from(value: PCurve<Line<Point2>, RevolutedCurve<Line<Point3>>>) -> Self { Self::Cone(value) }
+}
+
+impl From<PCurve<Line<Point2>, Torus>> for PoleCurve {
+    fn from(value: PCurve<Line<Point2>, Torus>) -> Self { Self::Torus(value) }
+}
+
+fn edge_index(index: usize, orientation: bool) -> CompressedEdgeIndex {
+    CompressedEdgeIndex { index, orientation }
+}
+
+#[test]
+fn sphere_caps() {
+    let sphere = Sphere::new(Point3::origin(), 1.0);
+    let vertices = vec![Point3::new(1.0, 0.0, 0.0), Point3::new(-1.0, 0.0, 0.0)];
+    let arc = |range| PoleCurve::Arc(TrimmedCurve::new(Processor::new(UnitCircle::new()), range));
+    let edges = vec![
+        CompressedEdge {
+            vertices: (0, 1),
+            curve: arc((0.0, PI)),
+            attributes: Default::default(),
+        },
+        CompressedEdge {
+            vertices: (1, 0),
+            curve: arc((PI, 2.0 * PI)),
+            attributes: Default::default(),
+        },
+    ];
+    let faces = vec![
+        CompressedFace {
+            boundaries: vec![vec![edge_index(0, true), edge_index(1, true)]],
+            orientation: true,
+            surface: sphere,
+            attributes: Default::default(),
+        },
+        CompressedFace {
+            boundaries: vec![vec![edge_index(1, false), edge_index(0, false)]],
+            orientation: true,
+            surface: sphere,
+            attributes: Default::default(),
+        },
+    ];
+    let mut shell = CompressedShell {
+        vertices,
+        edges,
+        faces,
+        vertex_attributes: Vec::new(),
+    };
+    shell.robust_split_closed_edges_and_faces(0.01);
+    assert_eq!(shell.faces.len(), 4);
+    assert_eq!(shell.vertices.len(), 4);
+    assert_near!(shell.vertices[2], Point3::new(0.0, 0.0, 1.0));
+    assert_near!(shell.vertices[3], Point3::new(0.0, 0.0, -1.0));
+    let solid = truck_topology::Solid::new(vec![Shell::extract(shell).unwrap()]);
+    assert!(crate::check(&solid, 0.01).is_valid());
+}
+
+#[test]
+fn sphere_with_degenerate_edges() {
+    let sphere = Sphere::new(Point3::origin(), 1.0);
+    let (north, south) = (Point3::new(0.0, 0.0, 1.0), Point3::new(0.0, 0.0, -1.0));
+    let seam = PCurve::new(Line(Point2::new(0.0, 0.0), Point2::new(PI, 0.0)), sphere);
+    let edges = vec![
+        CompressedEdge {
+            vertices: (0, 1),
+            curve: PoleCurve::Sphere(seam),
+            attributes: Default::default(),
+        },
+        CompressedEdge {
+            vertices: (0, 0),
+            curve: PoleCurve::Line(Line(north, north)),
+            attributes: Default::default(),
+        },
+        CompressedEdge {
+            vertices: (1, 1),
+            curve: PoleCurve::Line(Line(south, south)),
+            attributes: Default::default(),
+        },
+    ];
+    let boundary = vec![
+        edge_index(0, true),
+        edge_index(2, true),
+        edge_index(0, false),
+        edge_index(1, true),
+    ];
+    let mut shell = CompressedShell {
+        vertices: vec![north, south],
+        edges,
+        faces: vec![CompressedFace {
+            boundaries: vec![boundary],
+            orientation: true,
+            surface: sphere,
+            attributes: Default::default(),
+        }],
+        vertex_attributes: Vec::new(),
+    };
+    assert!(Shell::extract(shell.clone()).is_err());
+
+    shell.remove_degenerate_edges(0.01);
+    assert_eq!(shell.edges.len(), 1);
+    assert_eq!(
+        shell.faces[0].boundaries,
+        vec![vec![edge_index(0, true), edge_index(0, false)]]
+    );
+    shell.robust_split_closed_edges_and_faces(0.01);
+    assert_eq!(shell.faces.len(), 2);
+    assert_eq!(shell.edges.len(), 2);
+    let solid = truck_topology::Solid::new(vec![Shell::extract(shell).unwrap()]);
+    assert!(crate::check(&solid, 0.01).is_valid());
+}
+
+#[test]
+fn cone_with_apex() {
+    let (apex, base) = (Point3::new(0.0, 0.0, 1.0), Point3::new(1.0, 0.0, 0.0));
+    let cone = RevolutedCurve::by_revolution(Line(apex, base), Point3::origin(), Vector3::unit_z());
+    let circle = TrimmedCurve::new(Processor::new(UnitCircle::new()), (0.0, 2.0 * PI));
+    let edges = vec![
+        CompressedEdge {
+            vertices: (0, 0),
+            curve: PoleCurve::Arc(circle),
+            attributes: Default::default(),
+        },
+        CompressedEdge {
+            vertices: (0, 1),
+            curve: PoleCurve::Line(Line(base, apex)),
+            attributes: Default::default(),
+        },
+        CompressedEdge {
+            vertices: (1, 1),
+            curve: PoleCurve::Line(Line(apex, apex)),
+            attributes: Default::default(),
+        },
+    ];
+    let boundary = vec![
+        edge_index(0, true),
+        edge_index(1, true),
+        edge_index(2, true),
+        edge_index(1, false),
+    ];
+    let mut shell = CompressedShell {
+        vertices: vec![base, apex],
+        edges,
+        faces: vec![CompressedFace {
+            boundaries: vec![boundary],
+            orientation: true,
+            surface: cone,
+            attributes: Default::default(),
+        }],
+        vertex_attributes: Vec::new(),
+    };
+    shell.remove_degenerate_edges(0.01);
+    shell.robust_split_closed_edges_and_faces(0.01);
+    // The apex is not duplicated.
+    assert_eq!(shell.vertices.len(), 3);
+    assert_eq!(shell.faces.len(), 2);
+    let shell = Shell::extract(shell).unwrap();
+    assert_eq!(
+        shell.shell_condition(),
+        truck_topology::shell::ShellCondition::Oriented
+    );
+}
+
+#[test]
+fn torus() {
+    let torus = Torus::new(Point3::origin(), 2.0, 0.5);
+    let line = |p: (f64, f64), q: (f64, f64)| {
+        let line = Line(Point2::new(p.0, p.1), Point2::new(q.0, q.1));
+        PoleCurve::Torus(PCurve::new(line, torus))
+    };
+    let edges = vec![
+        CompressedEdge {
+            vertices: (0, 0),
+            curve: line((0.0, 0.0), (2.0 * PI, 0.0)),
+            attributes: Default::default(),
+        },
+        CompressedEdge {
+            vertices: (0, 0),
+            curve: line((0.0, 0.0), (0.0, 2.0 * PI)),
+            attributes: Default::default(),
+        },
+    ];
+    let boundary = vec![
+        edge_index(0, true),
+        edge_index(1, true),
+        edge_index(0, false),
+        edge_index(1, false),
+    ];
+    let mut shell = CompressedShell {
+        vertices: vec![torus.subs(0.0, 0.0)],
+        edges,
+        faces: vec![CompressedFace {
+            boundaries: vec![boundary],
+            orientation: true,
+            surface: torus,
+            attributes: Default::default(),
+        }],
+        vertex_attributes: Vec::new(),
+    };
+    shell.robust_split_closed_edges_and_faces(0.01);
+    let solid = truck_topology::Solid::new(vec![Shell::extract(shell).unwrap()]);
+    assert!(crate::check(&solid, 0.01).is_valid());
+}
@@ -13,9 +13,31 @@
     unused_qualifications
 )]
 
+/// The number of trials of Newton's method.
+const TRIALS: usize = 100;
+
 mod healing;
-pub use healing::{RobustSplitClosedEdgesAndFaces, SplitClosedEdgesAndFaces};
+pub use healing::{
+    sew, RemoveDegenerateEdges, RobustSplitClosedEdgesAndFaces, SplitClosedEdgesAndFaces,
+};
 mod transversal;
-pub use transversal::{and, or, ShapeOpsCurve, ShapeOpsSurface};
+pub use transversal::{
+    and, and_with_history, or, or_with_history, section, section_with_history, split_solid,
+    split_solid_with_history, ShapeOpsCurve, ShapeOpsSurface,
+};
 mod alternative;
+mod mass_properties;
+mod parameter;
+pub use mass_properties::{MassProperties, MassPropertiesData};
+mod classification;
+pub use classification::{Classification, Classify};
+mod distance;
+pub use distance::distance;
+mod face_domain;
 mod fillet;
+mod ray_cast;
+pub use ray_cast::{ray_cast, Hit};
+mod simplify;
+pub use simplify::{simplify, simplify_with_history};
+mod validation;
+pub use validation::{check, Problem, ValidationReport};
@@ -0,0 +1,425 @@
+use crate::parameter::{closest_period, surface_parameter};
+use std::cell::Cell;
+use truck_geometry::prelude::*;
+use truck_topology::*;
+
+/// The number of the integrated values:
+/// area, volume, three first moments and six second moments.
+const N: usize = 11;
+type Integrals = [f64; N];
+
+/// The abscissae and the weights of five points Gauss-Legendre quadrature on `[0, 1]`.
+const GAUSS: [(f64, f64); 5] = [
+    (0.046_910_077_030_668_004, 0.118_463_442_528_094_54),
+    (0.230_765_344_947_158_45, 0.239_314_335_249_683_23),
+    (0.5, 0.284_444_444_444_444_44),
+    (0.769_234_655_052_841_6, 0.239_314_335_249_683_23),
+    (0.953_089_922_969_332, 0.118_463_442_528_094_54),
+];
+
+/// The maximum depth of the adaptive quadrature.
+const MAX_DEPTH: usize = 16;
+
+/// The maximum number of the evaluations of the integrands for each face.
+const MAX_EVALUATIONS: usize = 1 << 22;
+
+/// Mass properties of the shape with the unit density.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MassPropertiesData {
+    /// the area of the surface
+    pub surface_area: f64,
+    /// the volume enclosed by the shape
+    pub volume: f64,
+    /// the center of gravity of the enclosed domain
+    pub center_of_gravity: Point3,
+    /// the inertia tensor around the center of gravity
+    pub inertia_tensor: Matrix3,
+}
+
+/// Exact mass properties of B-rep shapes.
+///
+/// # Details
+/// The integrals over each face are reduced to line integrals along the boundaries of
+/// the trimmed parameter domain by Green's theorem, and computed by the adaptive Gauss-Legendre quadrature.
+/// No tessellation is used, so the accuracy depends only on `tol`.
+///
+/// The volume, the center of gravity and the inertia tensor are meaningful only if the shells are closed.
+///
+/// Returns `None` if the parameters of the boundaries cannot be searched, the enclosed volume vanishes,
+/// e.g. for a planar open shell, or the accuracy `tol` cannot be reached within the bounded number of
+/// evaluations of the integrands.
+///
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use truck_shapeops::MassProperties;
+/// use std::f64::consts::PI;
+///
+/// let v = builder::vertex(Point3::new(0.0, 0.0, 1.0));
+/// let wire: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_y(), Rad(PI));
+/// let shell = builder::cone(&wire, Vector3::unit_z(), Rad(7.0));
+/// let sphere = Solid::new(vec![shell]);
+///
+/// let props = sphere.mass_properties(1.0e-8).unwrap();
+/// assert!(f64::abs(props.surface_area - 4.0 * PI) < 1.0e-6);
+/// assert!(f64::abs(props.volume - 4.0 * PI / 3.0) < 1.0e-6);
+/// assert!(props.center_of_gravity.to_vec().magnitude() < 1.0e-6);
+/// ```
+pub trait MassProperties {
+    /// Returns all mass properties.
+    fn mass_properties(&self, tol: f64) -> Option<MassPropertiesData>;
+    /// Returns the area of the surface.
+    #[inline(always)]
+    fn surface_area(&self, tol: f64) -> Option<f64> {
+        Some(self.mass_properties(tol)?.surface_area)
+    }
+    /// Returns the volume.
+    #[inline(always)]
+    fn volume(&self, tol: f64) -> Option<f64> { Some(self.mass_properties(tol)?.volume) }
+    /// Returns the center of gravity.
+    #[inline(always)]
+    fn center_of_gravity(&self, tol: f64) -> Option<Point3> {
+        Some(self.mass_properties(tol)?.center_of_gravity)
+    }
+    /// Returns the inertia tensor around the center of gravity.
+    #[inline(always)]
+    fn inertia_tensor(&self, tol: f64) -> Option<Matrix3> {
+        Some(self.mass_properties(tol)?.inertia_tensor)
+    }
+}
+
+#[inline(always)]
+fn add_assign(a: &mut Integrals, b: &Integrals) { a.iter_mut().zip(b).for_each(|(x, y)| *x += y) }
+
+#[inline(always)]
+fn scaled(a: &Integrals, s: f64) -> Integrals { a.map(|x| x * s) }
+
+#[inline(always)]
+fn max_diff(a: &Integrals, b: &Integrals) -> f64 {
+    a.iter()
+        .zip(b)
+        .fold(0.0, |max, (x, y)| f64::max(max, f64::abs(x - y)))
+}
+
+/// Five points Gauss-Legendre quadrature on `(a, b)`.
+/// The integrand is evaluated with the absolute tolerance `tol`.
+fn gauss(
+    f: &mut impl FnMut(f64, f64) -> Option<Integrals>,
+    (a, b): (f64, f64),
+    tol: f64,
+) -> Option<Integrals> {
+    GAUSS.iter().try_fold([0.0; N], |mut sum, (x, w)| {
+        add_assign(&mut sum, &scaled(&f(a + (b - a) * x, tol)?, w * (b - a)));
+        Some(sum)
+    })
+}
+
+/// Integrates `f` on `[0, 1]` adaptively.
+///
+/// The integrand `f(x, tol)` is allowed to have the absolute error `tol`. On the interval `(a, b)`
+/// with the tolerance `tol`, the errors of the integrand contribute at most `tol / 2` to the integral,
+/// and the rest is left for the error of the quadrature.
+fn adaptive_quadrature(
+    f: &mut impl FnMut(f64, f64) -> Option<Integrals>,
+    (a, b): (f64, f64),
+    whole: Integrals,
+    (tol, eps): (f64, f64),
+    depth: usize,
+) -> Option<Integrals> {
+    let m = (a + b) / 2.0;
+    let inner_tol = tol / (2.0 * (b - a));
+    let (left, right) = (gauss(f, (a, m), inner_tol)?, gauss(f, (m, b), inner_tol)?);
+    let mut sum = left;
+    add_assign(&mut sum, &right);
+    // `eps` is the bound by the rounding errors relative to the magnitude of the integrals.
+    // At the maximum depth, the interval is regarded as including a singular point of the integrand,
+    // e.g. a kink of the area element at a pole, whose contribution is negligible.
+    if depth == 0 || max_diff(&sum, &whole) < f64::max(tol / 2.0, eps) {
+        Some(sum)
+    } else {
+        let mut left = adaptive_quadrature(f, (a, m), left, (tol / 2.0, eps / 2.0), depth - 1)?;
+        let right = adaptive_quadrature(f, (m, b), right, (tol / 2.0, eps / 2.0), depth - 1)?;
+        add_assign(&mut left, &right);
+        Some(left)
+    }
+}
+
+fn integrate(mut f: impl FnMut(f64, f64) -> Option<Integrals>, tol: f64) -> Option<Integrals> {
+    let whole = gauss(&mut f, (0.0, 1.0), tol / 2.0)?;
+    let scale = whole.iter().fold(1.0, |max, x| f64::max(max, f64::abs(*x)));
+    adaptive_quadrature(&mut f, (0.0, 1.0), whole, (tol, scale * 1.0e-12), MAX_DEPTH)
+}
+
+/// The integrands at the point of the surface.
+/// `normal` is the non-normalized normal vector i.e. the cross product of derivations.
+fn integrands(pt: Point3, normal: Vector3) -> Integrals {
+    let (x, y, z) = (pt.x, pt.y, pt.z);
+    let (nx, ny, nz) = (normal.x, normal.y, normal.z);
+    [
+        normal.magnitude(),
+        pt.to_vec().dot(normal) / 3.0,
+        x * x * nx / 2.0,
+        y * y * ny / 2.0,
+        z * z * nz / 2.0,
+        x * x * x * nx / 3.0,
+        y * y * y * ny / 3.0,
+        z * z * z * nz / 3.0,
+        x * x * y * nx / 2.0,
+        y * y * z * ny / 2.0,
+        z * z * x * nz / 2.0,
+    ]
+}
+
+/// Solves `vec = uder * du + vder * dv` by least squares.
+fn least_squares(uder: Vector3, vder: Vector3, vec: Vector3) -> Option<Vector2> {
+    let (a, b, c) = (uder.dot(uder), uder.dot(vder), vder.dot(vder));
+    let det = a * c - b * b;
+    match det.so_small2() {
+        true => None,
+        false => Some(Vector2::new(
+            (c * uder.dot(vec) - b * vder.dot(vec)) / det,
+            (a * vder.dot(vec) - b * uder.dot(vec)) / det,
+        )),
+    }
+}
+
+struct FaceIntegrator<'a, S> {
+    surface: &'a S,
+    u_ref: f64,
+    tol: f64,
+    /// the number of the remaining evaluations of the integrands
+    budget: Cell<usize>,
+}
+
+impl<S> FaceIntegrator<'_, S>
+where S: ParametricSurface3D
+        + SearchParameter<D2, Point = Point3>
+        + SearchNearestParameter<D2, Point = Point3>
+{
+    /// Returns the parameter of `pt` near by `hint` modulo periods.
+    fn parameter(&self, pt: Point3, hint: Point2) -> Option<Point2> {
+        let (u, v) = surface_parameter(&self.surface, pt, Some(hint.into()))?;
+        let mut uv = Point2::new(
+            closest_period(u, hint.x, self.surface.u_period()),
+            closest_period(v, hint.y, self.surface.v_period()),
+        );
+        // The searched parameter is refined by Gauss-Newton method since the error of
+        // the parameter propagates to the derivations.
+        for _ in 0..3 {
+            let (uder, vder) = (self.surface.uder(uv.x, uv.y), self.surface.vder(uv.x, uv.y));
+            let diff = pt - self.surface.subs(uv.x, uv.y);
+            match least_squares(uder, vder, diff) {
+                Some(delta) if delta.magnitude() < TOLERANCE => uv += delta,
+                _ => break,
+            }
+        }
+        Some(uv)
+    }
+
+    /// Consumes one evaluation of the budget. Returns `None` if the budget is exhausted.
+    fn consume(&self) -> Option<()> {
+        let budget = self.budget.get().checked_sub(1)?;
+        self.budget.set(budget);
+        Some(())
+    }
+
+    /// $\int_{u_{ref}}^{u} f(s, v) ds$ with the absolute tolerance `tol`.
+    fn primitive(&self, uv: Point2, tol: f64) -> Option<Integrals> {
+        let (u0, u1, v) = (self.u_ref, uv.x, uv.y);
+        if f64::abs(u1 - u0) < TOLERANCE2 {
+            return Some([0.0; N]);
+        }
+        let res = integrate(
+            |s, _| {
+                self.consume()?;
+                let u = u0 + (u1 - u0) * s;
+                let normal = self.surface.uder(u, v).cross(self.surface.vder(u, v));
+                Some(integrands(self.surface.subs(u, v), normal))
+            },
+            tol / f64::abs(u1 - u0),
+        )?;
+        Some(scaled(&res, u1 - u0))
+    }
+
+    /// $\int F(u, v) dv$ along the segment from `p` to `q` in the parameter space.
+    fn segment_integral(&self, p: Point2, q: Point2) -> Option<Integrals> {
+        if (q.y - p.y).so_small() {
+            return Some([0.0; N]);
+        }
+        let tol = self.tol / f64::abs(q.y - p.y);
+        let res = integrate(|s, tol| self.primitive(p + (q - p) * s, tol), tol)?;
+        Some(scaled(&res, q.y - p.y))
+    }
+
+    /// $\int F(u, v) dv$ along the parameter curve of `curve` from `t0` to `t1`.
+    /// Returns the integral and the parameters of the end points.
+    fn curve_integral<C: ParametricCurve3D>(
+        &self,
+        curve: &C,
+        (t0, t1): (f64, f64),
+        hint: Point2,
+    ) -> Option<(Integrals, Point2, Point2)> {
+        // At singular points, the parameter is extrapolated from the interior of the curve.
+        let is_singular = |uv: Point2| {
+            let (uder, vder) = (self.surface.uder(uv.x, uv.y), self.surface.vder(uv.x, uv.y));
+            uder.cross(vder).magnitude() < TOLERANCE * uder.magnitude() * vder.magnitude()
+                || uder.so_small()
+                || vder.so_small()
+        };
+        let end_parameter = |t: f64, dt: f64, hint: Point2| -> Option<Point2> {
+            match self.parameter(curve.subs(t), hint) {
+                Some(uv) if !is_singular(uv) => Some(uv),
+                _ => {
+                    let p = self.parameter(curve.subs(t + dt), hint)?;
+                    let q = self.parameter(curve.subs(t + 2.0 * dt), p)?;
+                    Some(p + (p - q))
+                }
+            }
+        };
+        let dt = (t1 - t0) * 1.0e-4;
+        let start = end_parameter(t0, dt, hint)?;
+        let mut hint = start;
+        let res = integrate(
+            |s, tol| {
+                self.consume()?;
+                let t = t0 + (t1 - t0) * s;
+                let uv = self.parameter(curve.subs(t), hint)?;
+                hint = uv;
+                let (uder, vder) = (self.surface.uder(uv.x, uv.y), self.surface.vder(uv.x, uv.y));
+                let der = curve.der(t) * (t1 - t0);
+                let dv = least_squares(uder, vder, der).map_or(0.0, |delta| delta.y);
+                match dv == 0.0 {
+                    true => Some([0.0; N]),
+                    // the error of the primitive is multiplied by `dv`
+                    false => Some(scaled(&self.primitive(uv, tol / f64::abs(dv))?, dv)),
+                }
+            },
+            self.tol,
+        )?;
+        let end = end_parameter(t1, -dt, hint)?;
+        Some((res, start, end))
+    }
+}
+
+fn face_integrals<C, S>(face: &Face<Point3, C, S>, tol: f64) -> Option<Integrals>
+where
+    C: ParametricCurve3D + BoundedCurve,
+    S: ParametricSurface3D
+        + SearchParameter<D2, Point = Point3>
+        + SearchNearestParameter<D2, Point = Point3>, {
+    let surface = face.surface();
+    let curve = face.absolute_boundaries()[0].front()?.curve();
+    let (t0, t1) = curve.range_tuple();
+    let pt = curve.subs((t0 + t1) / 2.0);
+    let (u_ref, v_ref) = surface_parameter(&surface, pt, None)?;
+    let integrator = FaceIntegrator {
+        surface: &surface,
+        u_ref,
+        tol,
+        budget: Cell::new(MAX_EVALUATIONS),
+    };
+    let mut hint = Point2::new(u_ref, v_ref);
+    let mut sum = [0.0; N];
+    for wire in face.absolute_boundaries() {
+        let (mut first, mut last) = (None, None);
+        for edge in wire {
+            let curve = edge.curve();
+            let (t0, t1) = curve.range_tuple();
+            let range = match edge.orientation() {
+                true => (t0, t1),
+                false => (t1, t0),
+            };
+            let (res, start, end) = integrator.curve_integral(&curve, range, hint)?;
+            add_assign(&mut sum, &res);
+            // close the gaps in the parameter space, e.g. at the poles of a sphere
+            match last {
+                Some(last) => add_assign(&mut sum, &integrator.segment_integral(last, start)?),
+                None => first = Some(start),
+            }
+            last = Some(end);
+            hint = end;
+        }
+        if let (Some(first), Some(last)) = (first, last) {
+            add_assign(&mut sum, &integrator.segment_integral(last, first)?);
+        }
+    }
+    Some(match face.orientation() {
+        true => sum,
+        false => {
+            let area = sum[0];
+            let mut sum = scaled(&sum, -1.0);
+            sum[0] = area;
+            sum
+        }
+    })
+}
+
+fn into_data(integrals: Integrals) -> Option<MassPropertiesData> {
+    let [area, volume, mx, my, mz, xx, yy, zz, xy, yz, zx] = integrals;
+    if volume.so_small() {
+        return None;
+    }
+    let c = Point3::new(mx, my, mz) / volume;
+    let (ixx, iyy, izz) = (
+        yy + zz - volume * (c.y * c.y + c.z * c.z),
+        zz + xx - volume * (c.z * c.z + c.x * c.x),
+        xx + yy - volume * (c.x * c.x + c.y * c.y),
+    );
+    let ixy = -(xy - volume * c.x * c.y);
+    let iyz = -(yz - volume * c.y * c.z);
+    let izx = -(zx - volume * c.z * c.x);
+    Some(MassPropertiesData {
+        surface_area: area,
+        volume,
+        center_of_gravity: c,
+        inertia_tensor: Matrix3::new(ixx, ixy, izx, ixy, iyy, iyz, izx, iyz, izz),
+    })
+}
+
+fn shell_integrals<C, S>(shell: &Shell<Point3, C, S>, tol: f64) -> Option<Integrals>
+where
+    C: ParametricCurve3D + BoundedCurve,
+    S: ParametricSurface3D
+        + SearchParameter<D2, Point = Point3>
+        + SearchNearestParameter<D2, Point = Point3>, {
+    let tol = tol / f64::max(shell.len() as f64, 1.0);
+    shell.face_iter().try_fold([0.0; N], |mut sum, face| {
+        add_assign(&mut sum, &face_integrals(face, tol)?);
+        Some(sum)
+    })
+}
+
+impl<C, S> MassProperties for Shell<Point3, C, S>
+where
+    C: ParametricCurve3D + BoundedCurve,
+    S: ParametricSurface3D
+        + SearchParameter<D2, Point = Point3>
+        + SearchNearestParameter<D2, Point = Point3>,
+{
+    fn mass_properties(&self, tol: f64) -> Option<MassPropertiesData> {
+        nonpositive_tolerance!(tol, f64::EPSILON);
+        into_data(shell_integrals(self, tol)?)
+    }
+}
+
+impl<C, S> MassProperties for Solid<Point3, C, S>
+where
+    C: ParametricCurve3D + BoundedCurve,
+    S: ParametricSurface3D
+        + SearchParameter<D2, Point = Point3>
+        + SearchNearestParameter<D2, Point = Point3>,
+{
+    fn mass_properties(&self, tol: f64) -> Option<MassPropertiesData> {
+        nonpositive_tolerance!(tol, f64::EPSILON);
+        let boundaries = self.boundaries();
+        let tol = tol / boundaries.len() as f64;
+        let integrals = boundaries.iter().try_fold([0.0; N], |mut sum, shell| {
+            add_assign(&mut sum, &shell_integrals(shell, tol)?);
+            Some(sum)
+        })?;
+        into_data(integrals)
+    }
+}
+
+#[cfg(test)]
+mod tests;
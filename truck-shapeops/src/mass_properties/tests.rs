@@ -0,0 +1,131 @@
+use super::*;
+use std::f64::consts::PI;
+use truck_modeling::{builder, Shell, Solid, Surface, Wire};
+
+const TOL: f64 = 1.0e-8;
+
+fn assert_close(a: f64, b: f64) {
+    assert!(
+        f64::abs(a - b) < 1.0e-6 * f64::max(1.0, f64::abs(b)),
+        "{a} != {b}"
+    );
+}
+
+fn assert_tensor(a: Matrix3, b: Matrix3) {
+    (0..3).for_each(|i| (0..3).for_each(|j| assert_close(a[i][j], b[i][j])));
+}
+
+#[test]
+fn cuboid() {
+    let v = builder::vertex(Point3::new(1.0, 2.0, 3.0));
+    let e = builder::tsweep(&v, Vector3::new(2.0, 0.0, 0.0));
+    let f = builder::tsweep(&e, Vector3::new(0.0, 3.0, 0.0));
+    let cuboid: Solid = builder::tsweep(&f, Vector3::new(0.0, 0.0, 4.0));
+    let props = cuboid.mass_properties(TOL).unwrap();
+    assert_close(props.surface_area, 2.0 * (6.0 + 12.0 + 8.0));
+    assert_close(props.volume, 24.0);
+    assert_close(props.center_of_gravity.x, 2.0);
+    assert_close(props.center_of_gravity.y, 3.5);
+    assert_close(props.center_of_gravity.z, 5.0);
+    let m = 24.0;
+    let tensor = Matrix3::from_diagonal(Vector3::new(
+        m * (9.0 + 16.0) / 12.0,
+        m * (16.0 + 4.0) / 12.0,
+        m * (4.0 + 9.0) / 12.0,
+    ));
+    assert_tensor(props.inertia_tensor, tensor);
+
+    // the shell has the same properties
+    let shell_props = cuboid.boundaries()[0].mass_properties(TOL).unwrap();
+    assert_close(shell_props.volume, 24.0);
+}
+
+#[test]
+fn sphere() {
+    let (r, c) = (1.5, Point3::new(1.0, -1.0, 0.5));
+    let v = builder::vertex(c + Vector3::new(0.0, 0.0, r));
+    let wire: Wire = builder::rsweep(&v, c, Vector3::unit_y(), Rad(PI));
+    let shell = builder::cone(&wire, Vector3::unit_z(), Rad(7.0));
+    let sphere = Solid::new(vec![shell]);
+    let props = sphere.mass_properties(TOL).unwrap();
+    assert_close(props.surface_area, 4.0 * PI * r * r);
+    let m = 4.0 * PI * r * r * r / 3.0;
+    assert_close(props.volume, m);
+    assert_close(props.center_of_gravity.x, c.x);
+    assert_close(props.center_of_gravity.y, c.y);
+    assert_close(props.center_of_gravity.z, c.z);
+    let tensor = Matrix3::from_value(2.0 * m * r * r / 5.0);
+    assert_tensor(
+        props.inertia_tensor,
+        Matrix3::from_diagonal(tensor.diagonal()),
+    );
+}
+
+#[test]
+fn torus() {
+    let (r0, r1) = (2.0, 0.5);
+    let v = builder::vertex(Point3::new(r0, 0.0, r1));
+    let w = builder::rsweep(&v, Point3::new(r0, 0.0, 0.0), Vector3::unit_y(), Rad(7.0));
+    let shell = builder::rsweep(&w, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    let torus = Solid::new(vec![shell]);
+    let props = torus.mass_properties(TOL).unwrap();
+    assert_close(props.surface_area, 4.0 * PI * PI * r0 * r1);
+    let m = 2.0 * PI * PI * r0 * r1 * r1;
+    assert_close(props.volume, m);
+    assert_close(props.center_of_gravity.to_vec().magnitude(), 0.0);
+    let tensor = Matrix3::from_diagonal(Vector3::new(
+        m * (r0 * r0 / 2.0 + 5.0 * r1 * r1 / 8.0),
+        m * (r0 * r0 / 2.0 + 5.0 * r1 * r1 / 8.0),
+        m * (r0 * r0 + 3.0 * r1 * r1 / 4.0),
+    ));
+    assert_tensor(props.inertia_tensor, tensor);
+}
+
+#[test]
+fn inverted_solid() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let mut cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    cube.not();
+    let props = cube.mass_properties(TOL).unwrap();
+    assert_close(props.surface_area, 6.0);
+    assert_close(props.volume, -1.0);
+}
+
+#[test]
+fn nurbs_cylinder() {
+    let (r, h) = (1.5, 2.0);
+    let v = builder::vertex(Point3::new(r, 0.0, 0.0));
+    let w: Wire = builder::rsweep(&v, Point3::origin(), Vector3::unit_z(), Rad(7.0));
+    let f = builder::try_attach_plane(&[w]).unwrap();
+    let cylinder: Solid = builder::tsweep(&f, Vector3::new(0.0, 0.0, h));
+    let cylinder = cylinder.mapped(
+        |p| *p,
+        |c| c.clone(),
+        |s| Surface::NurbsSurface(s.to_nurbs().unwrap()),
+    );
+    assert!(cylinder
+        .face_iter()
+        .all(|face| matches!(face.surface(), Surface::NurbsSurface(_))));
+    let props = cylinder.mass_properties(TOL).unwrap();
+    assert_close(props.surface_area, 2.0 * PI * r * (r + h));
+    let m = PI * r * r * h;
+    assert_close(props.volume, m);
+    assert_close(props.center_of_gravity.z, h / 2.0);
+    let tensor = Matrix3::from_diagonal(Vector3::new(
+        m * (3.0 * r * r + h * h) / 12.0,
+        m * (3.0 * r * r + h * h) / 12.0,
+        m * r * r / 2.0,
+    ));
+    assert_tensor(props.inertia_tensor, tensor);
+}
+
+#[test]
+fn open_shell() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let shell: Shell = vec![f].into();
+    assert!(shell.mass_properties(TOL).is_none());
+}
@@ -0,0 +1,45 @@
+//! The helpers of searching parameters shared by the modules.
+
+use crate::TRIALS;
+use truck_geometry::prelude::*;
+
+/// Returns the parameter nearest to `hint` modulo `period`.
+pub(crate) fn closest_period(t: f64, hint: f64, period: Option<f64>) -> f64 {
+    match period {
+        Some(p) => t + p * f64::round((hint - t) / p),
+        None => t,
+    }
+}
+
+/// Searches the parameter of the point on `surface` nearest to `pt` from `hint`.
+///
+/// If the search fails, it is retried without the hint, since the hint may be a singular point.
+pub(crate) fn nearest_surface_parameter<S>(
+    surface: &S,
+    pt: Point3,
+    hint: Option<(f64, f64)>,
+) -> Option<(f64, f64)>
+where
+    S: SearchNearestParameter<D2, Point = Point3>,
+{
+    let res = surface.search_nearest_parameter(pt, hint, TRIALS);
+    match (res, hint) {
+        (None, Some(_)) => surface.search_nearest_parameter(pt, None, TRIALS),
+        _ => res,
+    }
+}
+
+/// Searches the parameter of `pt` on `surface` from `hint`, or the parameter of the nearest point
+/// by [`nearest_surface_parameter`] if `pt` is not found on the surface.
+pub(crate) fn surface_parameter<S>(
+    surface: &S,
+    pt: Point3,
+    hint: Option<(f64, f64)>,
+) -> Option<(f64, f64)>
+where
+    S: SearchParameter<D2, Point = Point3> + SearchNearestParameter<D2, Point = Point3>,
+{
+    surface
+        .search_parameter(pt, hint, TRIALS)
+        .or_else(|| nearest_surface_parameter(surface, pt, hint))
+}
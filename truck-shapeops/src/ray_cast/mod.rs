@@ -0,0 +1,116 @@
+use crate::face_domain::FaceDomain;
+use truck_geometry::prelude::*;
+use truck_topology::*;
+
+/// An intersection of a ray and a face.
+#[derive(Debug)]
+pub struct Hit<S> {
+    /// the ID of the face
+    pub face_id: FaceID<S>,
+    /// the parameter of the surface of the face, i.e. the surface without inversion
+    pub uv: Point2,
+    /// the parameter of the ray, i.e. `point == origin + t * direction`
+    pub t: f64,
+    /// the intersection point
+    pub point: Point3,
+}
+
+// The traits are implemented by hand, since `FaceID<S>` implements them for all `S`.
+impl<S> Clone for Hit<S> {
+    #[inline(always)]
+    fn clone(&self) -> Self { *self }
+}
+
+impl<S> Copy for Hit<S> {}
+
+impl<S> PartialEq for Hit<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.face_id == other.face_id
+            && self.uv == other.uv
+            && self.t == other.t
+            && self.point == other.point
+    }
+}
+
+/// Casts the ray from `origin` toward `direction` to the faces of `shell`.
+///
+/// Returns the intersections with the trimmed faces in front of `origin`, sorted by the parameter `t`.
+/// The intersections are searched by Newton's method from the sample points on the grid in the parameter
+/// domain of each face, whose accuracy is `tol`, and the faces whose bounding boxes do not meet the ray are skipped.
+/// An intersection on an edge may be reported by both of the adjacent faces.
+///
+/// Returns `None` if the boundary of a face cannot be mapped to the parameter space of the surface,
+/// since the ray may pass through such a face undetected.
+///
+/// A `truck_platform::Ray` created by a camera can be cast by passing its
+/// `origin()` and `direction()`.
+///
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+/// let shell = &cube.boundaries()[0];
+///
+/// let origin = Point3::new(0.5, 0.25, -1.0);
+/// let hits = truck_shapeops::ray_cast(shell, origin, Vector3::unit_z(), 0.01).unwrap();
+/// assert_eq!(hits.len(), 2);
+/// assert_near!(hits[0].t, 1.0);
+/// assert_near!(hits[0].point, Point3::new(0.5, 0.25, 0.0));
+/// assert_near!(hits[1].t, 2.0);
+/// assert_near!(hits[1].point, Point3::new(0.5, 0.25, 1.0));
+///
+/// let face = shell.face_iter().find(|face| face.id() == hits[1].face_id).unwrap();
+/// let uv = hits[1].uv;
+/// assert_near!(face.surface().subs(uv.x, uv.y), hits[1].point);
+/// ```
+pub fn ray_cast<C, S>(
+    shell: &Shell<Point3, C, S>,
+    origin: Point3,
+    direction: Vector3,
+    tol: f64,
+) -> Option<Vec<Hit<S>>>
+where
+    C: ParametricCurve3D + BoundedCurve + ParameterDivision1D<Point = Point3>,
+    S: ParametricSurface3D
+        + ParameterDivision2D
+        + SearchParameter<D2, Point = Point3>
+        + SearchNearestParameter<D2, Point = Point3>,
+{
+    nonpositive_tolerance!(tol);
+    let domains = shell
+        .face_iter()
+        .map(|face| Some((face.id(), FaceDomain::try_new(face, tol)?)))
+        .collect::<Option<Vec<_>>>()?;
+    let mut hits: Vec<Hit<S>> = domains
+        .into_iter()
+        .filter(|(_, domain)| domain.meets_ray(origin, direction))
+        .flat_map(|(face_id, domain)| {
+            let intersections = domain.ray_intersections(origin, direction, false);
+            intersections
+                .into_iter()
+                .flatten()
+                .filter(|(_, t)| *t >= 0.0)
+                .filter_map(move |(uv, t)| {
+                    let point = origin + direction * t;
+                    let uv = domain
+                        .trimmed(uv)
+                        .or_else(|| (domain.distance_to_boundary(point) < tol).then_some(uv))?;
+                    Some(Hit {
+                        face_id,
+                        uv,
+                        t,
+                        point,
+                    })
+                })
+        })
+        .collect();
+    hits.sort_by(|x, y| x.t.total_cmp(&y.t));
+    Some(hits)
+}
+
+#[cfg(test)]
+mod tests;
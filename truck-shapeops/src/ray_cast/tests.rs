@@ -0,0 +1,89 @@
+use super::*;
+use std::f64::consts::PI;
+use truck_modeling::{builder, Face, Shell, Solid, Surface};
+
+#[test]
+fn ray_cast_to_sphere() {
+    let (r, c) = (1.5, Point3::new(1.0, -1.0, 0.5));
+    let v = builder::vertex(c + Vector3::new(0.0, 0.0, r));
+    let wire = builder::rsweep(&v, c, Vector3::unit_y(), Rad(PI));
+    let shell: Shell = builder::cone(&wire, Vector3::unit_z(), Rad(7.0));
+
+    let origin = c + Vector3::new(-3.0, 0.3, 0.4);
+    let direction = Vector3::new(2.0, 0.0, 0.0);
+    let hits = ray_cast(&shell, origin, direction, 0.01).unwrap();
+    assert_eq!(hits.len(), 2);
+    let x = f64::sqrt(r * r - 0.3 * 0.3 - 0.4 * 0.4);
+    assert_near!(hits[0].point, c + Vector3::new(-x, 0.3, 0.4));
+    assert_near!(hits[1].point, c + Vector3::new(x, 0.3, 0.4));
+    assert_near!(hits[0].t, (3.0 - x) / 2.0);
+    hits.iter().for_each(|hit| {
+        let face = shell
+            .face_iter()
+            .find(|face| face.id() == hit.face_id)
+            .unwrap();
+        assert_near!(face.surface().subs(hit.uv.x, hit.uv.y), hit.point);
+    });
+
+    // the origin in the sphere
+    let hits = ray_cast(&shell, c, Vector3::new(1.0, 0.2, 0.1), 0.01).unwrap();
+    assert_eq!(hits.len(), 1);
+    assert_near!(hits[0].point.distance(c), r);
+
+    // the ray missing the sphere
+    let hits = ray_cast(&shell, origin, -direction, 0.01).unwrap();
+    assert!(hits.is_empty());
+    let hits = ray_cast(
+        &shell,
+        origin + Vector3::new(0.0, 2.0, 0.0),
+        direction,
+        0.01,
+    )
+    .unwrap();
+    assert!(hits.is_empty());
+}
+
+#[test]
+fn ray_cast_to_punched_cube() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let v = builder::vertex(Point3::new(0.5, 0.25, -0.5));
+    let w = builder::rsweep(&v, Point3::new(0.5, 0.5, 0.0), Vector3::unit_z(), Rad(7.0));
+    let f = builder::try_attach_plane(&[w]).unwrap();
+    let mut cylinder = builder::tsweep(&f, Vector3::unit_z() * 2.0);
+    cylinder.not();
+    let punched = crate::and(&cube, &cylinder, 0.05).unwrap();
+    let shell = &punched.boundaries()[0];
+
+    // the ray through the hole
+    let hits = ray_cast(shell, Point3::new(0.5, 0.5, -1.0), Vector3::unit_z(), 0.01).unwrap();
+    assert!(hits.is_empty());
+
+    // the ray through the wall of the hole
+    let hits = ray_cast(shell, Point3::new(-1.0, 0.5, 0.5), Vector3::unit_x(), 0.01).unwrap();
+    let xs: Vec<f64> = hits.iter().map(|hit| hit.point.x).collect();
+    assert_eq!(xs.len(), 4, "{xs:?}");
+    [0.0, 0.25, 0.75, 1.0]
+        .into_iter()
+        .zip(xs)
+        .for_each(|(a, b)| assert_near!(a, b));
+}
+
+#[test]
+fn ray_cast_to_invalid_face() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let mut shell = cube.into_boundaries().pop().unwrap();
+    let plane = Plane::new(
+        Point3::origin(),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    );
+    // the face without boundary, whose domain cannot be made
+    shell.push(Face::new(vec![], Surface::Plane(plane)));
+    assert!(ray_cast(&shell, Point3::new(0.5, 0.5, -1.0), Vector3::unit_z(), 0.01).is_none());
+}
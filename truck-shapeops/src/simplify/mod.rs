@@ -0,0 +1,358 @@
+use crate::parameter::surface_parameter;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use truck_geometry::prelude::*;
+use truck_topology::{history::*, *};
+
+type SolidWithHistory<C, S> = (Solid<Point3, C, S>, History<Point3, C, S>);
+
+/// Whether the boundary of `face` is on `surface` and the normal of `surface`, inverted if `orientation` is `false`,
+/// coincides with the oriented normal of `face`.
+fn on_surface<C, S>(surface: &S, orientation: bool, face: &Face<Point3, C, S>, tol: f64) -> bool
+where
+    C: ParametricCurve3D + BoundedCurve + ParameterDivision1D<Point = Point3>,
+    S: ParametricSurface3D
+        + SearchParameter<D2, Point = Point3>
+        + SearchNearestParameter<D2, Point = Point3>, {
+    let face_surface = face.surface();
+    let sign = |orientation: bool| if orientation { 1.0 } else { -1.0 };
+    let (sign0, sign1) = (sign(orientation), sign(face.orientation()));
+    face.edge_iter().all(|edge| {
+        let curve = edge.curve();
+        let (_, points) = curve.parameter_division(curve.range_tuple(), tol);
+        points.into_iter().all(|pt| {
+            let (Some((u0, v0)), Some((u1, v1))) = (
+                surface_parameter(surface, pt, None),
+                surface_parameter(&face_surface, pt, None),
+            ) else {
+                return false;
+            };
+            let normal0 = surface.normal(u0, v0) * sign0;
+            let normal1 = face_surface.normal(u1, v1) * sign1;
+            surface.subs(u0, v0).distance(pt) < tol && normal0.dot(normal1) > 1.0 - tol
+        })
+    })
+}
+
+/// Returns the surface including the two faces, i.e. the surface of `face0` if the boundary of `face1` is on it,
+/// or the surface concatenated from the surfaces of the faces.
+fn merged_surface<C, S>(
+    face0: &Face<Point3, C, S>,
+    face1: &Face<Point3, C, S>,
+    tol: f64,
+) -> Option<S>
+where
+    C: ParametricCurve3D + BoundedCurve + ParameterDivision1D<Point = Point3>,
+    S: ParametricSurface3D
+        + ConcatSurface
+        + SearchParameter<D2, Point = Point3>
+        + SearchNearestParameter<D2, Point = Point3>,
+{
+    let (surface0, surface1) = (face0.surface(), face1.surface());
+    let orientation = face0.orientation();
+    if on_surface(&surface0, orientation, face1, tol) {
+        return Some(surface0);
+    }
+    let surface = surface0
+        .try_concat_surface(&surface1)
+        .or_else(|| surface1.try_concat_surface(&surface0))?;
+    let on = |face| on_surface(&surface, orientation, face, tol);
+    (on(face0) && on(face1)).then_some(surface)
+}
+
+/// Returns the boundaries of the face merged from two faces adjacent along a connected sequence of edges.
+///
+/// Returns `None` if the faces are not adjacent, or the common edges are not connected,
+/// e.g. the faces are two halves of a cylinder.
+fn merged_boundaries<C, S>(
+    face0: &Face<Point3, C, S>,
+    face1: &Face<Point3, C, S>,
+) -> Option<Vec<Wire<Point3, C>>> {
+    let ids0: HashSet<_> = face0.edge_iter().map(|edge| edge.id()).collect();
+    let shared: HashSet<_> = face1
+        .edge_iter()
+        .map(|edge| edge.id())
+        .filter(|id| ids0.contains(id))
+        .collect();
+    if shared.is_empty() {
+        return None;
+    }
+    let mut next = HashMap::<VertexID<Point3>, Edge<Point3, C>>::default();
+    let (boundaries0, boundaries1) = (face0.boundaries(), face1.boundaries());
+    let edges = boundaries0.iter().chain(&boundaries1).flatten();
+    for edge in edges.filter(|edge| !shared.contains(&edge.id())) {
+        if next.insert(edge.front().id(), edge.clone()).is_some() {
+            return None;
+        }
+    }
+    let mut wires = Vec::new();
+    while let Some(&start) = next.keys().next() {
+        let (mut wire, mut vertex_id) = (Wire::new(), start);
+        while let Some(edge) = next.remove(&vertex_id) {
+            vertex_id = edge.back().id();
+            wire.push_back(edge);
+        }
+        if vertex_id != start {
+            return None;
+        }
+        wires.push(wire);
+    }
+    match wires.len() + 1 == boundaries0.len() + boundaries1.len() {
+        true => Some(wires),
+        false => None,
+    }
+}
+
+/// Creates the face with the absolute boundaries `wires` on `surface`, oriented as `orientation`.
+fn new_face<C, S>(
+    wires: Vec<Wire<Point3, C>>,
+    surface: S,
+    orientation: bool,
+) -> Option<Face<Point3, C, S>> {
+    match orientation {
+        true => Face::try_new(wires, surface).ok(),
+        false => {
+            let wires = wires.iter().map(Wire::inverse).collect();
+            Some(Face::try_new(wires, surface).ok()?.inverse())
+        }
+    }
+}
+
+/// Takes the input entities of `id` from `origins`, which is `id` itself if `id` is not a new entity.
+fn take_origins<T: Copy + Eq + std::hash::Hash>(origins: &mut HashMap<T, Vec<T>>, id: T) -> Vec<T> {
+    origins.remove(&id).unwrap_or_else(|| vec![id])
+}
+
+/// Merges the faces, and records the input faces of the merged faces in `origins`.
+fn merge_same_surface_faces<C, S>(
+    shell: &mut Shell<Point3, C, S>,
+    origins: &mut HashMap<FaceID<S>, Vec<FaceID<S>>>,
+    tol: f64,
+) where
+    C: ParametricCurve3D + BoundedCurve + ParameterDivision1D<Point = Point3>,
+    S: ParametricSurface3D
+        + ConcatSurface
+        + SearchParameter<D2, Point = Point3>
+        + SearchNearestParameter<D2, Point = Point3>,
+{
+    loop {
+        let merged = (0..shell.len()).find_map(|i| {
+            (i + 1..shell.len()).find_map(|j| {
+                let wires = merged_boundaries(&shell[i], &shell[j])?;
+                let surface = merged_surface(&shell[i], &shell[j], tol)?;
+                Some((i, j, new_face(wires, surface, shell[i].orientation())?))
+            })
+        });
+        let Some((i, j, face)) = merged else {
+            return;
+        };
+        let mut ids = take_origins(origins, shell[i].id());
+        ids.extend(take_origins(origins, shell[j].id()));
+        origins.insert(face.id(), ids);
+        shell[i] = face;
+        shell.remove(j);
+    }
+}
+
+/// Whether the oriented curves of the edges are connected with the same tangent and curvature.
+fn smoothly_connected<C>(edge0: &Edge<Point3, C>, edge1: &Edge<Point3, C>, tol: f64) -> bool
+where C: ParametricCurve3D + BoundedCurve + Invertible {
+    let (curve0, curve1) = (edge0.oriented_curve(), edge1.oriented_curve());
+    let (t0, t1) = (curve0.range_tuple().1, curve1.range_tuple().0);
+    let (der0, der1) = (curve0.der(t0), curve1.der(t1));
+    let curvature =
+        |der: Vector3, der2: Vector3| der.cross(der2).cross(der) / der.magnitude2().powi(2);
+    let (curvature0, curvature1) = (
+        curvature(der0, curve0.der2(t0)),
+        curvature(der1, curve1.der2(t1)),
+    );
+    der0.normalize().dot(der1.normalize()) > 1.0 - tol && curvature0.distance(curvature1) < tol
+}
+
+/// Returns the pair of the edges connected at the vertex if just two edges are incident to the vertex.
+fn connected_edges<C: Clone>(
+    shell: &Shell<Point3, C, impl Sized>,
+    vertex_id: VertexID<Point3>,
+) -> Option<(Edge<Point3, C>, Edge<Point3, C>)> {
+    let incident: HashSet<_> = shell
+        .edge_iter()
+        .filter(|edge| edge.front().id() == vertex_id || edge.back().id() == vertex_id)
+        .map(|edge| edge.id())
+        .collect();
+    if incident.len() != 2 {
+        return None;
+    }
+    shell
+        .face_iter()
+        .flat_map(Face::boundaries)
+        .find_map(|wire| {
+            let idx = wire.iter().position(|edge| edge.back().id() == vertex_id)?;
+            Some((wire[idx].clone(), wire[(idx + 1) % wire.len()].clone()))
+        })
+}
+
+/// Concatenates the edges, and records the input edges of the concatenated edges in `origins`.
+fn concat_smooth_edges<C, S>(
+    shell: &mut Shell<Point3, C, S>,
+    origins: &mut HashMap<EdgeID<C>, Vec<EdgeID<C>>>,
+    tol: f64,
+) where
+    C: ParametricCurve3D
+        + BoundedCurve
+        + Concat<C, Point = Point3, Output = C>
+        + Invertible
+        + ParameterTransform,
+{
+    let mut vertex_ids = HashSet::default();
+    let vertex_ids: Vec<_> = shell
+        .vertex_iter()
+        .map(|vertex| vertex.id())
+        .filter(|id| vertex_ids.insert(*id))
+        .collect();
+    vertex_ids.into_iter().for_each(|vertex_id| {
+        let Some((edge0, edge1)) = connected_edges(shell, vertex_id) else {
+            return;
+        };
+        if !smoothly_connected(&edge0, &edge1, tol) {
+            return;
+        }
+        if let Some(edge) = shell.remove_vertex_by_concat_edges(vertex_id) {
+            let mut ids = take_origins(origins, edge0.id());
+            ids.extend(take_origins(origins, edge1.id()));
+            origins.insert(edge.id(), ids);
+        }
+    });
+}
+
+/// Merges the adjacent faces on the same surface, and concatenates the edges on the same curve.
+///
+/// # Details
+/// The boolean operations split the faces and the edges along the intersections, and the pieces remain
+/// split even if they lie on the same surface or curve. This function applies [`Face::glue_at_boundaries`]-like
+/// merging and [`Shell::remove_vertex_by_concat_edges`] systematically.
+/// - Two adjacent faces are merged if the boundary of one face is on the surface of the other within `tol`
+///   and their oriented normals coincide. The surface of one face is used for the merged face.
+/// - Two adjacent faces on the surfaces which are concatenated by [`ConcatSurface`], e.g. the lateral faces
+///   of an extruded circle, are merged into the face on the concatenated surface.
+/// - The faces are not merged if the common edges are not connected, e.g. two halves of a cylinder,
+///   since the merged face would not have a simple boundary.
+/// - The two edges at a vertex with no other edges are concatenated if the tangents and the curvatures
+///   of the curves coincide at the vertex.
+///
+/// Returns `None` if the simplified shells do not form a solid.
+///
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// // a cube whose faces are split at x = 0.5
+/// let v = builder::vertices([(0.0, 0.0, 0.0), (0.5, 0.0, 0.0), (1.0, 0.0, 0.0)]);
+/// let wire: Wire = vec![builder::line(&v[0], &v[1]), builder::line(&v[1], &v[2])].into();
+/// let shell: Shell = builder::tsweep(&wire, Vector3::unit_y());
+/// let mut solids: Vec<truck_topology::Result<Solid>> = builder::tsweep(&shell, Vector3::unit_z());
+/// let cube = solids.pop().unwrap().unwrap();
+/// assert_eq!(cube.boundaries()[0].len(), 10);
+///
+/// let cube = truck_shapeops::simplify(&cube, 0.01).unwrap();
+/// let shell = cube.boundaries()[0].compress();
+/// assert_eq!(shell.faces.len(), 6);
+/// assert_eq!(shell.edges.len(), 12);
+/// assert_eq!(shell.vertices.len(), 8);
+/// ```
+pub fn simplify<C, S>(solid: &Solid<Point3, C, S>, tol: f64) -> Option<Solid<Point3, C, S>>
+where
+    C: ParametricCurve3D
+        + BoundedCurve
+        + ParameterDivision1D<Point = Point3>
+        + Concat<C, Point = Point3, Output = C>
+        + Invertible
+        + ParameterTransform,
+    S: ParametricSurface3D
+        + ConcatSurface
+        + SearchParameter<D2, Point = Point3>
+        + SearchNearestParameter<D2, Point = Point3>, {
+    simplify_with_history(solid, tol).map(|(solid, _)| solid)
+}
+
+/// Simplifies the solid as [`simplify`] with the history.
+///
+/// The merged faces are modified into the new face, and the concatenated edges into the new edge.
+/// The edges between the merged faces and the vertices between the concatenated edges are deleted.
+/// The other entities remain in the result as they are.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// let v = builder::vertices([(0.0, 0.0, 0.0), (0.5, 0.0, 0.0), (1.0, 0.0, 0.0)]);
+/// let wire: Wire = vec![builder::line(&v[0], &v[1]), builder::line(&v[1], &v[2])].into();
+/// let shell: Shell = builder::tsweep(&wire, Vector3::unit_y());
+/// let mut solids: Vec<truck_topology::Result<Solid>> = builder::tsweep(&shell, Vector3::unit_z());
+/// let cube = solids.pop().unwrap().unwrap();
+///
+/// let (simplified, history) = truck_shapeops::simplify_with_history(&cube, 0.01).unwrap();
+/// // the two halves of the bottom are merged.
+/// let bottom = &cube.boundaries()[0][0..2];
+/// assert_eq!(history.modified(&bottom[0]), history.modified(&bottom[1]));
+/// let merged = history.modified(&bottom[0])[0];
+/// assert!(simplified.face_iter().any(|face| EntityID::from(face) == merged));
+/// // the middle vertex of the edges is deleted.
+/// assert!(history.is_deleted(&v[1]));
+/// assert!(!history.is_deleted(&v[0]));
+/// ```
+pub fn simplify_with_history<C, S>(
+    solid: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Option<SolidWithHistory<C, S>>
+where
+    C: ParametricCurve3D
+        + BoundedCurve
+        + ParameterDivision1D<Point = Point3>
+        + Concat<C, Point = Point3, Output = C>
+        + Invertible
+        + ParameterTransform,
+    S: ParametricSurface3D
+        + ConcatSurface
+        + SearchParameter<D2, Point = Point3>
+        + SearchNearestParameter<D2, Point = Point3>,
+{
+    nonpositive_tolerance!(tol);
+    let mut face_origins = HashMap::default();
+    let mut edge_origins = HashMap::default();
+    let boundaries = solid
+        .boundaries()
+        .iter()
+        .map(|shell| {
+            let mut shell = shell.clone();
+            merge_same_surface_faces(&mut shell, &mut face_origins, tol);
+            concat_smooth_edges(&mut shell, &mut edge_origins, tol);
+            shell
+        })
+        .collect();
+    let output = Solid::try_new(boundaries).ok()?;
+    let mut history = History::new();
+    face_origins.into_iter().for_each(|(id, inputs)| {
+        inputs
+            .into_iter()
+            .for_each(|input| history.add_modified(EntityID::Face(input), EntityID::Face(id)));
+    });
+    edge_origins.into_iter().for_each(|(id, inputs)| {
+        inputs
+            .into_iter()
+            .for_each(|input| history.add_modified(EntityID::Edge(input), EntityID::Edge(id)));
+    });
+    let vertices: HashSet<_> = output.vertex_iter().map(|v| v.id()).collect();
+    let edges: HashSet<_> = output.edge_iter().map(|e| e.id()).collect();
+    solid.vertex_iter().for_each(|v| {
+        if !vertices.contains(&v.id()) {
+            history.add_deleted(&v);
+        }
+    });
+    solid.edge_iter().for_each(|e| {
+        if !edges.contains(&e.id()) && history.modified(&e).is_empty() {
+            history.add_deleted(&e);
+        }
+    });
+    Some((output, history))
+}
+
+#[cfg(test)]
+mod tests;
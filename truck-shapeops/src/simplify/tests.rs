@@ -0,0 +1,139 @@
+use super::*;
+use rustc_hash::FxHashSet as HashSet;
+use truck_meshalgo::prelude::*;
+use truck_modeling::{builder, Curve, Face, Shell, Solid, Wire};
+
+#[test]
+fn split_cube() {
+    let v = builder::vertices([
+        (0.0, 0.0, 0.0),
+        (0.3, 0.0, 0.0),
+        (0.6, 0.0, 0.0),
+        (1.0, 0.0, 0.0),
+    ]);
+    let wire: Wire = vec![
+        builder::line(&v[0], &v[1]),
+        builder::line(&v[1], &v[2]),
+        builder::line(&v[2], &v[3]),
+    ]
+    .into();
+    let shell: Shell = builder::tsweep(&wire, Vector3::unit_y());
+    let mut solids: Vec<truck_topology::Result<Solid>> = builder::tsweep(&shell, Vector3::unit_z());
+    let cube = solids.pop().unwrap().unwrap();
+    assert_eq!(cube.boundaries()[0].len(), 14);
+
+    let simplified = simplify(&cube, 0.01).unwrap();
+    let shell = simplified.boundaries()[0].compress();
+    assert_eq!(shell.faces.len(), 6);
+    assert_eq!(shell.edges.len(), 12);
+    assert_eq!(shell.vertices.len(), 8);
+    // the straight edges are concatenated into lines
+    assert!(shell
+        .edges
+        .iter()
+        .all(|edge| matches!(edge.curve, Curve::Line(_))));
+    assert!(crate::check(&simplified, 0.01).is_valid());
+}
+
+#[test]
+fn cylinder() {
+    // the circle split into four arcs
+    let v = builder::vertices([
+        (1.0, 0.0, 0.0),
+        (0.0, 1.0, 0.0),
+        (-1.0, 0.0, 0.0),
+        (0.0, -1.0, 0.0),
+    ]);
+    let circle: Wire = (0..4)
+        .map(|i| {
+            let (p, q) = (v[i].point(), v[(i + 1) % 4].point());
+            let transit = Point3::from_vec((p.to_vec() + q.to_vec()).normalize());
+            builder::circle_arc(&v[i], &v[(i + 1) % 4], transit)
+        })
+        .collect();
+    let disk: Face = builder::try_attach_plane(&[circle]).unwrap();
+    let cylinder: Solid = builder::tsweep(&disk, Vector3::unit_z());
+    assert_eq!(cylinder.boundaries()[0].len(), 6);
+
+    // The lateral faces are merged until the last two faces, which share the two separated edges.
+    let simplified = simplify(&cylinder, 0.01).unwrap();
+    let shell = simplified.boundaries()[0].compress();
+    assert_eq!(shell.faces.len(), 4);
+    assert_eq!(shell.edges.len(), 6);
+    assert_eq!(shell.vertices.len(), 4);
+    assert!(crate::check(&simplified, 0.01).is_valid());
+    simplified.boundaries()[0]
+        .face_iter()
+        .filter(|face| face.boundaries().len() == 1 && face.boundaries()[0].len() == 4)
+        .for_each(|face| {
+            let surface = face.surface();
+            let ((u0, u1), (v0, v1)) = surface.range_tuple();
+            (0..=10).for_each(|i| {
+                let (u, v) = (u0 + (u1 - u0) * i as f64 / 10.0, (v0 + v1) / 2.0);
+                let pt = surface.subs(u, v);
+                assert_near!(pt.to_vec().truncate().magnitude(), 1.0);
+            });
+        });
+    let volume = simplified.triangulation(0.01).to_polygon().volume();
+    assert!(f64::abs(volume - std::f64::consts::PI) < 0.05);
+}
+
+#[test]
+fn boolean_or() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    // the cubes share the face on the plane `x = 1`.
+    let moved = builder::translated(&cube, Vector3::unit_x());
+    let union = crate::or(&cube, &moved, 0.05).unwrap();
+    let shell = union.boundaries()[0].compress();
+    assert_eq!(shell.faces.len(), 10);
+    assert_eq!(shell.edges.len(), 20);
+    assert_eq!(shell.vertices.len(), 12);
+
+    // the coplanar faces of the cubes are merged.
+    let simplified = simplify(&union, 0.05).unwrap();
+    let shell = simplified.boundaries()[0].compress();
+    assert_eq!(shell.faces.len(), 6);
+    assert_eq!(shell.edges.len(), 12);
+    assert_eq!(shell.vertices.len(), 8);
+    assert!(crate::check(&simplified, 0.01).is_valid());
+    let volume = simplified.triangulation(0.01).to_polygon().volume();
+    assert_near!(volume, 2.0);
+}
+
+#[test]
+fn boolean_or_history() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let moved = builder::translated(&cube, Vector3::unit_x());
+    let (union, history0) = crate::or_with_history(&cube, &moved, 0.05).unwrap();
+    let (simplified, history1) = simplify_with_history(&union, 0.05).unwrap();
+    let history = history0.then(&history1);
+
+    // the faces of the cubes except the shared ones are modified into the faces of the box.
+    let faces: Vec<_> = simplified.face_iter().map(EntityID::from).collect();
+    let images: HashSet<_> = cube
+        .face_iter()
+        .chain(moved.face_iter())
+        .filter(|face| !history.is_deleted(*face))
+        .map(|face| match history.modified(face) {
+            [image] => {
+                assert!(faces.contains(image));
+                *image
+            }
+            images => panic!("the images of a face: {images:?}"),
+        })
+        .collect();
+    assert_eq!(images.len(), 6);
+    // the vertices on the shared faces are deleted by the concatenation of the edges.
+    let deleted = cube
+        .vertex_iter()
+        .filter(|v| history.is_deleted(v))
+        .map(|v| v.id())
+        .collect::<HashSet<_>>();
+    assert_eq!(deleted.len(), 4);
+}
@@ -2,7 +2,7 @@
 
 use super::faces_classification::FacesClassification;
 use super::loops_store::*;
-use rustc_hash::FxHashMap as HashMap;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use std::ops::Deref;
 use truck_meshalgo::prelude::*;
 use truck_topology::*;
@@ -48,6 +48,12 @@ struct WireChunk<'a, C> {
     wire: &'a BoundaryWire<Point3, C>,
 }
 
+/// Returns whether the wires consist of the same edges, e.g. a wire and its inverse.
+fn same_edge_set<C>(wire0: &Wire<Point3, C>, wire1: &Wire<Point3, C>) -> bool {
+    let edges = |wire: &Wire<Point3, C>| wire.edge_iter().map(Edge::id).collect::<HashSet<_>>();
+    wire0.len() == wire1.len() && edges(wire0) == edges(wire1)
+}
+
 type FaceWithShapesOpStatus<C, S> = (Face<Point3, C, S>, ShapesOpStatus);
 fn divide_one_face<C, S>(
     face: &Face<Point3, C, S>,
@@ -70,7 +76,11 @@ where
     })?;
     negative_wires.into_iter().try_for_each(|chunk| {
         let pt = chunk.poly.front();
-        let op = pre_faces.iter_mut().find(|face| face[0].poly.include(pt))?;
+        // The innermost loop which includes the wire is the outer boundary of the face.
+        let op = pre_faces
+            .iter_mut()
+            .filter(|face| !same_edge_set(face[0].wire, chunk.wire) && face[0].poly.include(pt))
+            .min_by(|face0, face1| face0[0].poly.area().total_cmp(&face1[0].poly.area()))?;
         op.push(chunk);
         Some(())
     })?;
@@ -117,11 +127,11 @@ where
                 .iter()
                 .all(|wire| wire.status() == ShapesOpStatus::Unknown)
             {
-                res.push(face.clone(), ShapesOpStatus::Unknown);
+                res.push(face.clone(), ShapesOpStatus::Unknown, face.id());
             } else {
                 let vec = divide_one_face(face, loops, tol)?;
                 vec.into_iter()
-                    .for_each(|(face, status)| res.push(face, status));
+                    .for_each(|(piece, status)| res.push(piece, status, face.id()));
             }
             Some(())
         })?;
@@ -87,6 +87,51 @@ fn divide_plane_test() {
     }
 }
 
+#[test]
+fn divide_plane_by_nested_loops() {
+    let square = |a: f64| -> Wire<_, _> {
+        let v = Vertex::news([
+            Point3::new(-a, -a, 0.0),
+            Point3::new(a, -a, 0.0),
+            Point3::new(a, a, 0.0),
+            Point3::new(-a, a, 0.0),
+        ]);
+        (0..4).map(|i| line(&v[i], &v[(i + 1) % 4])).collect()
+    };
+    let (outer, middle, inner) = (square(3.0), square(2.0), square(1.0));
+    let face = Face::new(
+        vec![outer.clone()],
+        Plane::new(
+            Point3::origin(),
+            Point3::new(1.0, 0.0, 0.0),
+            Point3::new(0.0, 1.0, 0.0),
+        ),
+    );
+    // The outer boundary includes all loops, so the holes must be given to the innermost ones.
+    let loops: Loops<_, _> = vec![
+        BoundaryWire::new(outer.clone(), ShapesOpStatus::Unknown),
+        BoundaryWire::new(middle.clone(), ShapesOpStatus::Or),
+        BoundaryWire::new(inner.clone(), ShapesOpStatus::And),
+        BoundaryWire::new(middle.inverse(), ShapesOpStatus::And),
+        BoundaryWire::new(inner.inverse(), ShapesOpStatus::Or),
+    ]
+    .into_iter()
+    .collect();
+    let res = divide_one_face(&face, &loops, 0.01).unwrap();
+    assert_eq!(res.len(), 3);
+    let expected = [
+        (vec![outer, middle.inverse()], ShapesOpStatus::And),
+        (vec![middle, inner.inverse()], ShapesOpStatus::Or),
+        (vec![inner], ShapesOpStatus::And),
+    ];
+    res.iter()
+        .zip(expected)
+        .for_each(|((face, status), (boundaries, expected))| {
+            assert_eq!(face.absolute_boundaries(), &boundaries);
+            assert_eq!(*status, expected);
+        });
+}
+
 type AlternativeIntersection = crate::alternative::Alternative<
     NurbsCurve<Vector4>,
     IntersectionCurve<PolylineCurve<Point3>, AlternativeSurface, AlternativeSurface>,
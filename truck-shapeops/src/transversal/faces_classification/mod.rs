@@ -6,6 +6,8 @@ use truck_topology::*;
 pub struct FacesClassification<P, C, S> {
     shell: Shell<P, C, S>,
     status: HashMap<FaceID<S>, ShapesOpStatus>,
+    /// the faces before division
+    origins: HashMap<FaceID<S>, FaceID<S>>,
 }
 
 impl<P, C, S> Default for FacesClassification<P, C, S> {
@@ -13,16 +15,21 @@ impl<P, C, S> Default for FacesClassification<P, C, S> {
         Self {
             shell: Default::default(),
             status: HashMap::default(),
+            origins: HashMap::default(),
         }
     }
 }
 
 impl<P, C, S> FacesClassification<P, C, S> {
-    pub fn push(&mut self, face: Face<P, C, S>, status: ShapesOpStatus) {
+    pub fn push(&mut self, face: Face<P, C, S>, status: ShapesOpStatus, origin: FaceID<S>) {
         self.status.insert(face.id(), status);
+        self.origins.insert(face.id(), origin);
         self.shell.push(face);
     }
 
+    /// Returns the map from the divided faces to the faces before division.
+    pub fn origins(&self) -> &HashMap<FaceID<S>, FaceID<S>> { &self.origins }
+
     pub fn and_or_unknown(&self) -> [Shell<P, C, S>; 3] {
         let [mut and, mut or, mut unknown] = <[Shell<P, C, S>; 3]>::default();
         for face in &self.shell {
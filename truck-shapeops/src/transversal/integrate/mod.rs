@@ -1,9 +1,10 @@
-use crate::alternative::Alternative;
+use crate::{alternative::Alternative, TRIALS};
 
-use super::*;
+use super::{sources::Sources, *};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
 use truck_geometry::prelude::*;
 use truck_meshalgo::prelude::*;
-use truck_topology::*;
+use truck_topology::{history::*, *};
 
 /// Only solids consisting of faces whose surface is implemented this trait can be used for set operations.
 pub trait ShapeOpsSurface:
@@ -72,14 +73,29 @@ fn altshell_to_shell<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     )
 }
 
+type ShellWithHistory<C, S> = (Shell<Point3, C, S>, History<Point3, C, S>);
+type SolidWithHistory<C, S> = (Solid<Point3, C, S>, History<Point3, C, S>);
+
+type PolyShell = Shell<Point3, PolylineCurve<Point3>, Option<PolygonMesh>>;
+
+/// Returns whether `pt` is inside the triangulated shell, by the crossings of a ray.
+fn is_inside(poly_shell: &PolyShell, pt: Point3) -> Option<bool> {
+    let dir = hash::take_one_unit(pt);
+    let count = poly_shell.iter().try_fold(0, |count, face| {
+        let poly = face.surface()?;
+        Some(count + poly.signed_crossing_faces(pt, dir))
+    })?;
+    Some(count >= 1)
+}
+
 fn process_one_pair_of_shells<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     shell0: &Shell<Point3, C, S>,
     shell1: &Shell<Point3, C, S>,
+    poly_shell0: &PolyShell,
+    poly_shell1: &PolyShell,
     tol: f64,
-) -> Option<[Shell<Point3, C, S>; 2]> {
+) -> Option<[ShellWithHistory<C, S>; 2]> {
     nonpositive_tolerance!(tol);
-    let poly_shell0 = shell0.triangulation(tol);
-    let poly_shell1 = shell1.triangulation(tol);
     let altshell0: AltCurveShell<C, S> =
         shell0.mapped(|x| *x, |c| Alternative::FirstType(c.clone()), Clone::clone);
     let altshell1: AltCurveShell<C, S> =
@@ -88,7 +104,7 @@ fn process_one_pair_of_shells<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
         geom_loops_store0: loops_store0,
         geom_loops_store1: loops_store1,
         ..
-    } = loops_store::create_loops_stores(&altshell0, &poly_shell0, &altshell1, &poly_shell1)?;
+    } = loops_store::create_loops_stores(&altshell0, poly_shell0, &altshell1, poly_shell1)?;
     let mut cls0 = divide_face::divide_faces(&altshell0, &loops_store0, tol)?;
     cls0.integrate_by_component();
     let mut cls1 = divide_face::divide_faces(&altshell1, &loops_store1, tol)?;
@@ -96,12 +112,7 @@ fn process_one_pair_of_shells<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     let [mut and0, mut or0, unknown0] = cls0.and_or_unknown();
     unknown0.into_iter().try_for_each(|face| {
         let pt = face.boundaries()[0].vertex_iter().next().unwrap().point();
-        let dir = hash::take_one_unit(pt);
-        let count = poly_shell1.iter().try_fold(0, |count, face| {
-            let poly = face.surface()?;
-            Some(count + poly.signed_crossing_faces(pt, dir))
-        })?;
-        if count >= 1 {
+        if is_inside(poly_shell1, pt)? {
             and0.push(face);
         } else {
             or0.push(face);
@@ -111,12 +122,7 @@ fn process_one_pair_of_shells<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     let [mut and1, mut or1, unknown1] = cls1.and_or_unknown();
     unknown1.into_iter().try_for_each(|face| {
         let pt = face.boundaries()[0].vertex_iter().next().unwrap().point();
-        let dir = hash::take_one_unit(pt);
-        let count = poly_shell0.iter().try_fold(0, |count, face| {
-            let poly = face.surface()?;
-            Some(count + poly.signed_crossing_faces(pt, dir))
-        })?;
-        if count >= 1 {
+        if is_inside(poly_shell0, pt)? {
             and1.push(face);
         } else {
             or1.push(face);
@@ -125,56 +131,392 @@ fn process_one_pair_of_shells<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     })?;
     and0.append(&mut and1);
     or0.append(&mut or1);
-    Some([
-        altshell_to_shell(&and0, tol)?,
-        altshell_to_shell(&or0, tol)?,
-    ])
+    let mut origins = cls0.origins().clone();
+    origins.extend(cls1.origins().iter().map(|(k, v)| (*k, *v)));
+    let sources = Sources::new(
+        &[shell0, shell1],
+        &[&altshell0, &altshell1],
+        &origins,
+        and0.face_iter().chain(or0.face_iter()),
+    );
+    let with_history = |divided: &AltCurveShell<C, S>| {
+        let output = altshell_to_shell(divided, tol)?;
+        let mut history = History::new();
+        sources.record(&mut history, divided, &output);
+        sources::add_deleted(&mut history, &[shell0, shell1]);
+        Some((output, history))
+    };
+    Some([with_history(&and0)?, with_history(&or0)?])
 }
 
-/// AND operation between two solids.
-pub fn and<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+type EdgePair<C> = (Edge<Point3, C>, Edge<Point3, C>);
+
+/// Returns the oriented normal of `face` at `pt` on the surface.
+fn face_normal<C, S: ShapeOpsSurface>(face: &Face<Point3, C, S>, pt: Point3) -> Option<Vector3> {
+    let surface = face.surface();
+    let (u, v) = surface.search_parameter(pt, None, TRIALS)?;
+    match face.orientation() {
+        true => Some(surface.normal(u, v)),
+        false => Some(-surface.normal(u, v)),
+    }
+}
+
+/// Returns the pairs of the edges of `face1` and the edges of `face0` with the same geometry, if the faces are
+/// the same face with the opposite orientations. The edges of `face1` are absolute, and the edges of `face0`
+/// are oriented in the same direction as them.
+fn shared_face_edges<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    face0: &Face<Point3, C, S>,
+    face1: &Face<Point3, C, S>,
+    tol: f64,
+) -> Option<Vec<EdgePair<C>>> {
+    let edges0: Vec<_> = face0.absolute_boundaries().iter().flatten().collect();
+    let edges1: Vec<_> = face1.absolute_boundaries().iter().flatten().collect();
+    if edges0.len() != edges1.len() {
+        return None;
+    }
+    let pairs = edges1
+        .iter()
+        .map(|edge1| {
+            let (p, q) = (
+                edge1.absolute_front().point(),
+                edge1.absolute_back().point(),
+            );
+            let curve1 = edge1.curve();
+            let (t0, t1) = curve1.try_range_tuple()?;
+            let mid = curve1.subs((t0 + t1) / 2.0);
+            let edge0 = edges0.iter().find_map(|edge0| {
+                let (p0, q0) = (
+                    edge0.absolute_front().point(),
+                    edge0.absolute_back().point(),
+                );
+                let edge0 = if p0.distance(p) < tol && q0.distance(q) < tol {
+                    edge0.absolute_clone()
+                } else if p0.distance(q) < tol && q0.distance(p) < tol {
+                    edge0.absolute_clone().inverse()
+                } else {
+                    return None;
+                };
+                let curve0 = edge0.curve();
+                let t = curve0.search_parameter(mid, None, TRIALS)?;
+                match curve0.subs(t).distance(mid) < tol {
+                    true => Some(edge0),
+                    false => None,
+                }
+            })?;
+            Some((edge1.absolute_clone(), edge0))
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let pt = edges1[0].front().point();
+    let (n0, n1) = (face_normal(face0, pt)?, face_normal(face1, pt)?);
+    if (n0 + n1).magnitude() >= tol {
+        return None;
+    }
+    // the surfaces coincide not only on the boundaries
+    let points: Vec<_> = face1.vertex_iter().map(|v| v.point().to_vec()).collect();
+    let center = Point3::from_vec(points.iter().sum::<Vector3>() / points.len() as f64);
+    let surface1 = face1.surface();
+    let (u, v) = surface1.search_nearest_parameter(center, None, TRIALS)?;
+    face0
+        .surface()
+        .search_parameter(surface1.subs(u, v), None, TRIALS)?;
+    Some(pairs)
+}
+
+/// Returns the bounding box of the vertices of `face`, which coincides with the one of the shared face.
+fn vertices_bounding_box<C, S>(face: &Face<Point3, C, S>) -> BoundingBox<Point3> {
+    face.vertex_iter().map(|v| v.point()).collect()
+}
+
+/// Returns the union of the shells which touch each other only on the shared faces.
+///
+/// The shared faces are removed, and the other faces of `shell1` are attached to the boundaries of the faces
+/// of `shell0`. Returns `None` if there are no shared faces, or the shells overlap besides the shared faces.
+fn glue_shared_faces<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    shell0: &Shell<Point3, C, S>,
+    shell1: &Shell<Point3, C, S>,
+    poly_shell0: &PolyShell,
+    poly_shell1: &PolyShell,
+    tol: f64,
+) -> Option<ShellWithHistory<C, S>> {
+    nonpositive_tolerance!(tol);
+    let near = |bdd0: BoundingBox<Point3>, bdd1: BoundingBox<Point3>| {
+        bdd0.min().distance(bdd1.min()) < tol && bdd0.max().distance(bdd1.max()) < tol
+    };
+    let candidates: Vec<_> = shell1
+        .face_iter()
+        .map(|face1| (face1, vertices_bounding_box(face1)))
+        .collect();
+    let mut deleted = HashSet::<FaceID<S>>::default();
+    let mut vertex_map = HashMap::<VertexID<Point3>, Vertex<Point3>>::default();
+    let mut edge_map = HashMap::<EdgeID<C>, Edge<Point3, C>>::default();
+    shell0.face_iter().for_each(|face0| {
+        let bdd0 = vertices_bounding_box(face0);
+        let shared = candidates.iter().find_map(|(face1, bdd1)| {
+            if !near(bdd0, *bdd1) || deleted.contains(&face1.id()) {
+                return None;
+            }
+            Some((*face1, shared_face_edges(face0, face1, tol)?))
+        });
+        if let Some((face1, pairs)) = shared {
+            deleted.extend([face0.id(), face1.id()]);
+            pairs.into_iter().for_each(|(edge1, edge0)| {
+                vertex_map.insert(edge1.front().id(), edge0.front().clone());
+                vertex_map.insert(edge1.back().id(), edge0.back().clone());
+                edge_map.insert(edge1.id(), edge0);
+            });
+        }
+    });
+    if deleted.is_empty() {
+        return None;
+    }
+
+    // the other parts of the shells are apart from each other
+    let glued_vertices: HashSet<_> = vertex_map.values().map(Vertex::id).collect();
+    let glued_edges: HashSet<_> = edge_map.values().map(Edge::id).collect();
+    let apart = |shell: &Shell<Point3, C, S>, other: &PolyShell| {
+        let vertices = shell
+            .vertex_iter()
+            .filter(|v| !vertex_map.contains_key(&v.id()) && !glued_vertices.contains(&v.id()))
+            .map(|v| Some(v.point()));
+        let edges = shell
+            .edge_iter()
+            .filter(|e| !edge_map.contains_key(&e.id()) && !glued_edges.contains(&e.id()))
+            .map(|e| {
+                let curve = e.curve();
+                let (t0, t1) = curve.try_range_tuple()?;
+                Some(curve.subs((t0 + t1) / 2.0))
+            });
+        vertices
+            .chain(edges)
+            .try_fold(true, |apart, pt| Some(apart && !is_inside(other, pt?)?))
+    };
+    if !apart(shell0, poly_shell1)? || !apart(shell1, poly_shell0)? {
+        return None;
+    }
+
+    let mut vertex = |v: &Vertex<Point3>| {
+        vertex_map
+            .entry(v.id())
+            .or_insert_with(|| v.clone())
+            .clone()
+    };
+    let mut edge = |e: &Edge<Point3, C>| {
+        let absolute = match edge_map.get(&e.id()) {
+            Some(edge) => edge.clone(),
+            None => {
+                let (front, back) = (vertex(e.absolute_front()), vertex(e.absolute_back()));
+                let edge = match front == *e.absolute_front() && back == *e.absolute_back() {
+                    true => e.absolute_clone(),
+                    false => {
+                        let edge = Edge::try_new(&front, &back, e.curve()).ok()?;
+                        edge.set_attributes(e.attributes());
+                        edge
+                    }
+                };
+                edge_map.insert(e.id(), edge.clone());
+                edge
+            }
+        };
+        match e.orientation() {
+            true => Some(absolute),
+            false => Some(absolute.inverse()),
+        }
+    };
+    let mut inputs: Vec<&Face<Point3, C, S>> = Vec::new();
+    let mut faces = Vec::new();
+    shell0
+        .face_iter()
+        .filter(|face| !deleted.contains(&face.id()))
+        .for_each(|face| {
+            inputs.push(face);
+            faces.push(face.clone());
+        });
+    shell1
+        .face_iter()
+        .filter(|face| !deleted.contains(&face.id()))
+        .try_for_each(|face| {
+            let boundaries = face
+                .absolute_boundaries()
+                .iter()
+                .map(|wire| wire.iter().map(&mut edge).collect::<Option<Wire<_, _>>>())
+                .collect::<Option<Vec<_>>>()?;
+            let mut glued = Face::try_new(boundaries, face.surface()).ok()?;
+            if !face.orientation() {
+                glued.invert();
+            }
+            glued.set_attributes(face.attributes());
+            inputs.push(face);
+            faces.push(glued);
+            Some(())
+        })?;
+    let shell: Shell<Point3, C, S> = faces.into();
+    if shell.shell_condition() != ShellCondition::Closed {
+        return None;
+    }
+
+    let output = shell.mapped(|p| *p, Clone::clone, Clone::clone);
+    let mut history = History::new();
+    inputs.into_iter().zip(&output).for_each(|(input, face)| {
+        history.add_modified(input, face);
+    });
+    // the entities of `shell1` are replaced by the ones of `shell0` on the shared faces
+    let vertices: HashMap<_, _> = shell
+        .vertex_iter()
+        .zip(output.vertex_iter())
+        .map(|(v, w)| (v.id(), w))
+        .collect();
+    let edges: HashMap<_, _> = shell
+        .edge_iter()
+        .zip(output.edge_iter())
+        .map(|(e, f)| (e.id(), f))
+        .collect();
+    shell0
+        .vertex_iter()
+        .chain(shell1.vertex_iter())
+        .for_each(|v| {
+            let glued = vertex_map.get(&v.id()).map_or(v.id(), Vertex::id);
+            if let Some(w) = vertices.get(&glued) {
+                history.add_modified(&v, w);
+            }
+        });
+    shell0.edge_iter().chain(shell1.edge_iter()).for_each(|e| {
+        let glued = edge_map.get(&e.id()).map_or(e.id(), Edge::id);
+        if let Some(f) = edges.get(&glued) {
+            history.add_modified(&e, f);
+        }
+    });
+    sources::add_deleted(&mut history, &[shell0, shell1]);
+    Some((output, history))
+}
+
+/// The kind of the boolean operations.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Operation {
+    And,
+    Or,
+}
+
+/// Applies the boolean operation to the solids.
+fn boolean<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     solid0: &Solid<Point3, C, S>,
     solid1: &Solid<Point3, C, S>,
     tol: f64,
-) -> Option<Solid<Point3, C, S>> {
+    operation: Operation,
+) -> Option<SolidWithHistory<C, S>> {
     let mut iter0 = solid0.boundaries().iter();
     let mut iter1 = solid1.boundaries().iter();
     let shell0 = iter0.next().unwrap();
     let shell1 = iter1.next().unwrap();
-    let [mut and_shell, _] = process_one_pair_of_shells(shell0, shell1, tol)?;
-    for shell in iter0 {
-        let [res, _] = process_one_pair_of_shells(&and_shell, shell, tol)?;
-        and_shell = res;
-    }
-    for shell in iter1 {
-        let [res, _] = process_one_pair_of_shells(&and_shell, shell, tol)?;
-        and_shell = res;
+    let process = |shell0: &Shell<Point3, C, S>, shell1: &Shell<Point3, C, S>| {
+        let poly_shell0 = shell0.triangulation(tol);
+        let poly_shell1 = shell1.triangulation(tol);
+        let res = process_one_pair_of_shells(shell0, shell1, &poly_shell0, &poly_shell1, tol);
+        match (res, operation) {
+            (Some([and, _]), Operation::And) => Some(and),
+            (Some([_, or]), Operation::Or) => Some(or),
+            // the shells touching on the shared faces cannot be divided by the intersection curves
+            (None, Operation::Or) => {
+                glue_shared_faces(shell0, shell1, &poly_shell0, &poly_shell1, tol)
+            }
+            (None, Operation::And) => None,
+        }
+    };
+    let (mut shell, mut history) = process(shell0, shell1)?;
+    for other in iter0.chain(iter1) {
+        let (res, next) = process(&shell, other)?;
+        shell = res;
+        history = history.then(&next);
     }
-    let boundaries = and_shell.connected_components();
-    Some(Solid::new(boundaries))
+    let boundaries = shell.connected_components();
+    Some((Solid::new(boundaries), history))
+}
+
+/// AND operation between two solids.
+pub fn and<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Option<Solid<Point3, C, S>> {
+    boolean(solid0, solid1, tol, Operation::And).map(|(solid, _)| solid)
 }
 
 /// OR operation between two solids.
+///
+/// If the solids touch each other only on whole faces, the boundaries cannot be divided by the
+/// intersection curves. In that case, the solids are united by removing the shared faces, provided
+/// that the other parts of the solids are apart from each other.
 pub fn or<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
     solid0: &Solid<Point3, C, S>,
     solid1: &Solid<Point3, C, S>,
     tol: f64,
 ) -> Option<Solid<Point3, C, S>> {
-    let mut iter0 = solid0.boundaries().iter();
-    let mut iter1 = solid1.boundaries().iter();
-    let shell0 = iter0.next().unwrap();
-    let shell1 = iter1.next().unwrap();
-    let [_, mut or_shell] = process_one_pair_of_shells(shell0, shell1, tol)?;
-    for shell in iter0 {
-        let [_, res] = process_one_pair_of_shells(&or_shell, shell, tol)?;
-        or_shell = res;
-    }
-    for shell in iter1 {
-        let [_, res] = process_one_pair_of_shells(&or_shell, shell, tol)?;
-        or_shell = res;
-    }
-    let boundaries = or_shell.connected_components();
-    Some(Solid::new(boundaries))
+    boolean(solid0, solid1, tol, Operation::Or).map(|(solid, _)| solid)
+}
+
+/// AND operation between two solids with the history.
+///
+/// Each face of the input solids is modified into the pieces of it remaining in the result,
+/// or deleted if no piece remains. The pieces inherit the user attributes of the input faces.
+/// Likewise, each edge is modified into its pieces and each vertex into its copy in the result.
+/// The edges on the intersections of the faces are generated by the faces, and the vertices
+/// cutting the edges are generated by the edges.
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+/// let moved = builder::translated(&cube, Vector3::new(0.5, 0.5, 0.5));
+///
+/// let (and, history) = truck_shapeops::and_with_history(&cube, &moved, 0.05).unwrap();
+/// assert_eq!(and.face_iter().count(), 6);
+/// // Each face of the result is a piece of a face of the inputs.
+/// let pieces = cube
+///     .face_iter()
+///     .chain(moved.face_iter())
+///     .map(|face| history.modified(face).len())
+///     .sum::<usize>();
+/// assert_eq!(pieces, 6);
+/// // The faces of the cube on the boundary of the other cube are deleted.
+/// assert!(history.is_deleted(&cube.boundaries()[0][0]));
+///
+/// // The corner of the cube inside the other cube remains in the result.
+/// let corner = cube.vertex_iter().find(|v| v.point() == Point3::new(1.0, 1.0, 1.0)).unwrap();
+/// let image = history.modified(&corner);
+/// assert_eq!(image.len(), 1);
+/// assert!(and.vertex_iter().any(|v| EntityID::from(&v) == image[0]));
+/// // The corner of the cube outside the other cube is deleted.
+/// assert!(history.is_deleted(&cube.vertex_iter().next().unwrap()));
+/// // Each intersection edge is generated by a face of each cube.
+/// let generated = cube
+///     .face_iter()
+///     .chain(moved.face_iter())
+///     .flat_map(|face| history.generated(face))
+///     .filter(|id| matches!(id, EntityID::Edge(_)))
+///     .count();
+/// assert_eq!(generated, 12);
+/// ```
+pub fn and_with_history<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Option<SolidWithHistory<C, S>> {
+    boolean(solid0, solid1, tol, Operation::And)
+}
+
+/// OR operation between two solids with the history.
+///
+/// The history is recorded in the same way as [`and_with_history`]. If the solids are united by
+/// removing the shared faces, the entities on the boundaries of the shared faces are modified
+/// into the ones remaining in the result.
+pub fn or_with_history<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid0: &Solid<Point3, C, S>,
+    solid1: &Solid<Point3, C, S>,
+    tol: f64,
+) -> Option<SolidWithHistory<C, S>> {
+    boolean(solid0, solid1, tol, Operation::Or)
 }
 
 #[cfg(test)]
@@ -1,3 +1,4 @@
+use rustc_hash::FxHashSet as HashSet;
 use truck_meshalgo::prelude::*;
 use truck_modeling::*;
 
@@ -19,3 +20,168 @@ fn punched_cube() {
     let file = std::fs::File::create("punched-cube.obj").unwrap();
     obj::write(&poly, file).unwrap();
 }
+
+#[test]
+fn punched_cube_history() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+
+    let v = builder::vertex(Point3::new(0.5, 0.25, -0.5));
+    let w = builder::rsweep(&v, Point3::new(0.5, 0.5, 0.0), Vector3::unit_z(), Rad(7.0));
+    let f = builder::try_attach_plane(&[w]).unwrap();
+    let mut cylinder = builder::tsweep(&f, Vector3::unit_z() * 2.0);
+    cylinder.not();
+    let (and, history) = crate::and_with_history(&cube, &cylinder, 0.05).unwrap();
+
+    // every face of the result is a piece of one input face.
+    and.face_iter().for_each(|face| {
+        let count = cube
+            .face_iter()
+            .chain(cylinder.face_iter())
+            .filter(|input| history.modified(*input).contains(&face.into()))
+            .count();
+        assert_eq!(count, 1);
+    });
+    // the caps of the cylinder are out of the cube.
+    let shell = &cylinder.boundaries()[0];
+    let deleted = shell
+        .face_iter()
+        .filter(|face| history.is_deleted(*face))
+        .count();
+    assert_eq!(deleted, 2);
+    cube.face_iter()
+        .for_each(|face| assert!(!history.is_deleted(face)));
+
+    // every edge of the result is a piece of one input edge, or the intersection of two input faces.
+    and.edge_iter().for_each(|edge| {
+        let id = EntityID::from(&edge);
+        let pieces = cube
+            .edge_iter()
+            .chain(cylinder.edge_iter())
+            .filter(|input| history.modified(input).contains(&id))
+            .map(|input| input.id())
+            .collect::<HashSet<_>>()
+            .len();
+        let generators = cube
+            .face_iter()
+            .chain(cylinder.face_iter())
+            .filter(|input| history.generated(*input).contains(&id))
+            .count();
+        assert!(matches!((pieces, generators), (1, 0) | (0, 2)));
+    });
+    // the edges and the vertices of the cube are not cut.
+    cube.edge_iter()
+        .for_each(|edge| assert_eq!(history.modified(&edge).len(), 1));
+    cube.vertex_iter()
+        .for_each(|v| assert_eq!(history.modified(&v).len(), 1));
+}
+
+#[test]
+fn punched_cube_attributes() {
+    use truck_topology::attribute::AttributeValue;
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    cube.face_iter().enumerate().for_each(|(i, face)| {
+        face.set_attribute("index", i as i64);
+    });
+
+    let v = builder::vertex(Point3::new(0.5, 0.25, -0.5));
+    let w = builder::rsweep(&v, Point3::new(0.5, 0.5, 0.0), Vector3::unit_z(), Rad(7.0));
+    let f = builder::try_attach_plane(&[w]).unwrap();
+    let mut cylinder = builder::tsweep(&f, Vector3::unit_z() * 2.0);
+    cylinder.not();
+    cylinder.face_iter().for_each(|face| {
+        face.set_attribute("name", "hole");
+    });
+    let and = crate::and(&cube, &cylinder, 0.05).unwrap();
+
+    let count = |solid: &Solid, key: &str| {
+        solid
+            .face_iter()
+            .filter(|face| face.attribute(key).is_some())
+            .count()
+    };
+    assert_eq!(
+        count(&and, "index") + count(&and, "name"),
+        and.face_iter().count()
+    );
+    assert_eq!(count(&and, "index"), 6);
+    assert!(count(&and, "name") > 0);
+
+    // the attributes are serialized with the compressed solid.
+    let json = serde_json::to_vec(&and.compress()).unwrap();
+    let solid = Solid::extract(serde_json::from_slice(&json).unwrap()).unwrap();
+    let indices: Vec<_> = solid
+        .face_iter()
+        .filter_map(|face| face.attribute("index"))
+        .collect();
+    assert_eq!(indices.len(), 6);
+    (0..6).for_each(|i| assert!(indices.contains(&AttributeValue::Integer(i))));
+}
+
+#[test]
+fn touching_cubes() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let moved = builder::translated(&cube, Vector3::unit_x());
+    let (or, history) = crate::or_with_history(&cube, &moved, 0.05).unwrap();
+    let shell = &or.boundaries()[0];
+    assert_eq!(shell.len(), 10);
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+
+    // the shared faces are deleted, and the others are kept as they are.
+    let deleted: Vec<_> = cube
+        .face_iter()
+        .chain(moved.face_iter())
+        .filter(|face| history.is_deleted(*face))
+        .collect();
+    assert_eq!(deleted.len(), 2);
+    deleted.iter().for_each(|face| {
+        face.vertex_iter()
+            .for_each(|v| assert_near!(v.point().x, 1.0));
+    });
+    cube.face_iter()
+        .chain(moved.face_iter())
+        .filter(|face| !history.is_deleted(*face))
+        .for_each(|face| assert_eq!(history.modified(face).len(), 1));
+    let volume = or.triangulation(0.01).to_polygon().volume();
+    assert_near!(volume, 2.0);
+
+    // the vertices and the edges on the shared faces are glued.
+    let vertex_images: HashSet<_> = cube
+        .vertex_iter()
+        .chain(moved.vertex_iter())
+        .map(|v| match history.modified(&v) {
+            [image] => *image,
+            images => panic!("the images of a vertex: {images:?}"),
+        })
+        .collect();
+    assert_eq!(vertex_images.len(), 12);
+    let edge_images: HashSet<_> = cube
+        .edge_iter()
+        .chain(moved.edge_iter())
+        .map(|e| match history.modified(&e) {
+            [image] => *image,
+            images => panic!("the images of an edge: {images:?}"),
+        })
+        .collect();
+    assert_eq!(edge_images.len(), 20);
+}
+
+#[test]
+fn partially_touching_cubes() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+    // the cubes touch each other on the halves of the faces on the plane `x = 1`,
+    // so no face is shared as a whole and the solids are not glued.
+    let moved = builder::translated(&cube, Vector3::new(1.0, 0.5, 0.0));
+    assert!(crate::or(&cube, &moved, 0.05).is_none());
+}
@@ -4,4 +4,7 @@ mod integrate;
 mod intersection_curve;
 mod loops_store;
 mod polyline_construction;
-pub use integrate::{and, or, ShapeOpsCurve, ShapeOpsSurface};
+mod sources;
+mod split;
+pub use integrate::{and, and_with_history, or, or_with_history, ShapeOpsCurve, ShapeOpsSurface};
+pub use split::{section, section_with_history, split_solid, split_solid_with_history};
@@ -0,0 +1,197 @@
+use crate::TRIALS;
+use rustc_hash::FxHashMap as HashMap;
+use truck_geometry::prelude::*;
+use truck_topology::{history::*, *};
+
+type EntityId<C, S> = EntityID<Point3, C, S>;
+
+/// The input entity from which an entity of the divided shells comes.
+enum Source<C, S> {
+    /// The entity is a piece or a copy of the input entity.
+    Modified(EntityId<C, S>),
+    /// The entity is made from the input entity, e.g. the intersection edge on an input face.
+    Generated(EntityId<C, S>),
+}
+
+impl<C, S> Clone for Source<C, S> {
+    #[inline(always)]
+    fn clone(&self) -> Self { *self }
+}
+
+impl<C, S> Copy for Source<C, S> {}
+
+impl<C, S> Source<C, S> {
+    fn record(self, history: &mut History<Point3, C, S>, output: EntityId<C, S>) {
+        match self {
+            Self::Modified(input) => history.add_modified(input, output),
+            Self::Generated(input) => history.add_generated(input, output),
+        }
+    }
+
+    /// Records the images of the sources of an entity. The copy of an input entity is not generated
+    /// by the other entities.
+    fn record_all(sources: &[Self], history: &mut History<Point3, C, S>, output: EntityId<C, S>) {
+        let modified = sources.iter().filter(|s| matches!(s, Self::Modified(_)));
+        match modified.clone().next() {
+            Some(_) => modified.for_each(|s| s.record(history, output)),
+            None => sources.iter().for_each(|s| s.record(history, output)),
+        }
+    }
+
+    fn generating(self) -> Self {
+        match self {
+            Self::Modified(input) | Self::Generated(input) => Self::Generated(input),
+        }
+    }
+}
+
+/// Returns the edge of `face` on which `edge` lies.
+fn original_edge<D, S>(
+    face: &Face<Point3, D, S>,
+    edge: &Edge<Point3, D>,
+) -> Option<Edge<Point3, D>>
+where
+    D: ParametricCurve3D + BoundedCurve + SearchParameter<D1, Point = Point3>,
+{
+    if let Some(original) = face.edge_iter().find(|original| original.id() == edge.id()) {
+        return Some(original);
+    }
+    let curve = edge.curve();
+    let (t0, t1) = curve.range_tuple();
+    let mid = curve.subs((t0 + t1) / 2.0);
+    face.edge_iter().find(|original| {
+        let curve = original.curve();
+        let (t0, t1) = curve.range_tuple();
+        let t = curve.search_parameter(mid, None, TRIALS);
+        t.is_some_and(|t| t0 <= t && t <= t1)
+    })
+}
+
+/// The correspondence from the entities of the faces divided by the intersection curves to the entities
+/// of the input shells.
+///
+/// The input shells are mapped to the shells with the curves of the division, and the faces of the mapped
+/// shells are divided into the pieces. The division cuts the edges and replaces the vertices, so the edges
+/// and the vertices of the pieces are related to the input ones by the geometry:
+/// - an edge of a piece is a piece of the input edge on which it lies,
+/// - the other edges of a piece, i.e. the intersection edges, are generated by the input faces of the pieces
+///   sharing it,
+/// - a vertex of a piece is a copy of the input vertex at the same point, and
+/// - the other vertices are generated by the input entities from which the edges incident to them come.
+pub(super) struct Sources<C, D, S> {
+    faces: HashMap<FaceID<S>, Face<Point3, C, S>>,
+    edges: HashMap<EdgeID<D>, Vec<Source<C, S>>>,
+    vertices: HashMap<VertexID<Point3>, Vec<Source<C, S>>>,
+}
+
+impl<C, D, S> Sources<C, D, S>
+where D: ParametricCurve3D + BoundedCurve + SearchParameter<D1, Point = Point3>
+{
+    /// Creates the correspondence for the pieces whose faces before division are recorded in `origins`.
+    ///
+    /// `inputs[i]` is mapped into `mapped[i]`.
+    pub(super) fn new<'a>(
+        inputs: &[&Shell<Point3, C, S>],
+        mapped: &[&Shell<Point3, D, S>],
+        origins: &HashMap<FaceID<S>, FaceID<S>>,
+        pieces: impl IntoIterator<Item = &'a Face<Point3, D, S>>,
+    ) -> Self
+    where
+        C: 'a,
+        D: 'a,
+        S: 'a,
+    {
+        let pairs = || mapped.iter().zip(inputs);
+        let mapped_faces: HashMap<_, _> = pairs()
+            .flat_map(|(mapped, input)| mapped.face_iter().zip(input.face_iter()))
+            .map(|(mapped, input)| (mapped.id(), (mapped, input)))
+            .collect();
+        let mapped_edges: HashMap<_, _> = pairs()
+            .flat_map(|(mapped, input)| mapped.edge_iter().zip(input.edge_iter()))
+            .map(|(mapped, input)| (mapped.id(), EntityID::from(&input)))
+            .collect();
+        let mapped_vertices: HashMap<_, _> = pairs()
+            .flat_map(|(mapped, input)| mapped.vertex_iter().zip(input.vertex_iter()))
+            .map(|(mapped, input)| (mapped.id(), EntityID::from(&input)))
+            .collect();
+        let mut sources = Self {
+            faces: HashMap::default(),
+            edges: HashMap::default(),
+            vertices: HashMap::default(),
+        };
+        pieces.into_iter().for_each(|piece| {
+            let Some((face, input)) = origins.get(&piece.id()).and_then(|id| mapped_faces.get(id))
+            else {
+                return;
+            };
+            sources.faces.insert(piece.id(), (*input).clone());
+            piece.edge_iter().for_each(|edge| {
+                let source = match original_edge(face, &edge) {
+                    Some(original) => Source::Modified(mapped_edges[&original.id()]),
+                    None => Source::Generated(EntityID::from(*input)),
+                };
+                sources.edges.entry(edge.id()).or_default().push(source);
+                [edge.front(), edge.back()].into_iter().for_each(|v| {
+                    let vertex = face
+                        .vertex_iter()
+                        .find(|w| w.id() == v.id() || w.point().near(&v.point()));
+                    let source = match vertex {
+                        Some(w) => Source::Modified(mapped_vertices[&w.id()]),
+                        None => source.generating(),
+                    };
+                    sources.vertices.entry(v.id()).or_default().push(source);
+                });
+            });
+        });
+        sources
+    }
+
+    /// Records the images of the input entities in `output`, which is mapped from `divided`.
+    ///
+    /// The faces of `divided` which are not pieces, e.g. the caps of a split, are skipped.
+    /// The pieces in `output` inherit the user attributes of the input faces.
+    pub(super) fn record(
+        &self,
+        history: &mut History<Point3, C, S>,
+        divided: &Shell<Point3, D, S>,
+        output: &Shell<Point3, C, S>,
+    ) {
+        divided.face_iter().zip(output).for_each(|(piece, face)| {
+            if let Some(input) = self.faces.get(&piece.id()) {
+                face.set_attributes(input.attributes());
+                history.add_modified(input, face);
+            }
+        });
+        divided
+            .edge_iter()
+            .zip(output.edge_iter())
+            .for_each(|(edge, out)| {
+                if let Some(sources) = self.edges.get(&edge.id()) {
+                    Source::record_all(sources, history, EntityID::from(&out));
+                }
+            });
+        divided
+            .vertex_iter()
+            .zip(output.vertex_iter())
+            .for_each(|(vertex, out)| {
+                if let Some(sources) = self.vertices.get(&vertex.id()) {
+                    Source::record_all(sources, history, EntityID::from(&out));
+                }
+            });
+    }
+}
+
+/// Records the entities of `inputs` which are not modified into any entities as deleted.
+pub(super) fn add_deleted<C, S>(
+    history: &mut History<Point3, C, S>,
+    inputs: &[&Shell<Point3, C, S>],
+) {
+    let ids = inputs.iter().flat_map(|shell| {
+        let vertices = shell.vertex_iter().map(|v| EntityID::from(&v));
+        let edges = shell.edge_iter().map(|e| EntityID::from(&e));
+        let faces = shell.face_iter().map(EntityID::from);
+        vertices.chain(edges).chain(faces)
+    });
+    let deleted: Vec<_> = ids.filter(|id| history.modified(*id).is_empty()).collect();
+    deleted.into_iter().for_each(|id| history.add_deleted(id));
+}
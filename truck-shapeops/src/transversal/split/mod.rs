@@ -0,0 +1,427 @@
+use super::integrate::{ShapeOpsCurve, ShapeOpsSurface};
+use super::loops_store;
+use super::sources::{self, Sources};
+use super::*;
+use crate::{alternative::Alternative, TRIALS};
+use truck_geometry::prelude::*;
+use truck_meshalgo::prelude::*;
+use truck_topology::{history::History, *};
+
+type ToolCurve<S> = PCurve<Line<Point2>, S>;
+type SplitCurve<C, S> =
+    Alternative<Alternative<C, ToolCurve<S>>, IntersectionCurve<PolylineCurve<Point3>, S, S>>;
+type SplitShell<C, S> = Shell<Point3, SplitCurve<C, S>, S>;
+type Solids<C, S> = Vec<Solid<Point3, C, S>>;
+type SplitShells<C, S> = ([SplitShell<C, S>; 3], Sources<C, SplitCurve<C, S>, S>);
+type SplitWithHistory<C, S> = (Solids<C, S>, Solids<C, S>, History<Point3, C, S>);
+type WiresWithHistory<C, S> = (Vec<Wire<Point3, C>>, History<Point3, C, S>);
+
+/// Creates the rectangle face on `surface` which covers the bounding box `bdd_box`.
+fn tool_face<S: ShapeOpsSurface>(
+    surface: &S,
+    bdd_box: &BoundingBox<Point3>,
+) -> Option<Face<Point3, ToolCurve<S>, S>> {
+    let (urange, vrange) = surface.try_range_tuple();
+    let (min, max) = (bdd_box.min(), bdd_box.max());
+    let mut uv_box = BoundingBox::<Point2>::new();
+    (0..8).try_for_each(|i| {
+        let x = if i & 1 == 0 { min.x } else { max.x };
+        let y = if i & 2 == 0 { min.y } else { max.y };
+        let z = if i & 4 == 0 { min.z } else { max.z };
+        let (u, v) = surface.search_nearest_parameter(Point3::new(x, y, z), None, TRIALS)?;
+        uv_box.push(Point2::new(u, v));
+        Some(())
+    })?;
+    let (uv_min, uv_max) = (uv_box.min(), uv_box.max());
+    let margin = uv_box.diagonal() * 0.1 + Vector2::new(1.0, 1.0);
+    // The nominal range is respected only if the nearest points do not leave it.
+    let clamp = |range: Option<(f64, f64)>, min: f64, max: f64, margin: f64| match range {
+        Some((t0, t1)) if t0 - TOLERANCE < min && max < t1 + TOLERANCE => {
+            (f64::max(t0, min - margin), f64::min(t1, max + margin))
+        }
+        _ => (min - margin, max + margin),
+    };
+    let (u0, u1) = clamp(urange, uv_min.x, uv_max.x, margin.x);
+    let (v0, v1) = clamp(vrange, uv_min.y, uv_max.y, margin.y);
+    let corners = [
+        Point2::new(u0, v0),
+        Point2::new(u1, v0),
+        Point2::new(u1, v1),
+        Point2::new(u0, v1),
+    ];
+    let vertices: Vec<_> = corners
+        .iter()
+        .map(|p| Vertex::new(surface.subs(p.x, p.y)))
+        .collect();
+    let wire: Wire<_, _> = (0..4)
+        .map(|i| {
+            let line = Line(corners[i], corners[(i + 1) % 4]);
+            let curve = PCurve::new(line, surface.clone());
+            Edge::new(&vertices[i], &vertices[(i + 1) % 4], curve)
+        })
+        .collect();
+    Face::try_new(vec![wire], surface.clone()).ok()
+}
+
+type SplitIntersectionCurve<S> = IntersectionCurve<PolylineCurve<Point3>, S, S>;
+
+fn split_shell_to_shell<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    shell: &SplitShell<C, S>,
+    intersection_curve: impl Fn(&SplitIntersectionCurve<S>) -> Option<C>,
+) -> Option<Shell<Point3, C, S>> {
+    shell.try_mapped(
+        |p| Some(*p),
+        |c| match c {
+            Alternative::FirstType(Alternative::FirstType(c)) => Some(c.clone()),
+            // The boundary of the tool face is always outside of the solid.
+            Alternative::FirstType(Alternative::SecondType(_)) => None,
+            Alternative::SecondType(ic) => intersection_curve(ic),
+        },
+        |s| Some(s.clone()),
+    )
+}
+
+/// Approximates the leader of the intersection curve by a B-spline curve.
+fn approximated_curve<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    ic: &SplitIntersectionCurve<S>,
+    tol: f64,
+) -> Option<C> {
+    let bsp = BSplineCurve::quadratic_approximation(ic, ic.range_tuple(), tol, 100)?;
+    Some(IntersectionCurve::new(ic.surface0().clone(), ic.surface1().clone(), bsp).into())
+}
+
+fn circum_center(pt0: Point3, pt1: Point3, pt2: Point3) -> Option<Point3> {
+    let (vec0, vec1) = (pt1 - pt0, pt2 - pt0);
+    let (a2, ab, b2) = (vec0.dot(vec0), vec0.dot(vec1), vec1.dot(vec1));
+    let det = a2 * b2 - ab * ab;
+    if det.so_small() {
+        return None;
+    }
+    let (u, v) = (a2 * b2 - ab * b2, a2 * b2 - ab * a2);
+    Some(pt0 + u / (2.0 * det) * vec0 + v / (2.0 * det) * vec1)
+}
+
+/// Returns the exact line, circle arc or circle if the intersection curve is on it within `tol`,
+/// e.g. the sections of planes, cylinders and spheres by a plane.
+fn analytic_curve<C, S>(ic: &SplitIntersectionCurve<S>, tol: f64) -> Option<C>
+where
+    C: From<Line<Point3>> + From<NurbsCurve<Vector4>>,
+    S: ShapeOpsSurface, {
+    const DIVISION: usize = 16;
+    let (t0, t1) = ic.range_tuple();
+    let samples = (1..DIVISION)
+        .map(|i| ic.subs(t0 + (t1 - t0) * i as f64 / DIVISION as f64))
+        .collect::<Vec<_>>();
+    let (p0, p1) = (ic.leader().front(), ic.leader().back());
+    let closed = p0.distance(p1) < tol;
+    if !closed {
+        let dir = p1 - p0;
+        let on_line = |p: &Point3| {
+            let diff = p - p0 - dir * (p - p0).dot(dir) / dir.magnitude2();
+            diff.magnitude() < tol
+        };
+        if samples.iter().all(on_line) {
+            return Some(Line(p0, p1).into());
+        }
+    }
+    // the circle through the start point and the two points after it
+    let (pt1, pt2) = match closed {
+        true => (samples[DIVISION / 4 - 1], samples[DIVISION / 2 - 1]),
+        false => (samples[DIVISION / 2 - 1], p1),
+    };
+    let center = circum_center(p0, pt1, pt2)?;
+    let (vec0, vec1) = (p0 - pt1, pt2 - pt1);
+    let axis = vec1.cross(vec0).normalize();
+    let radius = p0.distance(center);
+    let on_circle = |p: &Point3| {
+        f64::abs(p.distance(center) - radius) < tol && f64::abs((p - center).dot(axis)) < tol
+    };
+    if !samples.iter().all(on_circle) {
+        return None;
+    }
+    let angle = match closed {
+        true => Rad(2.0 * std::f64::consts::PI),
+        false => (Rad(std::f64::consts::PI) - vec0.angle(vec1)) * 2.0,
+    };
+    let diag = p0 - center;
+    let mat = Matrix4::from_cols(
+        diag.extend(0.0),
+        axis.cross(diag).extend(0.0),
+        axis.extend(0.0),
+        center.to_homogeneous(),
+    );
+    // the arc is divided into the segments of at most the right angle, which can represent the whole circle
+    let nurbs = UnitCircle::<Point3>::new().to_nurbs_in((0.0, angle.0))?;
+    Some(nurbs.transformed(mat).into())
+}
+
+fn shell_to_solids<C, S>(shell: Shell<Point3, C, S>) -> Option<Solids<C, S>> {
+    shell
+        .connected_components()
+        .into_iter()
+        .map(|shell| Solid::try_new(vec![shell]).ok())
+        .collect()
+}
+
+/// Returns the faces of `solid` on the back side and the front side of `surface`,
+/// and the faces of `surface` inside `solid`, with the sources of the faces of `solid`.
+fn split_into_shells<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid: &Solid<Point3, C, S>,
+    surface: &S,
+    tol: f64,
+) -> Option<SplitShells<C, S>> {
+    nonpositive_tolerance!(tol);
+    let poly_solid = solid.triangulation(tol);
+    let mut bdd_box: BoundingBox<Point3> = poly_solid
+        .face_iter()
+        .filter_map(|face| face.surface())
+        .flat_map(|poly| poly.positions().to_vec())
+        .collect();
+    let diag = bdd_box.diagonal().magnitude() * 0.1 + tol;
+    bdd_box.push(bdd_box.min() - Vector3::new(diag, diag, diag));
+    bdd_box.push(bdd_box.max() + Vector3::new(diag, diag, diag));
+    let tool: Shell<_, _, _> = vec![tool_face(surface, &bdd_box)?].into();
+    let poly_tool = tool.triangulation(tol);
+    let split_tool: SplitShell<C, S> = tool.mapped(
+        |x| *x,
+        |c| Alternative::FirstType(Alternative::SecondType(c.clone())),
+        Clone::clone,
+    );
+
+    let shell = solid.boundaries().first()?;
+    let split_shell: SplitShell<C, S> = shell.mapped(
+        |x| *x,
+        |c| Alternative::FirstType(Alternative::FirstType(c.clone())),
+        Clone::clone,
+    );
+    let poly_shell = &poly_solid.boundaries()[0];
+    let loops_store::LoopsStoreQuadruple {
+        geom_loops_store0: loops_store0,
+        geom_loops_store1: loops_store1,
+        ..
+    } = loops_store::create_loops_stores(&split_shell, poly_shell, &split_tool, &poly_tool)?;
+    let mut cls0 = divide_face::divide_faces(&split_shell, &loops_store0, tol)?;
+    cls0.integrate_by_component();
+    let [mut back, mut front, unknown] = cls0.and_or_unknown();
+    unknown.into_iter().try_for_each(|face| {
+        let pt = face.boundaries()[0].vertex_iter().next().unwrap().point();
+        let (u, v) = surface.search_nearest_parameter(pt, None, TRIALS)?;
+        match (pt - surface.subs(u, v)).dot(surface.normal(u, v)) < 0.0 {
+            true => back.push(face),
+            false => front.push(face),
+        }
+        Some(())
+    })?;
+    let mut cls1 = divide_face::divide_faces(&split_tool, &loops_store1, tol)?;
+    cls1.integrate_by_component();
+    let [caps, _, _] = cls1.and_or_unknown();
+    let sources = Sources::new(
+        &[shell],
+        &[&split_shell],
+        cls0.origins(),
+        back.face_iter().chain(front.face_iter()),
+    );
+    Some(([back, front, caps], sources))
+}
+
+/// Splits `solid` by `surface`.
+///
+/// Returns the pieces on the back side of `surface`, i.e. the opposite side to the normal,
+/// and the pieces on the front side. The cut faces are capped by the parts of `surface` inside `solid`.
+/// If `surface` does not meet `solid`, one of the returned vectors is empty.
+///
+/// # Remarks
+/// - The portion of `surface` needed for the cut is determined from the bounding box of `solid`.
+///   If the nearest points of the box stay in the parameter range of `surface`,
+///   the surface is not extended beyond the range, so it must cut through `solid` completely.
+/// - The surface must not be closed around `solid`, e.g. a cylinder containing the whole solid is not supported.
+/// - Only the first boundary shell of `solid` is split, i.e. solids with inner cavities are not supported.
+/// - Each connected component of the result becomes one solid.
+///
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// let plane = Plane::new(
+///     Point3::new(0.0, 0.0, 0.5),
+///     Point3::new(1.0, 0.0, 0.5),
+///     Point3::new(0.0, 1.0, 0.5),
+/// );
+/// let (back, front) = truck_shapeops::split_solid(&cube, &Surface::Plane(plane), 0.05).unwrap();
+/// assert_eq!(back.len(), 1);
+/// assert_eq!(front.len(), 1);
+/// assert_eq!(back[0].boundaries()[0].len(), 6);
+/// assert_eq!(front[0].boundaries()[0].len(), 6);
+/// ```
+pub fn split_solid<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid: &Solid<Point3, C, S>,
+    surface: &S,
+    tol: f64,
+) -> Option<(Solids<C, S>, Solids<C, S>)> {
+    split_solid_with_history(solid, surface, tol).map(|(back, front, _)| (back, front))
+}
+
+/// Splits `solid` by `surface` with the history.
+///
+/// Each face, edge, and vertex of `solid` is modified into the pieces of it in the both sides, and the edges on
+/// `surface` are generated by the faces of `solid`. The caps, i.e. the faces on `surface`, come from no entities of
+/// `solid`. See [`and_with_history`](crate::and_with_history) for the details.
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use truck_modeling::*;
+///
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// let plane = Plane::new(
+///     Point3::new(0.0, 0.0, 0.5),
+///     Point3::new(1.0, 0.0, 0.5),
+///     Point3::new(0.0, 1.0, 0.5),
+/// );
+/// let (back, front, history) =
+///     truck_shapeops::split_solid_with_history(&cube, &Surface::Plane(plane), 0.05).unwrap();
+/// assert_eq!((back.len(), front.len()), (1, 1));
+///
+/// // the side faces are split into two pieces, and the bottom and the top remain.
+/// let pieces = cube
+///     .face_iter()
+///     .map(|face| history.modified(face).len())
+///     .collect::<Vec<_>>();
+/// assert_eq!(pieces.iter().filter(|n| **n == 2).count(), 4);
+/// assert_eq!(pieces.iter().filter(|n| **n == 1).count(), 2);
+/// // the vertical edges are split, and the others remain.
+/// let split_edges: HashSet<_> = cube
+///     .edge_iter()
+///     .filter(|edge| history.modified(edge).len() == 2)
+///     .map(|edge| edge.id())
+///     .collect();
+/// assert_eq!(split_edges.len(), 4);
+/// ```
+pub fn split_solid_with_history<C: ShapeOpsCurve<S>, S: ShapeOpsSurface>(
+    solid: &Solid<Point3, C, S>,
+    surface: &S,
+    tol: f64,
+) -> Option<SplitWithHistory<C, S>> {
+    let ([mut back, mut front, caps], sources) = split_into_shells(solid, surface, tol)?;
+    back.extend(caps.face_iter().cloned());
+    front.extend(caps.face_iter().map(Face::inverse));
+    let back_shell = split_shell_to_shell(&back, |ic| approximated_curve(ic, tol))?;
+    let front_shell = split_shell_to_shell(&front, |ic| approximated_curve(ic, tol))?;
+    let mut history = History::new();
+    sources.record(&mut history, &back, &back_shell);
+    sources.record(&mut history, &front, &front_shell);
+    sources::add_deleted(&mut history, &[solid.boundaries().first()?]);
+    Some((
+        shell_to_solids(back_shell)?,
+        shell_to_solids(front_shell)?,
+        history,
+    ))
+}
+
+/// Returns the section wires of `solid` by `surface`.
+///
+/// The wires are the boundaries of the parts of `surface` inside `solid`, oriented along `surface`:
+/// outer wires run counterclockwise and inner wires clockwise seen from the front side of `surface`.
+/// The edges on lines or circles, e.g. the sections of planes, cylinders and spheres by a plane,
+/// are exact lines or circle arcs. The other edges are [`IntersectionCurve`]s whose leaders are B-spline curves.
+/// Returns an empty vector if `surface` does not meet `solid`.
+///
+/// # Remarks
+/// The restrictions on `surface` and `solid` are the same as [`split_solid`].
+///
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+///
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// let plane = Plane::new(
+///     Point3::new(0.0, 0.0, 0.5),
+///     Point3::new(1.0, 0.0, 0.5),
+///     Point3::new(0.0, 1.0, 0.5),
+/// );
+/// let wires = truck_shapeops::section(&cube, &Surface::Plane(plane), 0.05).unwrap();
+/// assert_eq!(wires.len(), 1);
+/// assert_eq!(wires[0].len(), 4);
+/// assert!(wires[0].is_closed());
+/// ```
+pub fn section<C, S>(
+    solid: &Solid<Point3, C, S>,
+    surface: &S,
+    tol: f64,
+) -> Option<Vec<Wire<Point3, C>>>
+where
+    C: ShapeOpsCurve<S> + From<Line<Point3>> + From<NurbsCurve<Vector4>>,
+    S: ShapeOpsSurface,
+{
+    section_with_history(solid, surface, tol).map(|(wires, _)| wires)
+}
+
+/// Returns the section wires of `solid` by `surface` with the history.
+///
+/// Each edge of the wires is generated by the faces of `solid` on which it lies, and each vertex of the wires
+/// is generated by the edges of `solid` on which it lies, or modified from the vertex of `solid` at the same point.
+/// # Examples
+/// ```
+/// use std::collections::HashSet;
+/// use truck_modeling::*;
+///
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+///
+/// let plane = Plane::new(
+///     Point3::new(0.0, 0.0, 0.5),
+///     Point3::new(1.0, 0.0, 0.5),
+///     Point3::new(0.0, 1.0, 0.5),
+/// );
+/// let (wires, history) =
+///     truck_shapeops::section_with_history(&cube, &Surface::Plane(plane), 0.05).unwrap();
+/// // each edge is generated by a side face.
+/// wires[0].edge_iter().for_each(|edge| {
+///     let faces = cube.face_iter().filter(|face| history.generated(*face).contains(&EntityID::from(edge)));
+///     assert_eq!(faces.count(), 1);
+/// });
+/// // each vertex is generated by a vertical edge.
+/// wires[0].vertex_iter().for_each(|v| {
+///     let edges: HashSet<_> = cube
+///         .edge_iter()
+///         .filter(|edge| history.generated(edge).contains(&EntityID::from(&v)))
+///         .map(|edge| edge.id())
+///         .collect();
+///     assert_eq!(edges.len(), 1);
+/// });
+/// ```
+pub fn section_with_history<C, S>(
+    solid: &Solid<Point3, C, S>,
+    surface: &S,
+    tol: f64,
+) -> Option<WiresWithHistory<C, S>>
+where
+    C: ShapeOpsCurve<S> + From<Line<Point3>> + From<NurbsCurve<Vector4>>,
+    S: ShapeOpsSurface,
+{
+    let ([_, _, caps], sources) = split_into_shells(solid, surface, tol)?;
+    let output = split_shell_to_shell(&caps, |ic| {
+        analytic_curve(ic, tol).or_else(|| approximated_curve(ic, tol))
+    })?;
+    let mut history = History::new();
+    sources.record(&mut history, &caps, &output);
+    let wires = output.face_iter().flat_map(Face::boundaries).collect();
+    Some((wires, history))
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,218 @@
+use super::*;
+use truck_modeling::{builder, Curve, Surface};
+use truck_topology::shell::ShellCondition;
+
+fn unit_cube() -> truck_modeling::Solid {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    builder::tsweep(&f, Vector3::unit_z())
+}
+
+fn volume(solid: &Solid<Point3, Curve, Surface>) -> f64 {
+    solid.triangulation(0.01).to_polygon().volume()
+}
+
+#[test]
+fn split_cube_by_plane() {
+    let cube = unit_cube();
+    let plane = Plane::new(
+        Point3::new(0.0, 0.0, 0.3),
+        Point3::new(1.0, 0.0, 0.3),
+        Point3::new(0.0, 1.0, 0.3),
+    );
+    let (back, front) = split_solid(&cube, &Surface::Plane(plane), 0.05).unwrap();
+    assert_eq!(back.len(), 1);
+    assert_eq!(front.len(), 1);
+    for solid in back.iter().chain(&front) {
+        let shell = &solid.boundaries()[0];
+        assert_eq!(shell.len(), 6);
+        assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+    }
+    assert_near!(volume(&back[0]), 0.3);
+    assert_near!(volume(&front[0]), 0.7);
+}
+
+#[test]
+fn split_cube_by_slanted_plane() {
+    let cube = unit_cube();
+    let plane = Plane::new(
+        Point3::new(0.0, 0.0, 0.25),
+        Point3::new(1.0, 0.0, 0.75),
+        Point3::new(0.0, 1.0, 0.25),
+    );
+    let (back, front) = split_solid(&cube, &Surface::Plane(plane), 0.05).unwrap();
+    assert_eq!(back.len(), 1);
+    assert_eq!(front.len(), 1);
+    for solid in back.iter().chain(&front) {
+        let shell = &solid.boundaries()[0];
+        assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+        assert!(f64::abs(volume(solid) - 0.5) < 0.01);
+    }
+}
+
+#[test]
+fn split_cube_by_missing_plane() {
+    let cube = unit_cube();
+    let plane = Plane::new(
+        Point3::new(0.0, 0.0, 2.0),
+        Point3::new(1.0, 0.0, 2.0),
+        Point3::new(0.0, 1.0, 2.0),
+    );
+    let (back, front) = split_solid(&cube, &Surface::Plane(plane), 0.05).unwrap();
+    assert_eq!(back.len(), 1);
+    assert!(front.is_empty());
+    assert_eq!(back[0].boundaries()[0].len(), 6);
+}
+
+#[test]
+fn section_of_cube() {
+    let cube = unit_cube();
+    let plane = Plane::new(
+        Point3::new(0.0, 0.0, 0.25),
+        Point3::new(1.0, 0.0, 0.75),
+        Point3::new(0.0, 1.0, 0.25),
+    );
+    let wires = section(&cube, &Surface::Plane(plane), 0.05).unwrap();
+    assert_eq!(wires.len(), 1);
+    let wire = &wires[0];
+    assert_eq!(wire.len(), 4);
+    assert!(wire.is_closed());
+    assert!(wire.is_simple());
+    wire.edge_iter().for_each(|edge| {
+        let curve = edge.curve();
+        assert!(matches!(curve, Curve::Line(_)));
+        let (t0, t1) = curve.range_tuple();
+        (0..=10).for_each(|i| {
+            let pt = curve.subs(t0 + (t1 - t0) * i as f64 / 10.0);
+            assert_near!(pt.z, 0.25 + 0.5 * pt.x);
+        });
+    });
+}
+
+#[test]
+fn section_of_punched_cube() {
+    let cube = unit_cube();
+    let v = builder::vertex(Point3::new(0.5, 0.25, -0.5));
+    let w = builder::rsweep(&v, Point3::new(0.5, 0.5, 0.0), Vector3::unit_z(), Rad(7.0));
+    let f = builder::try_attach_plane(&[w]).unwrap();
+    let mut cylinder = builder::tsweep(&f, Vector3::unit_z() * 2.0);
+    cylinder.not();
+    let punched_cube = and(&cube, &cylinder, 0.05).unwrap();
+
+    let plane = Plane::new(
+        Point3::new(0.0, 0.0, 0.5),
+        Point3::new(1.0, 0.0, 0.5),
+        Point3::new(0.0, 1.0, 0.5),
+    );
+    let wires = section(&punched_cube, &Surface::Plane(plane), 0.05).unwrap();
+    assert_eq!(wires.len(), 2);
+    assert!(wires.iter().all(|wire| wire.is_closed()));
+    wires
+        .iter()
+        .flat_map(|wire| wire.vertex_iter())
+        .for_each(|v| {
+            assert_near!(v.point().z, 0.5);
+        });
+    // The section of the cylinder consists of exact circle arcs.
+    wires
+        .iter()
+        .flat_map(|wire| wire.edge_iter())
+        .for_each(|edge| match edge.curve() {
+            Curve::Line(_) => {}
+            Curve::NurbsCurve(curve) => {
+                let (t0, t1) = curve.range_tuple();
+                (0..=10).for_each(|i| {
+                    let pt = curve.subs(t0 + (t1 - t0) * i as f64 / 10.0);
+                    assert_near!(pt.distance(Point3::new(0.5, 0.5, 0.5)), 0.25);
+                });
+            }
+            curve => panic!("unexpected curve: {curve:?}"),
+        });
+}
+
+#[test]
+fn analytic_section_curves() {
+    let plane = Surface::Plane(Plane::new(
+        Point3::new(0.0, 0.0, 0.5),
+        Point3::new(1.0, 0.0, 0.5),
+        Point3::new(0.0, 1.0, 0.5),
+    ));
+    let v = builder::vertex(Point3::new(0.0, 0.0, 1.0));
+    let wire: truck_modeling::Wire = builder::rsweep(
+        &v,
+        Point3::origin(),
+        Vector3::unit_x(),
+        Rad(std::f64::consts::PI),
+    );
+    let sphere: Surface = builder::cone(&wire, Vector3::unit_z(), Rad(7.0))[0].surface();
+    let radius = f64::sqrt(0.75);
+    let leader = PolylineCurve(
+        (0..=32)
+            .map(|i| 0.3 + 1.7 * i as f64 / 32.0)
+            .map(|t| Point3::new(radius * f64::cos(t), radius * f64::sin(t), 0.5))
+            .collect(),
+    );
+    let (front, back) = (leader.front(), leader.back());
+    let ic = IntersectionCurve::new(plane.clone(), sphere.clone(), leader);
+    let Some(Curve::NurbsCurve(arc)) = analytic_curve::<Curve, Surface>(&ic, 0.01) else {
+        panic!("the section of a sphere by a plane must be a circle.");
+    };
+    let (t0, t1) = arc.range_tuple();
+    assert_near!(arc.subs(t0), front);
+    assert_near!(arc.subs(t1), back);
+    (0..=10).for_each(|i| {
+        let pt = arc.subs(t0 + (t1 - t0) * i as f64 / 10.0);
+        assert_near!(pt.z, 0.5);
+        assert_near!(pt.to_vec().truncate().magnitude(), radius);
+        let angle = f64::atan2(pt.y, pt.x);
+        assert!((0.3 - TOLERANCE..=2.0 + TOLERANCE).contains(&angle));
+    });
+
+    // the whole circle
+    let leader = PolylineCurve(
+        (0..=32)
+            .map(|i| 0.3 + 2.0 * std::f64::consts::PI * i as f64 / 32.0)
+            .map(|t| Point3::new(radius * f64::cos(t), radius * f64::sin(t), 0.5))
+            .collect(),
+    );
+    let ic = IntersectionCurve::new(plane, sphere, leader);
+    let Some(Curve::NurbsCurve(circle)) = analytic_curve::<Curve, Surface>(&ic, 0.01) else {
+        panic!("the whole section of a sphere by a plane must be a circle.");
+    };
+    let (t0, t1) = circle.range_tuple();
+    assert_near!(circle.subs(t0), front);
+    assert_near!(circle.subs(t1), front);
+    (0..=10).for_each(|i| {
+        let pt = circle.subs(t0 + (t1 - t0) * i as f64 / 10.0);
+        assert_near!(pt.z, 0.5);
+        assert_near!(pt.to_vec().truncate().magnitude(), radius);
+    });
+    // the circle is oriented in the same direction as the leader
+    let pt = circle.subs(t0 + (t1 - t0) / 8.0);
+    let angle = f64::atan2(pt.y, pt.x);
+    assert!(0.3 < angle && angle < 0.3 + std::f64::consts::PI);
+
+    // the parabola on the surface `z = x^2` is neither a line nor a circle
+    let parabolic = Surface::BSplineSurface(BSplineSurface::new(
+        (KnotVec::bezier_knot(2), KnotVec::bezier_knot(1)),
+        vec![
+            vec![Point3::new(-1.0, -1.0, 1.0), Point3::new(-1.0, 1.0, 1.0)],
+            vec![Point3::new(0.0, -1.0, -1.0), Point3::new(0.0, 1.0, -1.0)],
+            vec![Point3::new(1.0, -1.0, 1.0), Point3::new(1.0, 1.0, 1.0)],
+        ],
+    ));
+    let diagonal = Surface::Plane(Plane::new(
+        Point3::origin(),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+    ));
+    let leader = PolylineCurve(
+        (0..=32)
+            .map(|i| -0.8 + 1.6 * i as f64 / 32.0)
+            .map(|t| Point3::new(t, t, t * t))
+            .collect(),
+    );
+    let ic = IntersectionCurve::new(diagonal, parabolic, leader);
+    assert!(analytic_curve::<Curve, Surface>(&ic, 0.001).is_none());
+}
@@ -0,0 +1,293 @@
+use crate::{face_domain::FaceDomain, parameter::nearest_surface_parameter, TRIALS};
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+use truck_geometry::prelude::*;
+use truck_meshalgo::prelude::*;
+use truck_topology::*;
+
+/// A problem of a solid found by [`check`].
+#[derive(Clone, Debug)]
+pub enum Problem<C, S> {
+    /// The `shell`-th boundary of the solid is not connected.
+    DisconnectedShell {
+        /// the index of the shell in the boundaries
+        shell: usize,
+    },
+    /// The edge is used by only one face.
+    OpenEdge(EdgeID<C>),
+    /// The edge is used by more than two faces.
+    NonManifoldEdge(EdgeID<C>),
+    /// The neighborhood of the vertex is not a disk.
+    NonManifoldVertex(VertexID<Point3>),
+    /// The edge is used twice with the same direction, i.e. the orientations of the adjacent faces are incompatible.
+    WrongOrientation(EdgeID<C>),
+    /// The vertex is not at the end point of the curve of the edge.
+    VertexNotOnCurve {
+        /// the vertex
+        vertex: VertexID<Point3>,
+        /// the edge
+        edge: EdgeID<C>,
+        /// the distance between the vertex and the end point of the curve
+        distance: f64,
+    },
+    /// The curve of the edge is not on the surface of the face.
+    EdgeNotOnSurface {
+        /// the edge
+        edge: EdgeID<C>,
+        /// the face
+        face: FaceID<S>,
+        /// the maximum distance between the sampled points on the curve and the surface
+        distance: f64,
+    },
+    /// The `wire`-th boundary of the face intersects itself in the parameter space.
+    SelfIntersectingWire {
+        /// the face
+        face: FaceID<S>,
+        /// the index of the wire in the boundaries
+        wire: usize,
+    },
+    /// The length of the edge is less than the tolerance.
+    ZeroLengthEdge(EdgeID<C>),
+    /// The `wire`-th boundary of the face is inverted, i.e. an outer loop is clockwise or
+    /// an inner loop is counterclockwise in the parameter space.
+    InvertedLoop {
+        /// the face
+        face: FaceID<S>,
+        /// the index of the wire in the boundaries
+        wire: usize,
+    },
+    /// The boundary of the face cannot be mapped to the parameter space of the surface,
+    /// e.g. the face has no boundary or a boundary is far from the surface.
+    InvalidFaceBoundary(FaceID<S>),
+}
+
+/// The result of [`check`].
+#[derive(Clone, Debug)]
+pub struct ValidationReport<C, S> {
+    /// the found problems
+    pub problems: Vec<Problem<C, S>>,
+}
+
+impl<C, S> ValidationReport<C, S> {
+    /// Returns `true` if no problems are found.
+    #[inline(always)]
+    pub fn is_valid(&self) -> bool { self.problems.is_empty() }
+}
+
+fn check_shell_topology<C, S>(shell: &Shell<Point3, C, S>, problems: &mut Vec<Problem<C, S>>) {
+    let mut usages = HashMap::<EdgeID<C>, Vec<bool>>::default();
+    shell
+        .face_iter()
+        .flat_map(Face::boundary_iters)
+        .flatten()
+        .for_each(|edge| {
+            usages
+                .entry(edge.id())
+                .or_default()
+                .push(edge.orientation())
+        });
+    let mut checked = HashSet::default();
+    shell.edge_iter().for_each(|edge| {
+        if !checked.insert(edge.id()) {
+            return;
+        }
+        match usages[&edge.id()].as_slice() {
+            [_] => problems.push(Problem::OpenEdge(edge.id())),
+            [a, b] if a == b => problems.push(Problem::WrongOrientation(edge.id())),
+            [_, _] => {}
+            _ => problems.push(Problem::NonManifoldEdge(edge.id())),
+        }
+    });
+    shell
+        .singular_vertices()
+        .into_iter()
+        .for_each(|v| problems.push(Problem::NonManifoldVertex(v.id())));
+}
+
+fn check_edge<C>(edge: &Edge<Point3, C>, tol: f64, problems: &mut Vec<Problem<C, impl Sized>>)
+where C: ParametricCurve3D + BoundedCurve + ParameterDivision1D<Point = Point3> {
+    let curve = edge.curve();
+    [
+        (edge.absolute_front(), curve.front()),
+        (edge.absolute_back(), curve.back()),
+    ]
+    .into_iter()
+    .for_each(|(vertex, point)| {
+        let distance = vertex.point().distance(point);
+        if distance > tol {
+            problems.push(Problem::VertexNotOnCurve {
+                vertex: vertex.id(),
+                edge: edge.id(),
+                distance,
+            });
+        }
+    });
+    let (_, points) = curve.parameter_division(curve.range_tuple(), tol);
+    let length: f64 = points.windows(2).map(|p| p[0].distance(p[1])).sum();
+    if length < tol {
+        problems.push(Problem::ZeroLengthEdge(edge.id()));
+    }
+}
+
+fn check_edge_on_surface<C, S>(
+    edge: &Edge<Point3, C>,
+    face: &Face<Point3, C, S>,
+    tol: f64,
+) -> Option<f64>
+where
+    C: ParametricCurve3D + BoundedCurve + ParameterDivision1D<Point = Point3>,
+    S: ParametricSurface3D
+        + SearchParameter<D2, Point = Point3>
+        + SearchNearestParameter<D2, Point = Point3>,
+{
+    let surface = face.surface();
+    let curve = edge.curve();
+    let (_, points) = curve.parameter_division(curve.range_tuple(), tol);
+    let mut hint = None;
+    points.into_iter().try_fold(0.0, |max, pt| {
+        let (u, v) = nearest_surface_parameter(&surface, pt, hint)
+            .or_else(|| surface.search_parameter(pt, None, TRIALS))?;
+        hint = Some((u, v));
+        Some(f64::max(max, surface.subs(u, v).distance(pt)))
+    })
+}
+
+/// Whether the segments `p0p1` and `q0q1` cross each other properly.
+fn crossing(p0: Point2, p1: Point2, q0: Point2, q1: Point2) -> bool {
+    let cross = |a: Point2, b: Point2, c: Point2| (b - a).perp_dot(c - a);
+    let (d0, d1) = (cross(p0, p1, q0), cross(p0, p1, q1));
+    let (d2, d3) = (cross(q0, q1, p0), cross(q0, q1, p1));
+    let eps = TOLERANCE2 * (p1 - p0).magnitude() * (q1 - q0).magnitude();
+    d0 * d1 < -eps && d2 * d3 < -eps
+}
+
+fn is_self_intersecting(poly: &PolylineCurve<Point2>) -> bool {
+    let segments: Vec<_> = poly.windows(2).map(|p| (p[0], p[1])).collect();
+    let n = segments.len();
+    (0..n).any(|i| {
+        (i + 2..n).filter(|j| !(i == 0 && *j == n - 1)).any(|j| {
+            let ((p0, p1), (q0, q1)) = (segments[i], segments[j]);
+            crossing(p0, p1, q0, q1)
+        })
+    })
+}
+
+fn check_face_domain<C, S>(face: &Face<Point3, C, S>, tol: f64, problems: &mut Vec<Problem<C, S>>)
+where
+    C: ParametricCurve3D + BoundedCurve + ParameterDivision1D<Point = Point3>,
+    S: ParametricSurface3D
+        + ParameterDivision2D
+        + SearchParameter<D2, Point = Point3>
+        + SearchNearestParameter<D2, Point = Point3>, {
+    let Some(domain) = FaceDomain::try_new(face, tol) else {
+        problems.push(Problem::InvalidFaceBoundary(face.id()));
+        return;
+    };
+    let boundaries = domain.boundaries();
+    boundaries.iter().enumerate().for_each(|(i, poly)| {
+        if is_self_intersecting(poly) {
+            problems.push(Problem::SelfIntersectingWire {
+                face: face.id(),
+                wire: i,
+            });
+        }
+        // The loops on the boundary of the period are not closed in the parameter space.
+        if !poly[0].near(&poly[poly.len() - 1]) {
+            return;
+        }
+        // The loops in the even number of the other loops are outer ones.
+        let depth = boundaries
+            .iter()
+            .enumerate()
+            .filter(|(j, other)| i != *j && other.include(poly[0]))
+            .count();
+        if (poly.area() > 0.0) != (depth % 2 == 0) {
+            problems.push(Problem::InvertedLoop {
+                face: face.id(),
+                wire: i,
+            });
+        }
+    });
+}
+
+/// Checks the validity of `solid` and lists all the problems with the IDs of the offending
+/// vertices, edges and faces.
+///
+/// # Details
+/// The following problems are checked.
+/// - Topology: disconnected shells, open edges, non-manifold edges and vertices,
+///   and edges whose adjacent faces have incompatible orientations.
+/// - Geometry: vertices not at the end points of the curves, curves not on the surfaces of the faces,
+///   and edges shorter than `tol`.
+/// - Boundaries of faces: wires intersecting themselves and inverted loops in the parameter space,
+///   and boundaries which cannot be mapped to the parameter space.
+///
+/// The geometric problems are reported if the distances are greater than `tol`.
+///
+/// # Examples
+/// ```
+/// use truck_modeling::*;
+/// use truck_shapeops::Problem;
+///
+/// let v = builder::vertex(Point3::origin());
+/// let e = builder::tsweep(&v, Vector3::unit_x());
+/// let f = builder::tsweep(&e, Vector3::unit_y());
+/// let cube: Solid = builder::tsweep(&f, Vector3::unit_z());
+/// assert!(truck_shapeops::check(&cube, 0.01).is_valid());
+///
+/// // moves a vertex
+/// let vertex = cube.vertex_iter().next().unwrap();
+/// vertex.set_point(Point3::new(0.0, 0.0, -0.1));
+/// let report = truck_shapeops::check(&cube, 0.01);
+/// assert_eq!(report.problems.len(), 3);
+/// assert!(report.problems.iter().all(|problem| matches!(
+///     problem,
+///     Problem::VertexNotOnCurve { vertex: id, .. } if *id == vertex.id(),
+/// )));
+/// ```
+pub fn check<C, S>(solid: &Solid<Point3, C, S>, tol: f64) -> ValidationReport<C, S>
+where
+    C: ParametricCurve3D + BoundedCurve + ParameterDivision1D<Point = Point3>,
+    S: ParametricSurface3D
+        + ParameterDivision2D
+        + SearchParameter<D2, Point = Point3>
+        + SearchNearestParameter<D2, Point = Point3>, {
+    nonpositive_tolerance!(tol);
+    let mut problems = Vec::new();
+    solid
+        .boundaries()
+        .iter()
+        .enumerate()
+        .for_each(|(i, shell)| {
+            if shell.connected_components().len() > 1 {
+                problems.push(Problem::DisconnectedShell { shell: i });
+            }
+            check_shell_topology(shell, &mut problems);
+        });
+    let mut checked = HashSet::default();
+    solid.edge_iter().for_each(|edge| {
+        if checked.insert(edge.id()) {
+            check_edge(&edge, tol, &mut problems);
+        }
+    });
+    solid.face_iter().for_each(|face| {
+        let mut checked = HashSet::default();
+        face.edge_iter().for_each(|edge| {
+            if !checked.insert(edge.id()) {
+                return;
+            }
+            let distance = check_edge_on_surface(&edge, face, tol).unwrap_or(f64::INFINITY);
+            if distance > tol {
+                problems.push(Problem::EdgeNotOnSurface {
+                    edge: edge.id(),
+                    face: face.id(),
+                    distance,
+                });
+            }
+        });
+        check_face_domain(face, tol, &mut problems);
+    });
+    ValidationReport { problems }
+}
+
+#[cfg(test)]
+mod tests;
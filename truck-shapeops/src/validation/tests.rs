@@ -0,0 +1,127 @@
+use super::*;
+use truck_modeling::{builder, Curve, Face, Shell, Solid, Surface};
+
+fn cube() -> Solid {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    builder::tsweep(&f, Vector3::unit_z())
+}
+
+fn count<C, S>(report: &ValidationReport<C, S>, pred: impl Fn(&Problem<C, S>) -> bool) -> usize {
+    report.problems.iter().filter(|p| pred(p)).count()
+}
+
+#[test]
+fn valid_solids() {
+    assert!(check(&cube(), 0.01).is_valid());
+
+    let v = builder::vertex(Point3::new(0.0, 0.0, 1.0));
+    let wire = builder::rsweep(
+        &v,
+        Point3::origin(),
+        Vector3::unit_y(),
+        Rad(std::f64::consts::PI),
+    );
+    let shell = builder::cone(&wire, Vector3::unit_z(), Rad(7.0));
+    let sphere = Solid::new(vec![shell]);
+    let report = check(&sphere, 0.01);
+    assert!(report.is_valid(), "{:?}", report.problems);
+}
+
+#[test]
+fn open_and_inverted_faces() {
+    let cube = cube();
+    let mut faces: Vec<Face> = cube.face_iter().cloned().collect();
+    // the solid with an open shell
+    let shell: Shell = faces[1..].iter().cloned().collect();
+    let solid = Solid::new_unchecked(vec![shell]);
+    let report = check(&solid, 0.01);
+    assert_eq!(report.problems.len(), 4);
+    assert_eq!(count(&report, |p| matches!(p, Problem::OpenEdge(_))), 4);
+
+    // the solid with an inverted face
+    faces[0].invert();
+    let solid = Solid::new_unchecked(vec![faces.into()]);
+    let report = check(&solid, 0.01);
+    assert_eq!(report.problems.len(), 4);
+    assert_eq!(
+        count(&report, |p| matches!(p, Problem::WrongOrientation(_))),
+        4
+    );
+}
+
+#[test]
+fn geometric_inconsistency() {
+    let cube = cube();
+    let face = cube.face_iter().next().unwrap();
+    let surface = match face.surface() {
+        Surface::Plane(plane) => {
+            let plane = plane.transformed(Matrix4::from_translation(Vector3::new(0.0, 0.0, 0.1)));
+            Surface::Plane(plane)
+        }
+        _ => unreachable!(),
+    };
+    face.set_surface(surface);
+    let report = check(&cube, 0.01);
+    assert_eq!(report.problems.len(), 4);
+    assert!(report.problems.iter().all(|p| matches!(
+        p,
+        Problem::EdgeNotOnSurface { face: id, distance, .. } if *id == face.id() && distance.near(&0.1),
+    )));
+}
+
+#[test]
+fn problems_of_face_boundaries() {
+    let p = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(1.0, 1.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+    ];
+    let v = builder::vertices(p);
+    let plane = Surface::Plane(Plane::new(p[0], p[1], p[3]));
+    let line = |i: usize, j: usize| builder::line(&v[i], &v[j]);
+
+    // a clockwise loop
+    let wire: Wire<Point3, Curve> = vec![line(0, 3), line(3, 2), line(2, 1), line(1, 0)].into();
+    let face = Face::new(vec![wire], plane.clone());
+    let solid = Solid::new_unchecked(vec![vec![face.clone()].into()]);
+    let report = check(&solid, 0.01);
+    let inverted = |p: &Problem<_, _>| matches!(p, Problem::InvertedLoop { face: id, wire: 0 } if *id == face.id());
+    assert_eq!(count(&report, inverted), 1);
+    assert_eq!(count(&report, |p| matches!(p, Problem::OpenEdge(_))), 4);
+
+    // a bow tie
+    let wire: Wire<Point3, Curve> = vec![line(0, 1), line(1, 3), line(3, 2), line(2, 0)].into();
+    let face = Face::new(vec![wire], plane.clone());
+    let solid = Solid::new_unchecked(vec![vec![face.clone()].into()]);
+    let report = check(&solid, 0.01);
+    let intersecting = |p: &Problem<_, _>| matches!(p, Problem::SelfIntersectingWire { face: id, wire: 0 } if *id == face.id());
+    assert_eq!(count(&report, intersecting), 1);
+
+    // a zero length edge
+    let v4 = builder::vertex(p[0]);
+    let wire: Wire<Point3, Curve> = vec![
+        line(0, 1),
+        line(1, 2),
+        line(2, 3),
+        builder::line(&v[3], &v4),
+        builder::line(&v4, &v[0]),
+    ]
+    .into();
+    let face = Face::new(vec![wire.clone()], plane.clone());
+    let solid = Solid::new_unchecked(vec![vec![face].into()]);
+    let report = check(&solid, 0.01);
+    let zero_length =
+        |p: &Problem<_, _>| matches!(p, Problem::ZeroLengthEdge(id) if *id == wire[4].id());
+    assert_eq!(count(&report, zero_length), 1);
+
+    // a face without boundary
+    let face = Face::new(vec![], plane);
+    let solid = Solid::new_unchecked(vec![vec![face.clone()].into()]);
+    let report = check(&solid, 0.01);
+    let invalid =
+        |p: &Problem<_, _>| matches!(p, Problem::InvalidFaceBoundary(id) if *id == face.id());
+    assert_eq!(count(&report, invalid), 1);
+}
@@ -149,6 +149,7 @@ fn output_vtk(polyshells: Vec<MeshedCShell>, path: &Path) {
                  vertices,
                  edges,
                  faces,
+                 vertex_attributes,
              }| {
                 let faces = faces
                     .into_iter()
@@ -157,6 +158,7 @@ fn output_vtk(polyshells: Vec<MeshedCShell>, path: &Path) {
                             boundaries: face.boundaries,
                             orientation: face.orientation,
                             surface: face.surface?,
+                            attributes: face.attributes,
                         })
                     })
                     .collect();
@@ -164,6 +166,7 @@ fn output_vtk(polyshells: Vec<MeshedCShell>, path: &Path) {
                     vertices,
                     edges,
                     faces,
+                    vertex_attributes,
                 }
                 .to_data_set() else {
                     unreachable!()
@@ -96,6 +96,7 @@ impl Table {
             Some(CompressedEdge {
                 vertices: (*vidx_map.get(&front_idx)?, *vidx_map.get(&back_idx)?),
                 curve,
+                attributes: Default::default(),
             })
         };
         let edges: Vec<CompressedEdge<Curve3D>> = shell
@@ -174,6 +175,7 @@ impl Table {
                     surface,
                     boundaries,
                     orientation,
+                    attributes: Default::default(),
                 })
             })
             .collect()
@@ -425,6 +427,7 @@ impl StepShell for ShellHolder {
             vertices,
             edges,
             faces: table.shell_faces(self, &eidx_map),
+            vertex_attributes: Vec::new(),
         })
     }
 }
@@ -1,8 +1,11 @@
 use super::{Result, *};
 
+/// The faces, the edges and the vertices with their geometries, written in a row.
 #[derive(Clone, Debug)]
-pub(super) struct StepShell<'a, P, C, S> {
-    entity: &'a CompressedShell<P, C, S>,
+pub(super) struct StepTopology<'a, P, C, S> {
+    vertices: &'a [P],
+    edges: &'a [CompressedEdge<C>],
+    faces: Vec<&'a CompressedFace<S>>,
     idx: usize,
     face_indices: Vec<usize>,
     ep_edges: usize,
@@ -10,20 +13,20 @@ pub(super) struct StepShell<'a, P, C, S> {
     surface_indices: Vec<usize>,
     curve_indices: Vec<usize>,
     ep_points: usize,
-    is_open: bool,
 }
 
-impl<'a, P, C, S> StepShell<'a, P, C, S>
+impl<'a, P, C, S> StepTopology<'a, P, C, S>
 where
-    P: Copy,
     C: StepLength,
     S: StepLength,
 {
-    fn new(shell: &'a CompressedShell<P, C, S>, idx: usize, is_open: bool) -> Self {
-        let faces = &shell.faces;
-        let edges = &shell.edges;
-        let vertices = &shell.vertices;
-        let mut cursor = idx + 1;
+    fn new(
+        vertices: &'a [P],
+        edges: &'a [CompressedEdge<C>],
+        faces: Vec<&'a CompressedFace<S>>,
+        idx: usize,
+    ) -> Self {
+        let mut cursor = idx;
         let face_indices = faces
             .iter()
             .map(|f| {
@@ -54,50 +57,40 @@ where
                 res
             })
             .collect::<Vec<_>>();
-        let ep_points = cursor;
-        StepShell {
-            entity: shell,
+        StepTopology {
+            vertices,
+            edges,
+            faces,
             idx,
             face_indices,
             ep_edges,
             ep_vertices,
             surface_indices,
             curve_indices,
-            ep_points,
-            is_open,
+            ep_points: cursor,
         }
     }
 }
 
-impl<P, C, S> Display for StepShell<'_, P, C, S>
+impl<P, C, S> Display for StepTopology<'_, P, C, S>
 where
     P: DisplayByStep + Copy,
     C: DisplayByStep + StepCurve,
     S: DisplayByStep + StepSurface,
 {
     fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
-        let StepShell {
-            entity,
-            idx,
+        let StepTopology {
+            vertices,
+            edges,
+            faces,
             face_indices,
             ep_edges,
             ep_vertices,
             surface_indices,
             curve_indices,
             ep_points,
-            is_open,
+            ..
         } = self;
-        let faces = &entity.faces;
-        let edges = &entity.edges;
-        let vertices = &entity.vertices;
-        let shell_kind = match is_open {
-            true => "OPEN_SHELL",
-            false => "CLOSED_SHELL",
-        };
-        formatter.write_fmt(format_args!(
-            "#{idx} = {shell_kind}('', {face_indices});\n",
-            face_indices = IndexSliceDisplay(self.face_indices.clone()),
-        ))?;
         faces.iter().enumerate().try_for_each(|(i, f)| {
             let idx = face_indices[i];
             let mut cursor = idx + 1;
@@ -184,12 +177,66 @@ where
     }
 }
 
-impl<P, C, S> StepLength for StepShell<'_, P, C, S> {
-    fn step_length(&self) -> usize {
-        1 + self.ep_points + self.entity.vertices.len() - self.face_indices[0]
+impl<P, C, S> StepLength for StepTopology<'_, P, C, S> {
+    fn step_length(&self) -> usize { self.ep_points + self.vertices.len() - self.idx }
+}
+
+#[derive(Clone, Debug)]
+pub(super) struct StepShell<'a, P, C, S> {
+    idx: usize,
+    topology: StepTopology<'a, P, C, S>,
+    is_open: bool,
+}
+
+impl<'a, P, C, S> StepShell<'a, P, C, S>
+where
+    C: StepLength,
+    S: StepLength,
+{
+    fn new(shell: &'a CompressedShell<P, C, S>, idx: usize, is_open: bool) -> Self {
+        let faces = shell.faces.iter().collect();
+        let topology = StepTopology::new(&shell.vertices, &shell.edges, faces, idx + 1);
+        StepShell {
+            idx,
+            topology,
+            is_open,
+        }
+    }
+}
+
+/// Returns the name of the shell entity.
+fn shell_kind(is_open: bool) -> &'static str {
+    match is_open {
+        true => "OPEN_SHELL",
+        false => "CLOSED_SHELL",
+    }
+}
+
+impl<P, C, S> Display for StepShell<'_, P, C, S>
+where
+    P: DisplayByStep + Copy,
+    C: DisplayByStep + StepCurve,
+    S: DisplayByStep + StepSurface,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> Result {
+        let StepShell {
+            idx,
+            topology,
+            is_open,
+        } = self;
+        formatter.write_fmt(format_args!(
+            "#{idx} = {shell_kind}('', {face_indices});\n",
+            shell_kind = shell_kind(*is_open),
+            face_indices = IndexSliceDisplay(topology.face_indices.iter().copied()),
+        ))?;
+        Display::fmt(topology, formatter)
     }
 }
 
+impl<P, C, S> StepLength for StepShell<'_, P, C, S> {
+    fn step_length(&self) -> usize { 1 + self.topology.step_length() }
+}
+
 #[derive(Clone, Debug)]
 pub(super) struct StepSolid<'a, P, C, S> {
     idx: usize,
@@ -239,19 +286,17 @@ where
                 Display::fmt(step_shell, f)
             }
             _ => {
-                let first_shell_idx = boundaries[0].face_indices[0] - 1;
+                let first_shell_idx = boundaries[0].idx;
                 f.write_fmt(format_args!(
                     "#{idx} = BREP_WITH_VOIDS('', #{first_shell_idx}, {other_shells});\n",
                     other_shells = IndexSliceDisplay(
-                        boundaries[1..]
-                            .iter()
-                            .map(|step_shell| step_shell.face_indices[0] - 2)
+                        boundaries[1..].iter().map(|step_shell| step_shell.idx - 1)
                     ),
                 ))?;
                 Display::fmt(&boundaries[0], f)?;
                 boundaries[1..].iter().try_for_each(|step_shell| {
-                    let oriented_shell_idx = step_shell.face_indices[0] - 2;
-                    let shell_idx = step_shell.face_indices[0] - 1;
+                    let oriented_shell_idx = step_shell.idx - 1;
+                    let shell_idx = step_shell.idx;
                     f.write_fmt(format_args!(
                     "#{oriented_shell_idx} = ORIENTED_CLOSED_SHELL('', *, #{shell_idx}, .T.);\n",
                 ))?;
@@ -273,12 +318,191 @@ impl<P, C, S> StepLength for StepSolid<'_, P, C, S> {
     }
 }
 
+/// Compound whose members share the vertices and the edges.
+///
+/// The solids and the shells are written as the solid models and the shell based surface models, and the wires
+/// and the vertices are written as a geometric curve set. The faces, the edges and the vertices are written once
+/// after them, and the trimmed curves of the curve set refer to the geometries of the shared edges.
+#[derive(Clone, Debug)]
+pub(super) struct StepCompound<'a, P, C, S> {
+    entity: &'a CompressedCompound<P, C, S>,
+    solid_indices: Vec<Vec<usize>>,
+    shell_indices: Vec<usize>,
+    curve_set_idx: Option<usize>,
+    wire_edges: Vec<usize>,
+    topology: StepTopology<'a, P, C, S>,
+    idx: usize,
+}
+
+impl<'a, P, C, S> StepCompound<'a, P, C, S>
+where
+    C: StepLength,
+    S: StepLength,
+{
+    fn new(compound: &'a CompressedCompound<P, C, S>, idx: usize) -> Self {
+        let mut cursor = idx;
+        // the indices of the solid and its closed shells
+        let solid_indices = compound
+            .solids
+            .iter()
+            .map(|shells| {
+                let solid_idx = cursor;
+                let mut indices = vec![solid_idx];
+                indices.extend((0..shells.len()).map(|i| solid_idx + 1 + 2 * i));
+                cursor += 2 * usize::max(shells.len(), 1);
+                indices
+            })
+            .collect::<Vec<_>>();
+        let shell_indices = compound
+            .shells
+            .iter()
+            .map(|_| {
+                cursor += 2;
+                cursor - 2
+            })
+            .collect::<Vec<_>>();
+        let mut wire_edges: Vec<usize> = compound.wires.iter().flatten().map(|e| e.index).collect();
+        wire_edges.sort_unstable();
+        wire_edges.dedup();
+        let curve_set_idx = match wire_edges.is_empty() && compound.vertex_indices.is_empty() {
+            true => None,
+            false => {
+                cursor += 1 + wire_edges.len();
+                Some(cursor - 1 - wire_edges.len())
+            }
+        };
+        let faces = compound.solids.iter().flatten().chain(&compound.shells);
+        let topology = StepTopology::new(
+            &compound.vertices,
+            &compound.edges,
+            faces.flatten().collect(),
+            cursor,
+        );
+        StepCompound {
+            entity: compound,
+            solid_indices,
+            shell_indices,
+            curve_set_idx,
+            wire_edges,
+            topology,
+            idx,
+        }
+    }
+}
+
+impl<P, C, S> StepCompound<'_, P, C, S> {
+    /// Returns the indices of the solid models and the shell based surface models.
+    fn model_indices(&self) -> impl Iterator<Item = usize> + '_ {
+        let solids = self.solid_indices.iter().map(|indices| indices[0]);
+        solids.chain(self.shell_indices.iter().copied())
+    }
+}
+
+impl<P, C, S> Display for StepCompound<'_, P, C, S>
+where
+    P: DisplayByStep + Copy,
+    C: DisplayByStep + StepCurve,
+    S: DisplayByStep + StepSurface,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        let StepCompound {
+            entity,
+            solid_indices,
+            shell_indices,
+            curve_set_idx,
+            wire_edges,
+            topology,
+            ..
+        } = self;
+        // the faces of the shells are written in a row.
+        let mut first_face = 0;
+        let mut write_shell = |f: &mut Formatter<'_>, idx: usize, len: usize, is_open: bool| {
+            let face_indices = &topology.face_indices[first_face..first_face + len];
+            first_face += len;
+            f.write_fmt(format_args!(
+                "#{idx} = {shell_kind}('', {face_indices});\n",
+                shell_kind = shell_kind(is_open),
+                face_indices = IndexSliceDisplay(face_indices.iter().copied()),
+            ))
+        };
+        entity
+            .solids
+            .iter()
+            .zip(solid_indices)
+            .try_for_each(|(shells, indices)| {
+                if shells.is_empty() {
+                    f.pad("empty solid!")?;
+                    return Err(std::fmt::Error);
+                }
+                let (idx, shell_idx) = (indices[0], indices[1]);
+                match shells.len() {
+                    1 => f.write_fmt(format_args!(
+                        "#{idx} = MANIFOLD_SOLID_BREP('', #{shell_idx});\n"
+                    ))?,
+                    _ => f.write_fmt(format_args!(
+                        "#{idx} = BREP_WITH_VOIDS('', #{shell_idx}, {other_shells});\n",
+                        other_shells = IndexSliceDisplay(indices[2..].iter().map(|i| i - 1)),
+                    ))?,
+                }
+                write_shell(f, shell_idx, shells[0].len(), false)?;
+                shells[1..]
+                    .iter()
+                    .zip(&indices[2..])
+                    .try_for_each(|(shell, shell_idx)| {
+                        let oriented_shell_idx = shell_idx - 1;
+                        f.write_fmt(format_args!(
+                            "#{oriented_shell_idx} = ORIENTED_CLOSED_SHELL('', *, #{shell_idx}, .T.);\n",
+                        ))?;
+                        write_shell(f, *shell_idx, shell.len(), false)
+                    })
+            })?;
+        entity
+            .shells
+            .iter()
+            .zip(shell_indices)
+            .try_for_each(|(shell, idx)| {
+                f.write_fmt(format_args!(
+                    "#{idx} = SHELL_BASED_SURFACE_MODEL('', (#{shell_idx}));\n",
+                    shell_idx = idx + 1,
+                ))?;
+                write_shell(f, idx + 1, shell.len(), true)
+            })?;
+        if let Some(idx) = curve_set_idx {
+            let ep_points = topology.ep_points;
+            let elements = (idx + 1..idx + 1 + wire_edges.len())
+                .chain(entity.vertex_indices.iter().map(|i| ep_points + i));
+            f.write_fmt(format_args!(
+                "#{idx} = GEOMETRIC_CURVE_SET('', {elements});\n",
+                elements = IndexSliceDisplay(elements),
+            ))?;
+            wire_edges.iter().enumerate().try_for_each(|(i, index)| {
+                let e = &entity.edges[*index];
+                f.write_fmt(format_args!(
+                    "#{idx} = TRIMMED_CURVE('', #{basis_curve}, (#{trim_1}), (#{trim_2}), {same_sense}, .CARTESIAN.);\n",
+                    idx = idx + 1 + i,
+                    basis_curve = topology.curve_indices[*index],
+                    trim_1 = ep_points + e.vertices.0,
+                    trim_2 = ep_points + e.vertices.1,
+                    same_sense = BooleanDisplay(e.curve.same_sense()),
+                ))
+            })?;
+        }
+        Display::fmt(topology, f)
+    }
+}
+
+impl<P, C, S> StepLength for StepCompound<'_, P, C, S> {
+    fn step_length(&self) -> usize { self.topology.step_length() + self.topology.idx - self.idx }
+}
+
 #[derive(Clone, Debug)]
 pub(super) enum PreStepModel<'a, P, C, S> {
     /// shell based surface model
     Shell(StepShell<'a, P, C, S>),
     /// solid model
     Solid(StepSolid<'a, P, C, S>),
+    /// compound
+    Compound(StepCompound<'a, P, C, S>),
 }
 
 impl<'a, P, C, S> From<&'a CompressedShell<P, C, S>> for PreStepModel<'a, P, C, S>
@@ -318,6 +542,26 @@ where
                 Display::fmt(&x, f)
             }
             Self::Solid(x) => Display::fmt(x, f),
+            Self::Compound(x) => Display::fmt(x, f),
+        }
+    }
+}
+
+impl<P, C, S> PreStepModel<'_, P, C, S> {
+    /// Returns the indices of the items of the advanced brep shape representation.
+    fn brep_indices(&self) -> Vec<usize> {
+        match self {
+            Self::Shell(x) => vec![x.idx - 1],
+            Self::Solid(x) => vec![x.idx],
+            Self::Compound(x) => x.model_indices().collect(),
+        }
+    }
+
+    /// Returns the index of the geometric curve set.
+    fn curve_set_index(&self) -> Option<usize> {
+        match self {
+            Self::Compound(x) => x.curve_set_idx,
+            _ => None,
         }
     }
 }
@@ -327,6 +571,7 @@ impl<P, C, S> StepLength for PreStepModel<'_, P, C, S> {
         match self {
             Self::Shell(x) => 1 + x.step_length(),
             Self::Solid(x) => x.step_length(),
+            Self::Compound(x) => x.step_length(),
         }
     }
 }
@@ -409,6 +654,29 @@ where
         self.next_idx += model.step_length();
         self.models.push(model)
     }
+    /// push a compound to step models
+    ///
+    /// The solids and the shells are pushed as the solid models and the shell based surface models, sharing the
+    /// vertices and the edges. The wires and the vertices are pushed as a geometric curve set, which is an item
+    /// of the geometrically bounded wireframe shape representation related to the brep shape representation.
+    pub fn push_compound(&mut self, compound: &'a CompressedCompound<P, C, S>) {
+        let model = PreStepModel::Compound(StepCompound::new(compound, self.next_idx));
+        self.next_idx += model.step_length();
+        self.models.push(model)
+    }
+}
+
+impl<'a, P, C, S> From<&'a CompressedCompound<P, C, S>> for StepModels<'a, P, C, S>
+where
+    P: Copy,
+    C: StepLength,
+    S: StepLength,
+{
+    fn from(compound: &'a CompressedCompound<P, C, S>) -> Self {
+        let mut models = Self::default();
+        models.push_compound(compound);
+        models
+    }
 }
 
 impl<'a, P, C, S> FromIterator<&'a CompressedShell<P, C, S>> for StepModels<'a, P, C, S>
@@ -468,13 +736,27 @@ where
 #7 = PRODUCT('','','', (#8));
 #8 = PRODUCT_CONTEXT('', #2, 'mechanical');
 #9 = PRODUCT_DEFINITION_CONTEXT('part definition', #2, 'design');\n")?;
-        let models_slice = IndexSliceDisplay(self.models.iter().map(|model| match model {
-            PreStepModel::Shell(x) => x.idx - 1,
-            PreStepModel::Solid(x) => x.idx,
-        }));
-        f.write_fmt(format_args!(
-            "#10 = ADVANCED_BREP_SHAPE_REPRESENTATION('', {models_slice}, #11);\n"
-        ))?;
+        let brep_indices: Vec<usize> = self
+            .models
+            .iter()
+            .flat_map(PreStepModel::brep_indices)
+            .collect();
+        let curve_set_indices: Vec<usize> = self
+            .models
+            .iter()
+            .filter_map(PreStepModel::curve_set_index)
+            .collect();
+        // The curve sets are not items of the brep shape representation.
+        let wireframe = IndexSliceDisplay(curve_set_indices.iter().copied());
+        match brep_indices.is_empty() && !curve_set_indices.is_empty() {
+            true => f.write_fmt(format_args!(
+                "#10 = GEOMETRICALLY_BOUNDED_WIREFRAME_SHAPE_REPRESENTATION('', {wireframe}, #11);\n"
+            ))?,
+            false => f.write_fmt(format_args!(
+                "#10 = ADVANCED_BREP_SHAPE_REPRESENTATION('', {models_slice}, #11);\n",
+                models_slice = IndexSliceDisplay(brep_indices.iter().copied()),
+            ))?,
+        }
         f.pad("#11 = (
     GEOMETRIC_REPRESENTATION_CONTEXT(3) 
     GLOBAL_UNCERTAINTY_ASSIGNED_CONTEXT((#15))
@@ -488,6 +770,15 @@ where
         )?;
         self.models
             .iter()
-            .try_for_each(|model| Display::fmt(model, f))
+            .try_for_each(|model| Display::fmt(model, f))?;
+        if !brep_indices.is_empty() && !curve_set_indices.is_empty() {
+            let idx = self.next_idx;
+            f.write_fmt(format_args!(
+                "#{idx} = GEOMETRICALLY_BOUNDED_WIREFRAME_SHAPE_REPRESENTATION('', {wireframe}, #11);
+#{relationship} = SHAPE_REPRESENTATION_RELATIONSHIP('', '', #10, #{idx});\n",
+                relationship = idx + 1,
+            ))?;
+        }
+        Ok(())
     }
 }
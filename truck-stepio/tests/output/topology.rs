@@ -56,3 +56,66 @@ fn parse_solids() {
         panic!("failed to parse step\n[Error Message]\n{e}[STEP file]\n{step_string}")
     });
 }
+
+#[test]
+fn parse_compound() {
+    let v = builder::vertex(Point3::origin());
+    let e = builder::tsweep(&v, Vector3::unit_x());
+    let f = builder::tsweep(&e, Vector3::unit_y());
+    let solid: Solid = builder::tsweep(&f, Vector3::unit_z());
+    let v = builder::vertex(Point3::new(2.0, 0.0, 0.0));
+    let wire: Wire = vec![
+        builder::line(&v, &builder::vertex(Point3::new(3.0, 0.0, 0.0))),
+        builder::circle_arc(
+            &v,
+            &builder::vertex(Point3::new(2.0, 1.0, 0.0)),
+            Point3::new(2.5, 0.5, 0.0),
+        ),
+    ]
+    .into();
+    let point = builder::vertex(Point3::new(0.0, 0.0, 5.0));
+    let compound = Compound::new(vec![solid], Vec::new(), vec![wire], vec![point]);
+    let compressed = compound.compress();
+    let step_string =
+        CompleteStepDisplay::new(StepModels::from(&compressed), Default::default()).to_string();
+    ruststep::parser::parse(&step_string).unwrap_or_else(|e| {
+        panic!("failed to parse step\n[Error Message]\n{e}[STEP file]\n{step_string}")
+    });
+    let data = step_string.split("DATA;").nth(1).unwrap();
+    let indices: Vec<usize> = data
+        .lines()
+        .filter_map(|line| line.strip_prefix('#')?.split(" = ").next()?.parse().ok())
+        .collect();
+    assert!(indices.iter().enumerate().all(|(i, idx)| i + 1 == *idx));
+    // the edges of the solid and the wire are written once.
+    let count = |name: &str| data.matches(name).count();
+    assert_eq!(count("= EDGE_CURVE("), compressed.edges.len());
+    assert_eq!(count("= VERTEX_POINT("), compressed.vertices.len());
+    // the curve set is an item of the wireframe representation related to the brep representation.
+    let entity = |name: &str| data.lines().find(|line| line.contains(name)).unwrap();
+    let curve_set = entity("GEOMETRIC_CURVE_SET").split(" = ").next().unwrap();
+    let brep = entity("ADVANCED_BREP_SHAPE_REPRESENTATION");
+    assert!(!brep.contains(&format!("{curve_set},")) && !brep.contains(&format!("{curve_set})")));
+    let wireframe = entity("GEOMETRICALLY_BOUNDED_WIREFRAME_SHAPE_REPRESENTATION");
+    assert!(wireframe.contains(&format!("({curve_set})")));
+    let wireframe = wireframe.split(" = ").next().unwrap();
+    let relationship = entity("SHAPE_REPRESENTATION_RELATIONSHIP");
+    assert!(relationship.ends_with(&format!("#10, {wireframe});")));
+}
+
+#[test]
+fn parse_wireframe_compound() {
+    let v = builder::vertices([(0.0, 0.0, 0.0), (1.0, 0.0, 0.0), (1.0, 1.0, 0.0)]);
+    let wire: Wire = vec![builder::line(&v[0], &v[1]), builder::line(&v[1], &v[2])].into();
+    let compound = Compound::new(Vec::new(), Vec::new(), vec![wire], Vec::new());
+    let compressed = compound.compress();
+    let step_string =
+        CompleteStepDisplay::new(StepModels::from(&compressed), Default::default()).to_string();
+    ruststep::parser::parse(&step_string).unwrap_or_else(|e| {
+        panic!("failed to parse step\n[Error Message]\n{e}[STEP file]\n{step_string}")
+    });
+    // without the brep models, the wireframe representation is the representation of the shape.
+    assert!(step_string.contains("#10 = GEOMETRICALLY_BOUNDED_WIREFRAME_SHAPE_REPRESENTATION"));
+    assert!(!step_string.contains("ADVANCED_BREP_SHAPE_REPRESENTATION"));
+    assert!(!step_string.contains("SHAPE_REPRESENTATION_RELATIONSHIP"));
+}
@@ -0,0 +1,176 @@
+//! User attributes attached to the vertices, the edges and the faces.
+//!
+//! The attributes are the named values of the closed type [`AttributeValue`], not a generic parameter of
+//! the entities such as `Vertex<P, A>`. The reasons are as follows.
+//!
+//! - The attributes are carried through the modeling operations, e.g. the sweeps, the boolean operations
+//!   and the healing, which are generic over the geometries only. A type parameter would have to be threaded
+//!   through all of them and all type aliases of `truck-modeling`, although most entities have no attributes.
+//! - The attributes of the shapes from different sources, e.g. STEP files and the applications, are mixed
+//!   in one shape, so each application would have to agree on one type anyway.
+//! - The compressed structures are serialized with the attributes, so the values have to be self-describing.
+//!
+//! The applications needing the typed data keep it by the keys of their own and convert it from and to
+//! [`AttributeValue`], e.g. by `From` and `TryFrom`.
+
+use crate::*;
+use parking_lot::MutexGuard;
+use serde::{Deserialize, Serialize};
+use std::{collections::BTreeMap, ops::Deref, sync::OnceLock};
+
+/// The value of a user attribute attached to a vertex, an edge or a face.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum AttributeValue {
+    /// boolean flag
+    Bool(bool),
+    /// integer, e.g. an external identifier
+    Integer(i64),
+    /// real number, e.g. a tolerance or a roughness
+    Real(f64),
+    /// text, e.g. a name or a material tag
+    Text(String),
+    /// RGBA color
+    Color([f64; 4]),
+    /// list of values
+    List(Vec<AttributeValue>),
+}
+
+/// The user attributes of a vertex, an edge or a face, which are named values.
+///
+/// The attributes are shared by the clones and the inverses of the entity, like its geometry.
+/// They are copied to the new entities by `mapped`, `compress` and `extract`.
+/// # Examples
+/// ```
+/// use truck_topology::{attribute::*, *};
+///
+/// let v = Vertex::news([0, 1]);
+/// let edge = Edge::new(&v[0], &v[1], 2);
+/// assert_eq!(edge.attribute("name"), None);
+/// edge.set_attribute("name", "fillet");
+///
+/// // shared by the inverse
+/// let inverse = edge.inverse();
+/// assert_eq!(inverse.attribute("name"), Some(AttributeValue::from("fillet")));
+///
+/// // copied to the mapped edge
+/// let mapped = edge.mapped(|i| *i + 10, |j| *j + 10);
+/// inverse.set_attribute("color", [1.0, 0.0, 0.0, 1.0]);
+/// assert_eq!(mapped.attributes().len(), 1);
+/// assert_eq!(edge.attributes().len(), 2);
+/// ```
+pub type Attributes = BTreeMap<String, AttributeValue>;
+
+macro_rules! impl_from_for_attribute_value {
+    ($($ty: ty => $variant: ident),*) => {$(
+        impl From<$ty> for AttributeValue {
+            #[inline(always)]
+            fn from(value: $ty) -> Self { Self::$variant(value.into()) }
+        }
+    )*};
+}
+
+impl_from_for_attribute_value!(
+    bool => Bool,
+    i32 => Integer,
+    i64 => Integer,
+    f64 => Real,
+    String => Text,
+    &str => Text,
+    [f64; 4] => Color,
+    Vec<AttributeValue> => List
+);
+
+/// The geometry of an entity and its user attributes, shared by the clones of the entity.
+///
+/// The attributes are initialized when they are set first, since most entities have no attributes.
+#[derive(Debug)]
+pub(super) struct Shared<T> {
+    geometry: Mutex<T>,
+    attributes: OnceLock<Mutex<Attributes>>,
+}
+
+impl<T> Shared<T> {
+    #[inline(always)]
+    pub(super) fn new(geometry: T) -> Self {
+        Self {
+            geometry: Mutex::new(geometry),
+            attributes: OnceLock::new(),
+        }
+    }
+
+    /// Creates the slot with the copy of the attributes of another entity.
+    #[inline(always)]
+    pub(super) fn with_attributes(geometry: T, attributes: Attributes) -> Self {
+        let shared = Self::new(geometry);
+        if !attributes.is_empty() {
+            shared.attributes.get_or_init(|| Mutex::new(attributes));
+        }
+        shared
+    }
+
+    #[inline(always)]
+    fn attributes(&self) -> Option<MutexGuard<'_, Attributes>> {
+        self.attributes.get().map(Mutex::lock)
+    }
+
+    #[inline(always)]
+    fn attributes_mut(&self) -> MutexGuard<'_, Attributes> {
+        self.attributes.get_or_init(Default::default).lock()
+    }
+}
+
+impl<T> Deref for Shared<T> {
+    type Target = Mutex<T>;
+    #[inline(always)]
+    fn deref(&self) -> &Mutex<T> { &self.geometry }
+}
+
+macro_rules! impl_attribute_methods {
+    ($entity: ident<$($ty: ident),*>, $field: ident, $name: literal) => {
+        impl<$($ty),*> $entity<$($ty),*> {
+            #[doc = concat!("Returns the user attributes of the ", $name, ".")]
+            #[inline(always)]
+            pub fn attributes(&self) -> Attributes {
+                self.$field.attributes().map(|a| a.clone()).unwrap_or_default()
+            }
+
+            #[doc = concat!("Returns the user attribute of the ", $name, " named `key`.")]
+            #[inline(always)]
+            pub fn attribute(&self, key: &str) -> Option<AttributeValue> {
+                self.$field.attributes()?.get(key).cloned()
+            }
+
+            #[doc = concat!("Sets the user attribute of the ", $name, " named `key`, and returns the old value.")]
+            ///
+            /// The attribute is shared by all clones of the entity.
+            #[inline(always)]
+            pub fn set_attribute(
+                &self,
+                key: impl Into<String>,
+                value: impl Into<AttributeValue>,
+            ) -> Option<AttributeValue> {
+                self.$field.attributes_mut().insert(key.into(), value.into())
+            }
+
+            #[doc = concat!("Removes the user attribute of the ", $name, " named `key`, and returns the value.")]
+            #[inline(always)]
+            pub fn remove_attribute(&self, key: &str) -> Option<AttributeValue> {
+                self.$field.attributes()?.remove(key)
+            }
+
+            #[doc = concat!("Replaces all user attributes of the ", $name, ".")]
+            #[inline(always)]
+            pub fn set_attributes(&self, attributes: Attributes) {
+                match self.$field.attributes() {
+                    Some(mut slot) => *slot = attributes,
+                    None if attributes.is_empty() => {}
+                    None => *self.$field.attributes_mut() = attributes,
+                }
+            }
+        }
+    };
+}
+
+impl_attribute_methods!(Vertex<P>, point, "vertex");
+impl_attribute_methods!(Edge<P, C>, curve, "edge");
+impl_attribute_methods!(Face<P, C, S>, surface, "face");
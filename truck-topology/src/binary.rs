@@ -0,0 +1,305 @@
+use crate::compress::*;
+use rustc_hash::FxHashMap as HashMap;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::io::{BufReader, BufWriter, Read, Write};
+use thiserror::Error;
+
+/// The magic header of the binary format.
+pub const MAGIC: [u8; 8] = *b"TRUCKBIN";
+
+/// The schema version written by this crate.
+///
+/// The version is incremented whenever the layout of the records changes, and [`read`] decodes
+/// the data by the layout of its version. The data of the other versions, including the newer
+/// ones, are rejected by [`Error::UnsupportedVersion`].
+pub const VERSION: u32 = 1;
+
+/// Errors caused by reading or writing the binary format.
+#[derive(Debug, Error)]
+pub enum Error {
+    /// The data does not start with [`MAGIC`].
+    #[error("This is not the binary format of truck.")]
+    InvalidMagic,
+    /// The schema version is not supported by this crate.
+    #[error("The schema version {0} is not supported. The supported version is {VERSION}.")]
+    UnsupportedVersion(u32),
+    /// An index of the geometry or the topology is out of range.
+    #[error("The index {0} is out of range.")]
+    OutOfRange(usize),
+    /// Errors caused by I/O.
+    #[error(transparent)]
+    FromIO(#[from] std::io::Error),
+    /// Errors caused by encoding or decoding geometries.
+    #[error(transparent)]
+    FromBincode(#[from] bincode::Error),
+}
+
+type Result<T> = std::result::Result<T, Error>;
+
+/// The reference to a geometry in the stream.
+///
+/// A geometry is encoded just after [`GeometryRef::New`] when it appears for the first time,
+/// and is referred by its number among the geometries of the same kind afterward.
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+enum GeometryRef {
+    New,
+    Stored(usize),
+}
+
+/// The geometries already written, which deduplicates the same geometries.
+///
+/// The geometries are identified by their encoded bytes, so the same bytes are never mistaken
+/// for the different geometries.
+#[derive(Debug, Default)]
+struct GeometryWriter {
+    indices: HashMap<Vec<u8>, usize>,
+    buffer: Vec<u8>,
+}
+
+impl GeometryWriter {
+    fn write<T: Serialize, W: Write>(&mut self, writer: &mut W, geometry: &T) -> Result<()> {
+        self.buffer.clear();
+        bincode::serialize_into(&mut self.buffer, geometry)?;
+        if let Some(idx) = self.indices.get(&self.buffer) {
+            bincode::serialize_into(writer, &GeometryRef::Stored(*idx))?;
+            return Ok(());
+        }
+        bincode::serialize_into(&mut *writer, &GeometryRef::New)?;
+        writer.write_all(&self.buffer)?;
+        let len = self.indices.len();
+        self.indices.insert(self.buffer.clone(), len);
+        Ok(())
+    }
+}
+
+/// The geometries already read, which are referred by the later [`GeometryRef::Stored`].
+#[derive(Debug)]
+struct GeometryReader<T>(Vec<T>);
+
+impl<T: Clone + DeserializeOwned> GeometryReader<T> {
+    fn read<R: Read>(&mut self, reader: &mut R) -> Result<T> {
+        match bincode::deserialize_from(&mut *reader)? {
+            GeometryRef::New => {
+                let geometry: T = bincode::deserialize_from(reader)?;
+                self.0.push(geometry.clone());
+                Ok(geometry)
+            }
+            GeometryRef::Stored(idx) => self.0.get(idx).cloned().ok_or(Error::OutOfRange(idx)),
+        }
+    }
+}
+
+fn write_seq<T, W: Write>(
+    writer: &mut W,
+    seq: &[T],
+    mut write: impl FnMut(&mut W, &T) -> Result<()>,
+) -> Result<()> {
+    bincode::serialize_into(&mut *writer, &(seq.len() as u64))?;
+    seq.iter().try_for_each(|x| write(writer, x))
+}
+
+fn read_seq<T, R: Read>(
+    reader: &mut R,
+    mut read: impl FnMut(&mut R) -> Result<T>,
+) -> Result<Vec<T>> {
+    let len: u64 = bincode::deserialize_from(&mut *reader)?;
+    (0..len).map(|_| read(reader)).collect()
+}
+
+/// Writes the compressed solid in the binary format to the output stream.
+///
+/// The format starts with [`MAGIC`] and the schema [`VERSION`], and then the shells follow.
+/// The records are written as they are produced, and the same points, curves and surfaces are
+/// written only once: they are referred by the number in the later occurrences.
+/// # Examples
+/// ```
+/// use truck_topology::*;
+/// let v = Vertex::news([0, 1, 2, 3]);
+/// let edge = [
+///     Edge::new(&v[0], &v[1], 100),
+///     Edge::new(&v[0], &v[2], 100),
+///     Edge::new(&v[0], &v[3], 100),
+///     Edge::new(&v[1], &v[2], 100),
+///     Edge::new(&v[1], &v[3], 100),
+///     Edge::new(&v[2], &v[3], 100),
+/// ];
+/// let mut shell: Shell<_, _, _> = [
+///     wire![&edge[0], &edge[3], &edge[1].inverse()],
+///     wire![&edge[1], &edge[5], &edge[2].inverse()],
+///     wire![&edge[2], &edge[4].inverse(), &edge[0].inverse()],
+///     wire![&edge[3], &edge[5], &edge[4].inverse()],
+/// ]
+/// .into_iter()
+/// .map(|wire| Face::new(vec![wire], 1000))
+/// .collect();
+/// shell[3].invert();
+/// let solid = Solid::new(vec![shell]).compress();
+///
+/// let mut bytes = Vec::new();
+/// binary::write(&solid, &mut bytes).unwrap();
+/// assert_eq!(&bytes[..8], &binary::MAGIC);
+///
+/// let read: compress::CompressedSolid<usize, usize, usize> = binary::read(bytes.as_slice()).unwrap();
+/// assert_eq!(read, solid);
+/// ```
+pub fn write<P, C, S, W>(solid: &CompressedSolid<P, C, S>, writer: W) -> Result<()>
+where
+    P: Serialize,
+    C: Serialize,
+    S: Serialize,
+    W: Write, {
+    let mut writer = BufWriter::new(writer);
+    writer.write_all(&MAGIC)?;
+    writer.write_all(&VERSION.to_le_bytes())?;
+    let (mut points, mut curves, mut surfaces) = (
+        GeometryWriter::default(),
+        GeometryWriter::default(),
+        GeometryWriter::default(),
+    );
+    write_seq(&mut writer, &solid.boundaries, |writer, shell| {
+        write_seq(writer, &shell.vertices, |writer, point| {
+            points.write(writer, point)
+        })?;
+        bincode::serialize_into(&mut *writer, &shell.vertex_attributes)?;
+        write_seq(writer, &shell.edges, |writer, edge| {
+            curves.write(writer, &edge.curve)?;
+            Ok(bincode::serialize_into(
+                writer,
+                &(edge.vertices, &edge.attributes),
+            )?)
+        })?;
+        write_seq(writer, &shell.faces, |writer, face| {
+            surfaces.write(writer, &face.surface)?;
+            Ok(bincode::serialize_into(
+                writer,
+                &(&face.boundaries, face.orientation, &face.attributes),
+            )?)
+        })
+    })?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Reads the compressed solid in the binary format from the input stream.
+///
+/// The shells are decoded record by record by the layout of the schema version in the header,
+/// and only the distinct geometries are kept for the later references. See [`write`] for the examples.
+pub fn read<P, C, S, R>(reader: R) -> Result<CompressedSolid<P, C, S>>
+where
+    P: Clone + DeserializeOwned,
+    C: Clone + DeserializeOwned,
+    S: Clone + DeserializeOwned,
+    R: Read, {
+    let mut reader = BufReader::new(reader);
+    let mut magic = [0u8; 8];
+    reader.read_exact(&mut magic)?;
+    if magic != MAGIC {
+        return Err(Error::InvalidMagic);
+    }
+    let mut version = [0u8; 4];
+    reader.read_exact(&mut version)?;
+    match u32::from_le_bytes(version) {
+        1 => read_v1(&mut reader),
+        version => Err(Error::UnsupportedVersion(version)),
+    }
+}
+
+/// Decodes the shells in the layout of the schema version 1.
+fn read_v1<P, C, S, R>(reader: &mut R) -> Result<CompressedSolid<P, C, S>>
+where
+    P: Clone + DeserializeOwned,
+    C: Clone + DeserializeOwned,
+    S: Clone + DeserializeOwned,
+    R: Read, {
+    let (mut points, mut curves, mut surfaces) = (
+        GeometryReader(Vec::new()),
+        GeometryReader(Vec::new()),
+        GeometryReader(Vec::new()),
+    );
+    let boundaries = read_seq(reader, |reader| {
+        let vertices = read_seq(reader, |reader| points.read(reader))?;
+        let vertex_attributes = bincode::deserialize_from(&mut *reader)?;
+        let edges = read_seq(reader, |reader| {
+            let curve = curves.read(reader)?;
+            let (vertices, attributes) = bincode::deserialize_from(reader)?;
+            Ok(CompressedEdge {
+                vertices,
+                curve,
+                attributes,
+            })
+        })?;
+        let faces = read_seq(reader, |reader| {
+            let surface = surfaces.read(reader)?;
+            let (boundaries, orientation, attributes) = bincode::deserialize_from(reader)?;
+            Ok(CompressedFace {
+                boundaries,
+                orientation,
+                surface,
+                attributes,
+            })
+        })?;
+        Ok(CompressedShell {
+            vertices,
+            edges,
+            faces,
+            vertex_attributes,
+        })
+    })?;
+    Ok(CompressedSolid { boundaries })
+}
+
+#[test]
+fn invalid_header() {
+    let solid = CompressedSolid::<usize, usize, usize> {
+        boundaries: Vec::new(),
+    };
+    let mut bytes = Vec::new();
+    write(&solid, &mut bytes).unwrap();
+    assert!(read::<usize, usize, usize, _>(bytes.as_slice()).is_ok());
+
+    let mut wrong_magic = bytes.clone();
+    wrong_magic[0] = b'X';
+    let res = read::<usize, usize, usize, _>(wrong_magic.as_slice());
+    assert!(matches!(res, Err(Error::InvalidMagic)));
+
+    let mut newer = bytes.clone();
+    newer[8..12].copy_from_slice(&(VERSION + 1).to_le_bytes());
+    let res = read::<usize, usize, usize, _>(newer.as_slice());
+    assert!(matches!(res, Err(Error::UnsupportedVersion(v)) if v == VERSION + 1));
+
+    let mut older = bytes.clone();
+    older[8..12].copy_from_slice(&0u32.to_le_bytes());
+    let res = read::<usize, usize, usize, _>(older.as_slice());
+    assert!(matches!(res, Err(Error::UnsupportedVersion(0))));
+}
+
+#[test]
+fn shared_geometry() {
+    let shell = |vertices: Vec<[f64; 3]>| CompressedShell::<[f64; 3], usize, usize> {
+        vertices,
+        edges: Vec::new(),
+        faces: Vec::new(),
+        vertex_attributes: Vec::new(),
+    };
+    let encoded_len = |vertices: Vec<[f64; 3]>| {
+        let solid = CompressedSolid {
+            boundaries: vec![shell(vertices)],
+        };
+        let mut bytes = Vec::new();
+        write(&solid, &mut bytes).unwrap();
+        let read: CompressedSolid<[f64; 3], usize, usize> = read(bytes.as_slice()).unwrap();
+        assert_eq!(read, solid);
+        bytes.len()
+    };
+    // the same points are referred by the number after the first one
+    let same = encoded_len(vec![[1.0, 2.0, 3.0]; 10]);
+    let distinct = encoded_len((0..10).map(|i| [i as f64, 2.0, 3.0]).collect());
+    assert!(same < distinct);
+
+    let mut dangling = Vec::new();
+    dangling.extend(MAGIC);
+    dangling.extend(VERSION.to_le_bytes());
+    bincode::serialize_into(&mut dangling, &(1u64, 1u64, GeometryRef::Stored(3))).unwrap();
+    let res = read::<[f64; 3], usize, usize, _>(dangling.as_slice());
+    assert!(matches!(res, Err(Error::OutOfRange(3))));
+}
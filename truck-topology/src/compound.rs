@@ -0,0 +1,238 @@
+use crate::*;
+use truck_base::entry_map::FxEntryMap as EntryMap;
+
+impl<P, C, S> Compound<P, C, S> {
+    /// Creates the compound of the members.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let v = Vertex::news([(); 4]);
+    /// let wire: Wire<(), ()> = vec![Edge::new(&v[0], &v[1], ()), Edge::new(&v[1], &v[2], ())].into();
+    /// let compound: Compound<(), (), ()> = Compound::new(Vec::new(), Vec::new(), vec![wire], vec![v[3].clone()]);
+    /// assert_eq!(compound.wires().len(), 1);
+    /// assert_eq!(compound.vertices().len(), 1);
+    /// // vertex iterator runs over all vertices of the members.
+    /// assert_eq!(compound.vertex_iter().count(), 4);
+    /// ```
+    #[inline(always)]
+    pub const fn new(
+        solids: Vec<Solid<P, C, S>>,
+        shells: Vec<Shell<P, C, S>>,
+        wires: Vec<Wire<P, C>>,
+        vertices: Vec<Vertex<P>>,
+    ) -> Self {
+        Self {
+            solids,
+            shells,
+            wires,
+            vertices,
+        }
+    }
+
+    /// Returns the reference of the solids.
+    #[inline(always)]
+    pub const fn solids(&self) -> &Vec<Solid<P, C, S>> { &self.solids }
+    /// Returns the mutable reference of the solids.
+    #[inline(always)]
+    pub fn solids_mut(&mut self) -> &mut Vec<Solid<P, C, S>> { &mut self.solids }
+    /// Returns the reference of the shells.
+    #[inline(always)]
+    pub const fn shells(&self) -> &Vec<Shell<P, C, S>> { &self.shells }
+    /// Returns the mutable reference of the shells.
+    #[inline(always)]
+    pub fn shells_mut(&mut self) -> &mut Vec<Shell<P, C, S>> { &mut self.shells }
+    /// Returns the reference of the wires.
+    #[inline(always)]
+    pub const fn wires(&self) -> &Vec<Wire<P, C>> { &self.wires }
+    /// Returns the mutable reference of the wires.
+    #[inline(always)]
+    pub fn wires_mut(&mut self) -> &mut Vec<Wire<P, C>> { &mut self.wires }
+    /// Returns the reference of the vertices contained directly, not as the members of the other members.
+    #[inline(always)]
+    pub const fn vertices(&self) -> &Vec<Vertex<P>> { &self.vertices }
+    /// Returns the mutable reference of the vertices contained directly.
+    #[inline(always)]
+    pub fn vertices_mut(&mut self) -> &mut Vec<Vertex<P>> { &mut self.vertices }
+
+    /// Returns whether the compound has no members.
+    #[inline(always)]
+    pub fn is_empty(&self) -> bool {
+        self.solids.is_empty()
+            && self.shells.is_empty()
+            && self.wires.is_empty()
+            && self.vertices.is_empty()
+    }
+
+    /// Returns an iterator over the faces of the solids and the shells.
+    #[inline(always)]
+    pub fn face_iter(&self) -> impl Iterator<Item = &Face<P, C, S>> {
+        let solid_faces = self.solids.iter().flat_map(Solid::face_iter);
+        solid_faces.chain(self.shells.iter().flatten())
+    }
+
+    /// Returns an iterator over the edges of the faces and the wires.
+    #[inline(always)]
+    pub fn edge_iter(&self) -> impl Iterator<Item = Edge<P, C>> + '_ {
+        let face_edges = self.face_iter().flat_map(Face::boundaries).flatten();
+        face_edges.chain(self.wires.iter().flat_map(Wire::edge_iter).cloned())
+    }
+
+    /// Returns an iterator over the vertices of the edges and the vertices contained directly.
+    #[inline(always)]
+    pub fn vertex_iter(&self) -> impl Iterator<Item = Vertex<P>> + '_ {
+        let face_vertices = self.face_iter().flat_map(Face::vertex_iter);
+        let wire_vertices = self.wires.iter().flat_map(Wire::vertex_iter);
+        face_vertices
+            .chain(wire_vertices)
+            .chain(self.vertices.iter().cloned())
+    }
+
+    /// Returns a new compound whose surfaces are mapped by `surface_mapping`,
+    /// curves are mapped by `curve_mapping` and points are mapped by `point_mapping`.
+    ///
+    /// The vertices and the edges shared by several members remain shared.
+    /// # Remarks
+    /// Accessing geometry elements directly in the closure will result in a deadlock.
+    /// So, this method does not appear to the document.
+    #[doc(hidden)]
+    pub fn try_mapped<Q, D, T>(
+        &self,
+        mut point_mapping: impl FnMut(&P) -> Option<Q>,
+        mut curve_mapping: impl FnMut(&C) -> Option<D>,
+        mut surface_mapping: impl FnMut(&S) -> Option<T>,
+    ) -> Option<Compound<Q, D, T>> {
+        let mut vertex_map = EntryMap::new(Vertex::id, move |v| v.try_mapped(&mut point_mapping));
+        let vertices = self
+            .vertices
+            .iter()
+            .map(|v| vertex_map.entry_or_insert(v).clone())
+            .collect::<Option<Vec<_>>>()?;
+        let mut edge_map = EntryMap::new(
+            Edge::id,
+            wire::edge_entry_map_try_closure(&mut vertex_map, &mut curve_mapping),
+        );
+        let solids = self
+            .solids
+            .iter()
+            .map(|solid| {
+                let boundaries = solid
+                    .boundaries()
+                    .iter()
+                    .map(|shell| shell.sub_try_mapped(&mut edge_map, &mut surface_mapping));
+                Some(Solid::debug_new(boundaries.collect::<Option<Vec<_>>>()?))
+            })
+            .collect::<Option<Vec<_>>>()?;
+        let shells = self
+            .shells
+            .iter()
+            .map(|shell| shell.sub_try_mapped(&mut edge_map, &mut surface_mapping))
+            .collect::<Option<Vec<_>>>()?;
+        let wires = self
+            .wires
+            .iter()
+            .map(|wire| wire.sub_try_mapped(&mut edge_map))
+            .collect::<Option<Vec<_>>>()?;
+        Some(Compound::new(solids, shells, wires, vertices))
+    }
+
+    /// Returns a new compound whose surfaces are mapped by `surface_mapping`,
+    /// curves are mapped by `curve_mapping` and points are mapped by `point_mapping`.
+    ///
+    /// The vertices and the edges shared by several members remain shared.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let v = Vertex::news([0, 1, 2]);
+    /// let edge = Edge::new(&v[0], &v[1], 10);
+    /// let compound = Compound::<usize, usize, usize>::new(
+    ///     Vec::new(),
+    ///     Vec::new(),
+    ///     vec![vec![edge.clone()].into(), vec![edge.inverse()].into()],
+    ///     vec![v[0].clone(), v[2].clone()],
+    /// );
+    /// let mapped = compound.mapped(|i| *i as f64, |j| *j as f64, |k| *k as f64);
+    /// let wires = mapped.wires();
+    /// assert_eq!(wires[0][0].id(), wires[1][0].id());
+    /// assert_eq!(wires[0][0].curve(), 10.0);
+    /// assert_eq!(&mapped.vertices()[0], wires[0].front_vertex().unwrap());
+    /// assert_eq!(mapped.vertices()[1].point(), 2.0);
+    /// ```
+    /// # Remarks
+    /// Accessing geometry elements directly in the closure will result in a deadlock.
+    /// So, this method does not appear to the document.
+    #[doc(hidden)]
+    pub fn mapped<Q, D, T>(
+        &self,
+        mut point_mapping: impl FnMut(&P) -> Q,
+        mut curve_mapping: impl FnMut(&C) -> D,
+        mut surface_mapping: impl FnMut(&S) -> T,
+    ) -> Compound<Q, D, T> {
+        let mut vertex_map = EntryMap::new(Vertex::id, move |v| v.mapped(&mut point_mapping));
+        let vertices: Vec<_> = self
+            .vertices
+            .iter()
+            .map(|v| vertex_map.entry_or_insert(v).clone())
+            .collect();
+        let mut edge_map = EntryMap::new(
+            Edge::id,
+            wire::edge_entry_map_closure(&mut vertex_map, &mut curve_mapping),
+        );
+        let solids = self
+            .solids
+            .iter()
+            .map(|solid| {
+                let boundaries = solid
+                    .boundaries()
+                    .iter()
+                    .map(|shell| shell.sub_mapped(&mut edge_map, &mut surface_mapping));
+                Solid::debug_new(boundaries.collect())
+            })
+            .collect();
+        let shells = self
+            .shells
+            .iter()
+            .map(|shell| shell.sub_mapped(&mut edge_map, &mut surface_mapping))
+            .collect();
+        let wires = self
+            .wires
+            .iter()
+            .map(|wire| wire.sub_mapped(&mut edge_map))
+            .collect();
+        Compound::new(solids, shells, wires, vertices)
+    }
+}
+
+impl<P, C, S> Default for Compound<P, C, S> {
+    #[inline(always)]
+    fn default() -> Self { Self::new(Vec::new(), Vec::new(), Vec::new(), Vec::new()) }
+}
+
+impl<P, C, S> From<Solid<P, C, S>> for Compound<P, C, S> {
+    #[inline(always)]
+    fn from(solid: Solid<P, C, S>) -> Self {
+        Self::new(vec![solid], Vec::new(), Vec::new(), Vec::new())
+    }
+}
+
+impl<P, C, S> From<Shell<P, C, S>> for Compound<P, C, S> {
+    #[inline(always)]
+    fn from(shell: Shell<P, C, S>) -> Self {
+        Self::new(Vec::new(), vec![shell], Vec::new(), Vec::new())
+    }
+}
+
+impl<P, C, S> From<Wire<P, C>> for Compound<P, C, S> {
+    #[inline(always)]
+    fn from(wire: Wire<P, C>) -> Self { Self::new(Vec::new(), Vec::new(), vec![wire], Vec::new()) }
+}
+
+impl<P, C, S> PartialEq for Compound<P, C, S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.solids == other.solids
+            && self.shells == other.shells
+            && self.wires == other.wires
+            && self.vertices == other.vertices
+    }
+}
+
+impl<P, C, S> Eq for Compound<P, C, S> {}
@@ -7,8 +7,13 @@
 //!
 //! They directly reflect the results of parsing data from json or STEP, and all member variables are public.
 //! Boundary connectivity and closure are checked when converting to proprietary data structures, `Vertex`, `Edge`, and so on.
+//!
+//! The user attributes are stored in the fields `attributes` and `vertex_attributes`. These fields are omitted
+//! in the serialized data if they are empty, and the data serialized without them is still deserialized.
+//! The constructors `new` leave them empty, while the structures constructed by struct literals have to fill
+//! them, e.g. by `Default::default()`.
 
-use crate::*;
+use crate::{attribute::Attributes, *};
 use rustc_hash::FxHashMap as HashMap;
 use serde::{Deserialize, Serialize};
 
@@ -19,14 +24,29 @@ pub struct CompressedEdge<C> {
     pub vertices: (usize, usize),
     /// curve geometry of the edge
     pub curve: C,
+    /// user attributes of the edge
+    #[serde(default, skip_serializing_if = "Attributes::is_empty")]
+    pub attributes: Attributes,
 }
 
 impl<C> CompressedEdge<C> {
+    /// Creates a compressed edge without the user attributes.
+    #[inline(always)]
+    pub fn new(vertices: (usize, usize), curve: C) -> Self {
+        Self {
+            vertices,
+            curve,
+            attributes: Attributes::new(),
+        }
+    }
+
     #[inline(always)]
     fn create_edge<P>(self, v: &[Vertex<P>]) -> Result<Edge<P, C>> {
         let front = &v[self.vertices.0];
         let back = &v[self.vertices.1];
-        Edge::try_new(front, back, self.curve)
+        let edge = Edge::try_new(front, back, self.curve)?;
+        edge.set_attributes(self.attributes);
+        Ok(edge)
     }
 }
 
@@ -52,9 +72,23 @@ pub struct CompressedFace<S> {
     pub orientation: bool,
     /// surface geometry of the face
     pub surface: S,
+    /// user attributes of the face
+    #[serde(default, skip_serializing_if = "Attributes::is_empty")]
+    pub attributes: Attributes,
 }
 
 impl<S> CompressedFace<S> {
+    /// Creates a compressed face without the user attributes.
+    #[inline(always)]
+    pub fn new(boundaries: Vec<Vec<CompressedEdgeIndex>>, orientation: bool, surface: S) -> Self {
+        Self {
+            boundaries,
+            orientation,
+            surface,
+            attributes: Attributes::new(),
+        }
+    }
+
     fn create_face<P, C>(self, edges: &[Edge<P, C>]) -> Result<Face<P, C, S>> {
         let wires: Vec<Wire<P, C>> = self
             .boundaries
@@ -71,6 +105,7 @@ impl<S> CompressedFace<S> {
             })
             .collect();
         let mut face = Face::try_new(wires, self.surface)?;
+        face.set_attributes(self.attributes);
         if !self.orientation {
             face.invert();
         }
@@ -87,6 +122,28 @@ pub struct CompressedShell<P, C, S> {
     pub edges: Vec<CompressedEdge<C>>,
     /// all geometries and boundaries of faces
     pub faces: Vec<CompressedFace<S>>,
+    /// user attributes of vertices, in the same order as `vertices`.
+    ///
+    /// May be shorter than `vertices`, and the vertices without the entries have no attributes.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub vertex_attributes: Vec<Attributes>,
+}
+
+impl<P, C, S> CompressedShell<P, C, S> {
+    /// Creates a compressed shell without the user attributes of the vertices.
+    #[inline(always)]
+    pub fn new(
+        vertices: Vec<P>,
+        edges: Vec<CompressedEdge<C>>,
+        faces: Vec<CompressedFace<S>>,
+    ) -> Self {
+        Self {
+            vertices,
+            edges,
+            faces,
+            vertex_attributes: Vec::new(),
+        }
+    }
 }
 
 /// Serialized compressed solid
@@ -96,8 +153,34 @@ pub struct CompressedSolid<P, C, S> {
     pub boundaries: Vec<CompressedShell<P, C, S>>,
 }
 
+/// Serialized compressed compound
+///
+/// All vertices and edges of the compound, including the ones of the solids and the shells, are stored in
+/// the shared tables `vertices` and `edges`. The solids and the shells have only the faces, whose boundaries
+/// refer to the shared edges, and so the vertices and the edges shared by the members are kept shared.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompressedCompound<P, C, S> {
+    /// all geometries of the vertices
+    pub vertices: Vec<P>,
+    /// all geometries and end vertices of the edges
+    pub edges: Vec<CompressedEdge<C>>,
+    /// solids in the compound, given by the faces of the boundary shells
+    pub solids: Vec<Vec<Vec<CompressedFace<S>>>>,
+    /// shells in the compound, given by the faces
+    pub shells: Vec<Vec<CompressedFace<S>>>,
+    /// wires in the compound
+    pub wires: Vec<Vec<CompressedEdgeIndex>>,
+    /// indices of the vertices contained directly
+    pub vertex_indices: Vec<usize>,
+    /// user attributes of vertices, in the same order as `vertices`.
+    ///
+    /// May be shorter than `vertices`, and the vertices without the entries have no attributes.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub vertex_attributes: Vec<Attributes>,
+}
+
 struct CompressDirector<P, C> {
-    vmap: HashMap<VertexID<P>, (usize, P)>,
+    vmap: HashMap<VertexID<P>, (usize, (P, Attributes))>,
     emap: HashMap<EdgeID<C>, (usize, CompressedEdge<C>)>,
 }
 
@@ -114,7 +197,7 @@ impl<P: Clone, C: Clone> CompressDirector<P, C> {
         let id = self.vmap.len();
         self.vmap
             .entry(vertex.id())
-            .or_insert_with(|| (id, vertex.point()))
+            .or_insert_with(|| (id, (vertex.point(), vertex.attributes())))
             .0
     }
 
@@ -130,6 +213,7 @@ impl<P: Clone, C: Clone> CompressDirector<P, C> {
                 let cedge = CompressedEdge {
                     vertices: (front_id, back_id),
                     curve,
+                    attributes: edge.attributes(),
                 };
                 self.emap.insert(edge.id(), (id, cedge));
                 (id, edge.orientation()).into()
@@ -152,6 +236,7 @@ impl<P: Clone, C: Clone> CompressDirector<P, C> {
                 .collect(),
             orientation: face.orientation(),
             surface: face.surface(),
+            attributes: face.attributes(),
         }
     }
 
@@ -163,8 +248,13 @@ impl<P: Clone, C: Clone> CompressDirector<P, C> {
     }
 
     #[inline(always)]
-    fn vertices_edges(self) -> (Vec<P>, Vec<CompressedEdge<C>>) {
-        (Self::map2vec(self.vmap), Self::map2vec(self.emap))
+    fn vertices_edges(self) -> (Vec<P>, Vec<Attributes>, Vec<CompressedEdge<C>>) {
+        let (vertices, mut vertex_attributes): (Vec<_>, Vec<_>) =
+            Self::map2vec(self.vmap).into_iter().unzip();
+        if vertex_attributes.iter().all(Attributes::is_empty) {
+            vertex_attributes.clear();
+        }
+        (vertices, vertex_attributes, Self::map2vec(self.emap))
     }
 }
 
@@ -174,11 +264,12 @@ impl<P: Clone, C: Clone, S: Clone> Shell<P, C, S> {
         let mut director = CompressDirector::new();
         let mut face_closure = |face: &Face<P, C, S>| director.create_cface(face);
         let faces = self.iter().map(&mut face_closure).collect();
-        let (vertices, edges) = director.vertices_edges();
+        let (vertices, vertex_attributes, edges) = director.vertices_edges();
         CompressedShell {
             vertices,
             edges,
             faces,
+            vertex_attributes,
         }
     }
 
@@ -188,8 +279,13 @@ impl<P: Clone, C: Clone, S: Clone> Shell<P, C, S> {
             vertices,
             edges,
             faces,
+            vertex_attributes,
         } = cshell;
         let vertices: Vec<_> = vertices.into_iter().map(Vertex::new).collect();
+        vertices
+            .iter()
+            .zip(vertex_attributes)
+            .for_each(|(vertex, attributes)| vertex.set_attributes(attributes));
         let edges = edges
             .into_iter()
             .map(move |edge| edge.create_edge(&vertices))
@@ -221,6 +317,135 @@ impl<P: Clone, C: Clone, S: Clone> Solid<P, C, S> {
     }
 }
 
+impl<P: Clone, C: Clone, S: Clone> Compound<P, C, S> {
+    /// Compresses the compound into the serialized compressed compound.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let v = Vertex::news([0, 1, 2, 3]);
+    /// let edge = [
+    ///     Edge::new(&v[0], &v[1], 4),
+    ///     Edge::new(&v[1], &v[2], 5),
+    ///     Edge::new(&v[2], &v[0], 6),
+    ///     Edge::new(&v[2], &v[3], 7),
+    /// ];
+    /// let face = Face::new(vec![vec![edge[0].clone(), edge[1].clone(), edge[2].clone()].into()], 8);
+    /// // the wire shares the vertex `v[2]` with the shell.
+    /// let wire: Wire<usize, usize> = vec![edge[3].clone()].into();
+    /// let compound = Compound::new(Vec::new(), vec![vec![face].into()], vec![wire], vec![v[3].clone()]);
+    ///
+    /// let compressed = compound.compress();
+    /// assert_eq!(compressed.vertices, vec![0, 1, 2, 3]);
+    /// assert_eq!(compressed.edges.len(), 4);
+    /// assert_eq!(compressed.vertex_indices, vec![3]);
+    ///
+    /// let extracted = Compound::extract(compressed).unwrap();
+    /// let wire = &extracted.wires()[0];
+    /// let shell = &extracted.shells()[0];
+    /// assert!(shell.vertex_iter().any(|v| &v == wire[0].front()));
+    /// assert_eq!(wire.back_vertex(), Some(&extracted.vertices()[0]));
+    /// ```
+    pub fn compress(&self) -> CompressedCompound<P, C, S> {
+        let mut director = CompressDirector::new();
+        let mut compress_shell = |shell: &Shell<P, C, S>| -> Vec<_> {
+            shell
+                .iter()
+                .map(|face| director.create_cface(face))
+                .collect()
+        };
+        let solids = self
+            .solids()
+            .iter()
+            .map(|solid| solid.boundaries().iter().map(&mut compress_shell).collect())
+            .collect();
+        let shells = self.shells().iter().map(compress_shell).collect();
+        let wires = self
+            .wires()
+            .iter()
+            .map(|wire| director.create_boundary(wire))
+            .collect();
+        let vertex_indices = self
+            .vertices()
+            .iter()
+            .map(|vertex| director.get_vid(vertex))
+            .collect();
+        let (vertices, vertex_attributes, edges) = director.vertices_edges();
+        CompressedCompound {
+            vertices,
+            edges,
+            solids,
+            shells,
+            wires,
+            vertex_indices,
+            vertex_attributes,
+        }
+    }
+
+    /// Extracts the serialized compressed compound into the compound.
+    pub fn extract(ccompound: CompressedCompound<P, C, S>) -> Result<Self> {
+        let CompressedCompound {
+            vertices,
+            edges,
+            solids,
+            shells,
+            wires,
+            vertex_indices,
+            vertex_attributes,
+        } = ccompound;
+        let vertices: Vec<_> = vertices.into_iter().map(Vertex::new).collect();
+        vertices
+            .iter()
+            .zip(vertex_attributes)
+            .for_each(|(vertex, attributes)| vertex.set_attributes(attributes));
+        let edges = edges
+            .into_iter()
+            .map(|edge| edge.create_edge(&vertices))
+            .collect::<Result<Vec<_>>>()?;
+        let extract_shell = |faces: Vec<CompressedFace<S>>| -> Result<Shell<P, C, S>> {
+            faces
+                .into_iter()
+                .map(|face| face.create_face(&edges))
+                .collect()
+        };
+        let solids = solids
+            .into_iter()
+            .map(|shells| {
+                let shells = shells
+                    .into_iter()
+                    .map(extract_shell)
+                    .collect::<Result<Vec<_>>>()?;
+                Solid::try_new(shells)
+            })
+            .collect::<Result<_>>()?;
+        let shells = shells
+            .into_iter()
+            .map(extract_shell)
+            .collect::<Result<_>>()?;
+        let wires = wires
+            .into_iter()
+            .map(|wire| {
+                wire.into_iter()
+                    .map(
+                        |CompressedEdgeIndex { index, orientation }| match orientation {
+                            true => edges[index].clone(),
+                            false => edges[index].inverse(),
+                        },
+                    )
+                    .collect()
+            })
+            .collect();
+        Ok(Compound::new(
+            solids,
+            shells,
+            wires,
+            vertex_indices
+                .into_iter()
+                .map(|i| vertices[i].clone())
+                .collect(),
+        ))
+    }
+}
+
 // -------------------------- test -------------------------- //
 
 #[test]
@@ -231,6 +456,85 @@ fn compress_extract() {
     assert!(same_topology(shell0, &shell1));
 }
 
+#[test]
+fn compressed_constructors() {
+    let edges = vec![
+        CompressedEdge::new((0, 1), ()),
+        CompressedEdge::new((1, 2), ()),
+        CompressedEdge::new((2, 0), ()),
+    ];
+    let wire = vec![(0, true).into(), (1, true).into(), (2, true).into()];
+    let face = CompressedFace::new(vec![wire], true, ());
+    let compressed = CompressedShell::new(vec![0, 1, 2], edges, vec![face]);
+    let shell = Shell::extract(compressed.clone()).unwrap();
+    assert_eq!(shell.len(), 1);
+    assert!(shell[0].attributes().is_empty());
+    assert_eq!(shell.compress(), compressed);
+}
+
+#[test]
+fn compress_extract_attributes() {
+    use attribute::AttributeValue;
+    let cube = solid::cube();
+    let shell0 = &cube.boundaries()[0];
+    let vertex = shell0.vertex_iter().nth(3).unwrap();
+    vertex.set_attribute("name", "corner");
+    shell0[1].absolute_boundaries()[0][2].set_attribute("fillet", 0.5);
+    shell0[4].set_attribute("color", [1.0, 0.0, 0.0, 1.0]);
+
+    let compressed = shell0.compress();
+    assert_eq!(
+        compressed.vertex_attributes.len(),
+        compressed.vertices.len()
+    );
+    let shell1 = Shell::extract(compressed).unwrap();
+    let attributes = |shell: &Shell<(), (), ()>| {
+        let vertices: Vec<_> = shell.vertex_iter().map(|v| v.attributes()).collect();
+        let edges: Vec<_> = shell.edge_iter().map(|e| e.attributes()).collect();
+        let faces: Vec<_> = shell.face_iter().map(|f| f.attributes()).collect();
+        (vertices, edges, faces)
+    };
+    assert_eq!(attributes(shell0), attributes(&shell1));
+    assert_eq!(
+        shell1[4].attribute("color"),
+        Some(AttributeValue::Color([1.0, 0.0, 0.0, 1.0]))
+    );
+
+    // no attributes are stored for the shell without attributes.
+    let plain = Shell::extract(shell1.compress()).unwrap();
+    plain
+        .vertex_iter()
+        .for_each(|v| v.set_attributes(Default::default()));
+    assert!(plain.compress().vertex_attributes.is_empty());
+}
+
+#[test]
+fn compress_extract_compound() {
+    let cube = solid::cube();
+    let shell = &cube.boundaries()[0];
+    let face = shell[0].clone();
+    let edge = face.boundaries()[0][0].clone();
+    let compound = Compound::new(
+        vec![cube.clone()],
+        vec![vec![face].into()],
+        vec![vec![edge.clone()].into()],
+        vec![edge.front().clone()],
+    );
+    let compressed = compound.compress();
+    // the vertices and the edges of the members are shared.
+    assert_eq!(compressed.vertices.len(), 8);
+    assert_eq!(compressed.edges.len(), 12);
+
+    let extracted = Compound::extract(compressed).unwrap();
+    let solid_shell = &extracted.solids()[0].boundaries()[0];
+    assert!(same_topology(shell, solid_shell));
+    let face = &extracted.shells()[0][0];
+    assert_eq!(face.boundaries(), solid_shell[0].boundaries());
+    let edge = &extracted.wires()[0][0];
+    assert!(solid_shell.edge_iter().any(|e| e.is_same(edge)));
+    assert_eq!(edge.front(), &extracted.vertices()[0]);
+}
+
 #[allow(dead_code)]
 fn vmap_subroutin<P, Q>(
     v0: &Vertex<P>,
@@ -380,3 +684,34 @@ where
         Shell::deserialize(deserializer).map(|mut shell| shell.pop().unwrap())
     }
 }
+
+impl<P, C, S> Serialize for Compound<P, C, S>
+where
+    P: Clone + Serialize,
+    C: Clone + Serialize,
+    S: Clone + Serialize,
+{
+    fn serialize<Serializer>(
+        &self,
+        serializer: Serializer,
+    ) -> std::result::Result<Serializer::Ok, Serializer::Error>
+    where
+        Serializer: serde::Serializer,
+    {
+        self.compress().serialize(serializer)
+    }
+}
+
+impl<'de, P, C, S> Deserialize<'de> for Compound<P, C, S>
+where
+    P: Clone + Deserialize<'de>,
+    C: Clone + Deserialize<'de>,
+    S: Clone + Deserialize<'de>,
+{
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where D: serde::Deserializer<'de> {
+        use serde::de::Error;
+        let compressed = CompressedCompound::<P, C, S>::deserialize(deserializer)?;
+        Compound::extract(compressed).map_err(D::Error::custom)
+    }
+}
@@ -42,7 +42,7 @@ impl<P, C> Edge<P, C> {
         Edge {
             vertices: (front.clone(), back.clone()),
             orientation: true,
-            curve: Arc::new(Mutex::new(curve)),
+            curve: Arc::new(attribute::Shared::new(curve)),
         }
     }
 
@@ -288,7 +288,7 @@ impl<P, C> Edge<P, C> {
     /// assert_eq!(edge0.id(), edge1.id());
     /// ```
     #[inline(always)]
-    pub fn id(&self) -> EdgeID<C> { ID::new(Arc::as_ptr(&self.curve)) }
+    pub fn id(&self) -> EdgeID<C> { ID::new(Arc::as_ptr(&self.curve).cast()) }
 
     /// Returns how many same edges.
     ///
@@ -344,6 +344,7 @@ impl<P, C> Edge<P, C> {
         let v1 = self.absolute_back().try_mapped(&mut point_mapping)?;
         let curve = curve_mapping(&*self.curve.lock())?;
         let mut edge = Edge::debug_new(&v0, &v1, curve);
+        edge.set_attributes(self.attributes());
         if !self.orientation() {
             edge.invert();
         }
@@ -381,6 +382,7 @@ impl<P, C> Edge<P, C> {
         let v1 = self.absolute_back().mapped(&mut point_mapping);
         let curve = curve_mapping(&*self.curve.lock());
         let mut edge = Edge::debug_new(&v0, &v1, curve);
+        edge.set_attributes(self.attributes());
         if edge.orientation() != self.orientation() {
             edge.invert();
         }
@@ -409,12 +411,18 @@ impl<P, C> Edge<P, C> {
         let edge0 = Edge {
             vertices: (self.absolute_front().clone(), vertex.clone()),
             orientation: self.orientation,
-            curve: Arc::new(Mutex::new(curve0)),
+            curve: Arc::new(attribute::Shared::with_attributes(
+                curve0,
+                self.attributes(),
+            )),
         };
         let edge1 = Edge {
             vertices: (vertex.clone(), self.absolute_back().clone()),
             orientation: self.orientation,
-            curve: Arc::new(Mutex::new(curve1)),
+            curve: Arc::new(attribute::Shared::with_attributes(
+                curve1,
+                self.attributes(),
+            )),
         };
         match self.orientation {
             true => (edge0, edge1),
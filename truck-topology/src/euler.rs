@@ -0,0 +1,441 @@
+use crate::*;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+/// The entities created by an Euler operator.
+///
+/// The entities replacing the edited ones are also reported, since they have the new ids.
+#[derive(Clone, Debug)]
+pub struct EulerRecord<P, C, S> {
+    /// the created vertices
+    pub vertices: Vec<Vertex<P>>,
+    /// the created edges
+    pub edges: Vec<Edge<P, C>>,
+    /// the created faces
+    pub faces: Vec<Face<P, C, S>>,
+}
+
+impl<P, C, S> Default for EulerRecord<P, C, S> {
+    #[inline(always)]
+    fn default() -> Self {
+        Self {
+            vertices: Vec::new(),
+            edges: Vec::new(),
+            faces: Vec::new(),
+        }
+    }
+}
+
+impl<P, C, S> Shell<P, C, S> {
+    /// Returns the Euler-Poincaré characteristic `V - E + F - R`, where `R` is the number of the rings,
+    /// i.e. the boundaries of the faces other than the first ones.
+    ///
+    /// For a closed shell with `G` holes, the characteristic is `2 - 2G`.
+    /// All Euler operators in this module keep the characteristic.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let v = Vertex::news([(); 4]);
+    /// let edge = [
+    ///     Edge::new(&v[0], &v[1], ()),
+    ///     Edge::new(&v[0], &v[2], ()),
+    ///     Edge::new(&v[0], &v[3], ()),
+    ///     Edge::new(&v[1], &v[2], ()),
+    ///     Edge::new(&v[1], &v[3], ()),
+    ///     Edge::new(&v[2], &v[3], ()),
+    /// ];
+    /// let shell: Shell<_, _, _> = [
+    ///     wire![&edge[0], &edge[3], &edge[1].inverse()],
+    ///     wire![&edge[1], &edge[5], &edge[2].inverse()],
+    ///     wire![&edge[2], &edge[4].inverse(), &edge[0].inverse()],
+    ///     wire![&edge[3], &edge[5], &edge[4].inverse()],
+    /// ]
+    /// .into_iter()
+    /// .map(|wire| Face::new(vec![wire], ()))
+    /// .collect();
+    /// assert_eq!(shell.euler_characteristic(), 2);
+    /// ```
+    pub fn euler_characteristic(&self) -> isize {
+        let vertices = self.vertex_iter().map(|v| v.id()).collect::<HashSet<_>>();
+        let edges = self.edge_iter().map(|e| e.id()).collect::<HashSet<_>>();
+        let rings: usize = self.iter().map(|face| face.boundaries.len() - 1).sum();
+        vertices.len() as isize - edges.len() as isize + self.len() as isize - rings as isize
+    }
+
+    /// Make edge-vertex: cuts the edge with `edge_id` into two edges at `vertex`.
+    ///
+    /// Since a boundary of a face must be closed, the new edge is not a dangling one
+    /// but the half of the cut edge.
+    ///
+    /// # Returns
+    /// Returns `vertex` and the two edges created by cutting.
+    ///
+    /// # Failures
+    /// Returns `None` and does not edit `self` if the conditions of [`Shell::cut_edge`] are not satisfied.
+    pub fn make_edge_vertex(
+        &mut self,
+        edge_id: EdgeID<C>,
+        vertex: &Vertex<P>,
+    ) -> Option<EulerRecord<P, C, S>>
+    where
+        P: Clone,
+        C: Cut<Point = P> + SearchParameter<D1, Point = P>,
+    {
+        let (edge0, edge1) = self.cut_edge(edge_id, vertex)?;
+        Some(EulerRecord {
+            vertices: vec![vertex.clone()],
+            edges: vec![edge0, edge1],
+            faces: Vec::new(),
+        })
+    }
+
+    /// Kill edge-vertex: removes the vertex with `vertex_id` by concatenating the two edges on both sides.
+    /// This is the inverse of [`Shell::make_edge_vertex`].
+    ///
+    /// # Returns
+    /// Returns the concatenated edge.
+    ///
+    /// # Failures
+    /// Returns `None` and does not edit `self` if:
+    /// - the vertex is not adjacent to exactly two edges in the shell, or
+    /// - the conditions of [`Shell::remove_vertex_by_concat_edges`] are not satisfied.
+    pub fn kill_edge_vertex(&mut self, vertex_id: VertexID<P>) -> Option<EulerRecord<P, C, S>>
+    where
+        P: Debug,
+        C: Concat<C, Point = P, Output = C> + Invertible + ParameterTransform, {
+        let adjacent_edges = self
+            .edge_iter()
+            .filter(|e| e.front().id() == vertex_id || e.back().id() == vertex_id)
+            .map(|e| e.id())
+            .collect::<HashSet<_>>();
+        if adjacent_edges.len() != 2 {
+            return None;
+        }
+        let edge = self.remove_vertex_by_concat_edges(vertex_id)?;
+        Some(EulerRecord {
+            edges: vec![edge],
+            ..Default::default()
+        })
+    }
+
+    /// Make edge-face: cuts the face with `face_id` into two faces by `edge`.
+    ///
+    /// # Returns
+    /// Returns `edge` and the two faces replacing the cut face.
+    ///
+    /// # Failures
+    /// Returns `None` and does not edit `self` if:
+    /// - there is no face corresponding to `face_id` in the shell,
+    /// - `edge` is already included in the shell,
+    /// - `edge` is a closed edge, or
+    /// - the conditions of [`Face::cut_by_edge`] are not satisfied.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let v = Vertex::news([(); 4]);
+    /// let wire = wire![
+    ///     &Edge::new(&v[0], &v[1], ()),
+    ///     &Edge::new(&v[1], &v[2], ()),
+    ///     &Edge::new(&v[2], &v[3], ()),
+    ///     &Edge::new(&v[3], &v[0], ()),
+    /// ];
+    /// let mut shell: Shell<(), (), ()> = vec![Face::new(vec![wire], ())].into();
+    /// let face_id = shell[0].id();
+    /// let chi = shell.euler_characteristic();
+    ///
+    /// let record = shell.make_edge_face(face_id, &Edge::new(&v[0], &v[2], ())).unwrap();
+    /// assert_eq!(shell.len(), 2);
+    /// assert_eq!(record.faces.len(), 2);
+    /// assert_eq!(shell.euler_characteristic(), chi);
+    ///
+    /// // inverse operator
+    /// let record = shell.kill_edge_face(record.edges[0].id()).unwrap();
+    /// assert_eq!(shell.len(), 1);
+    /// assert_eq!(shell[0].id(), record.faces[0].id());
+    /// assert_eq!(shell[0].boundaries()[0].len(), 4);
+    /// ```
+    pub fn make_edge_face(
+        &mut self,
+        face_id: FaceID<S>,
+        edge: &Edge<P, C>,
+    ) -> Option<EulerRecord<P, C, S>>
+    where
+        S: Clone,
+    {
+        if edge.front() == edge.back() || self.edge_iter().any(|e| e.is_same(edge)) {
+            return None;
+        }
+        let i = self.iter().position(|face| face.id() == face_id)?;
+        let (face0, face1) = self[i].cut_by_edge(edge.clone())?;
+        self[i] = face0.clone();
+        self.push(face1.clone());
+        Some(EulerRecord {
+            edges: vec![edge.clone()],
+            faces: vec![face0, face1],
+            ..Default::default()
+        })
+    }
+
+    /// Kill edge-face: removes the edge with `edge_id` and merges the two faces on both sides.
+    /// This is the inverse of [`Shell::make_edge_face`].
+    ///
+    /// The merged face has the surface and the user attributes of the first of the two faces in the shell.
+    ///
+    /// # Returns
+    /// Returns the merged face.
+    ///
+    /// # Failures
+    /// Returns `None` and does not edit `self` if:
+    /// - the edge is not shared by exactly two different faces,
+    /// - the orientations of the two faces are not compatible on the edge, or
+    /// - the boundary of the merged face is not simple, e.g. the faces share another edge.
+    pub fn kill_edge_face(&mut self, edge_id: EdgeID<C>) -> Option<EulerRecord<P, C, S>>
+    where S: Clone {
+        self.merge_faces_by_killing_edges(&[edge_id], -1)
+    }
+
+    /// Make edge-kill ring: connects two boundaries of the face with `face_id` by `edges`,
+    /// and cuts the face into two faces.
+    ///
+    /// A boundary of a face cannot run the both sides of an edge, so the single bridge
+    /// of the classical MEKR cannot be represented. This operator adds two bridges at once,
+    /// i.e. it is MEKR followed by MEF.
+    ///
+    /// # Returns
+    /// Returns `edges` and the two faces replacing the cut face.
+    ///
+    /// # Failures
+    /// Returns `None` and does not edit `self` if:
+    /// - there is no face corresponding to `face_id` in the shell,
+    /// - the face does not have exactly two boundaries,
+    /// - `edges` are already included in the shell,
+    /// - each edge does not connect the two boundaries, or
+    /// - the ends of the edges on the same boundary are the same vertex.
+    /// # Examples
+    /// ```
+    /// use truck_topology::*;
+    /// let v = Vertex::news([(); 6]);
+    /// let outer = wire![
+    ///     &Edge::new(&v[0], &v[1], ()),
+    ///     &Edge::new(&v[1], &v[2], ()),
+    ///     &Edge::new(&v[2], &v[0], ()),
+    /// ];
+    /// let inner = wire![
+    ///     &Edge::new(&v[3], &v[5], ()),
+    ///     &Edge::new(&v[5], &v[4], ()),
+    ///     &Edge::new(&v[4], &v[3], ()),
+    /// ];
+    /// let mut shell: Shell<(), (), ()> = vec![Face::new(vec![outer, inner], ())].into();
+    /// let face_id = shell[0].id();
+    /// let chi = shell.euler_characteristic();
+    ///
+    /// let bridges = [Edge::new(&v[0], &v[3], ()), Edge::new(&v[1], &v[4], ())];
+    /// let record = shell.make_edge_kill_ring(face_id, [&bridges[0], &bridges[1]]).unwrap();
+    /// assert_eq!(shell.len(), 2);
+    /// assert!(shell.iter().all(|face| face.boundaries().len() == 1));
+    /// assert_eq!(shell.euler_characteristic(), chi);
+    ///
+    /// // inverse operator
+    /// let edge_ids = [record.edges[0].id(), record.edges[1].id()];
+    /// shell.kill_edge_make_ring(edge_ids).unwrap();
+    /// assert_eq!(shell.len(), 1);
+    /// assert_eq!(shell[0].boundaries().len(), 2);
+    /// assert_eq!(shell.euler_characteristic(), chi);
+    /// ```
+    pub fn make_edge_kill_ring(
+        &mut self,
+        face_id: FaceID<S>,
+        edges: [&Edge<P, C>; 2],
+    ) -> Option<EulerRecord<P, C, S>>
+    where
+        S: Clone,
+    {
+        if edges[0].is_same(edges[1])
+            || self
+                .edge_iter()
+                .any(|e| e.is_same(edges[0]) || e.is_same(edges[1]))
+        {
+            return None;
+        }
+        let i = self.iter().position(|face| face.id() == face_id)?;
+        let (face0, face1) = self[i].cut_by_bridges(edges)?;
+        self[i] = face0.clone();
+        self.push(face1.clone());
+        Some(EulerRecord {
+            edges: edges.into_iter().cloned().collect(),
+            faces: vec![face0, face1],
+            ..Default::default()
+        })
+    }
+
+    /// Kill edge-make ring: removes the two edges with `edge_ids` and merges the two faces on both sides,
+    /// which makes a new ring of the merged face. This is the inverse of [`Shell::make_edge_kill_ring`].
+    ///
+    /// The merged face has the surface and the user attributes of the first of the two faces in the shell.
+    ///
+    /// # Returns
+    /// Returns the merged face.
+    ///
+    /// # Failures
+    /// Returns `None` and does not edit `self` if:
+    /// - the edges are not shared by the same two different faces,
+    /// - the orientations of the two faces are not compatible on the edges, or
+    /// - the merged face does not have one more boundary than the sum of the ring of the two faces.
+    pub fn kill_edge_make_ring(
+        &mut self,
+        edge_ids: [EdgeID<C>; 2],
+    ) -> Option<EulerRecord<P, C, S>>
+    where
+        S: Clone,
+    {
+        if edge_ids[0] == edge_ids[1] {
+            return None;
+        }
+        self.merge_faces_by_killing_edges(&edge_ids, 0)
+    }
+
+    /// Removes the edges with `edge_ids` shared by two faces, and merges the faces.
+    /// The number of the boundaries of the merged face must be `loop_diff` plus the sum of those of two faces.
+    fn merge_faces_by_killing_edges(
+        &mut self,
+        edge_ids: &[EdgeID<C>],
+        loop_diff: isize,
+    ) -> Option<EulerRecord<P, C, S>>
+    where
+        S: Clone,
+    {
+        let find_faces = |edge_id: EdgeID<C>| -> Vec<usize> {
+            self.iter()
+                .enumerate()
+                .flat_map(|(i, face)| {
+                    face.edge_iter()
+                        .filter(|e| e.id() == edge_id)
+                        .map(move |_| i)
+                })
+                .collect()
+        };
+        let (i, j) = match find_faces(edge_ids[0])[..] {
+            [i, j] if i != j => (i, j),
+            _ => return None,
+        };
+        if edge_ids[1..]
+            .iter()
+            .any(|edge_id| find_faces(*edge_id) != [i, j])
+        {
+            return None;
+        }
+        let (face0, face1) = (&self[i], &self[j]);
+        let orientation_of = |face: &Face<P, C, S>, edge_id: EdgeID<C>| {
+            let edge = face.edge_iter().find(|e| e.id() == edge_id).unwrap();
+            edge.orientation()
+        };
+        if edge_ids
+            .iter()
+            .any(|edge_id| orientation_of(face0, *edge_id) == orientation_of(face1, *edge_id))
+        {
+            return None;
+        }
+
+        let mut vemap = HashMap::<VertexID<P>, Edge<P, C>>::default();
+        face0
+            .edge_iter()
+            .chain(face1.edge_iter())
+            .try_for_each(|edge| match edge_ids.contains(&edge.id()) {
+                true => Some(()),
+                false => match vemap.insert(edge.front().id(), edge) {
+                    Some(_) => None,
+                    None => Some(()),
+                },
+            })?;
+        let mut boundaries = Vec::new();
+        while let Some(vertex_id) = vemap.keys().next().copied() {
+            let mut edge = vemap.remove(&vertex_id).unwrap();
+            let mut wire = Wire::new();
+            while edge.back().id() != vertex_id {
+                let next = vemap.remove(&edge.back().id())?;
+                wire.push_back(edge);
+                edge = next;
+            }
+            wire.push_back(edge);
+            boundaries.push(wire);
+        }
+        let old_loops = face0.boundaries.len() + face1.boundaries.len();
+        if boundaries.len() as isize != old_loops as isize + loop_diff {
+            return None;
+        }
+
+        let orientation = face0.orientation();
+        if !orientation {
+            boundaries.iter_mut().for_each(|wire| {
+                wire.invert();
+            });
+        }
+        let mut face = Face::try_new(boundaries, face0.surface()).ok()?;
+        if !orientation {
+            face.invert();
+        }
+        face.set_attributes(face0.attributes());
+        let chi = self.euler_characteristic();
+        self[i] = face.clone();
+        self.remove(j);
+        debug_assert_eq!(chi, self.euler_characteristic());
+        Some(EulerRecord {
+            faces: vec![face],
+            ..Default::default()
+        })
+    }
+}
+
+impl<P, C, S: Clone> Face<P, C, S> {
+    /// Cuts the face with two boundaries by two bridges connecting the boundaries.
+    fn cut_by_bridges(&self, bridges: [&Edge<P, C>; 2]) -> Option<(Self, Self)> {
+        let (wire0, wire1) = match &self.boundaries[..] {
+            [wire0, wire1] => (wire0, wire1),
+            _ => return None,
+        };
+        let position =
+            |wire: &Wire<P, C>, vertex: &Vertex<P>| wire.iter().position(|e| e.front() == vertex);
+        // orient the bridges as `bridge0`: `wire0` -> `wire1` and `bridge1`: `wire1` -> `wire0`.
+        let orient = |bridge: &Edge<P, C>, from: &Wire<P, C>, to: &Wire<P, C>| match (
+            position(from, bridge.front()),
+            position(to, bridge.back()),
+        ) {
+            (Some(a), Some(b)) => Some((bridge.clone(), a, b)),
+            _ => match (position(from, bridge.back()), position(to, bridge.front())) {
+                (Some(a), Some(b)) => Some((bridge.inverse(), a, b)),
+                _ => None,
+            },
+        };
+        let (bridge0, p0, q0) = orient(bridges[0], wire0, wire1)?;
+        let (bridge1, q1, p1) = orient(bridges[1], wire1, wire0)?;
+        if p0 == p1 || q0 == q1 {
+            return None;
+        }
+        let sub_wire = |wire: &Wire<P, C>, from: usize, to: usize| {
+            let len = (to + wire.len() - from) % wire.len();
+            wire.iter()
+                .cycle()
+                .skip(from)
+                .take(len)
+                .cloned()
+                .collect::<Wire<P, C>>()
+        };
+        let mut boundary0 = sub_wire(wire0, p0, p1);
+        boundary0.push_back(bridge1.inverse());
+        boundary0.append(&mut sub_wire(wire1, q1, q0));
+        boundary0.push_back(bridge0.inverse());
+        let mut boundary1 = sub_wire(wire0, p1, p0);
+        boundary1.push_back(bridge0);
+        boundary1.append(&mut sub_wire(wire1, q0, q1));
+        boundary1.push_back(bridge1);
+
+        let new_face = |boundary: Wire<P, C>| {
+            let mut new_face = Face::try_new(vec![boundary], self.surface()).ok()?;
+            if !self.orientation() {
+                new_face.invert();
+            }
+            new_face.set_attributes(self.attributes());
+            Some(new_face)
+        };
+        Some((new_face(boundary0)?, new_face(boundary1)?))
+    }
+}
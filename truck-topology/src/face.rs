@@ -61,7 +61,7 @@ impl<P, C, S> Face<P, C, S> {
         Face {
             boundaries,
             orientation: true,
-            surface: Arc::new(Mutex::new(surface)),
+            surface: Arc::new(attribute::Shared::new(surface)),
         }
     }
 
@@ -215,7 +215,10 @@ impl<P, C, S> Face<P, C, S> {
     fn renew_pointer(&mut self)
     where S: Clone {
         let surface = self.surface();
-        self.surface = Arc::new(Mutex::new(surface));
+        self.surface = Arc::new(attribute::Shared::with_attributes(
+            surface,
+            self.attributes(),
+        ));
     }
 
     /// Returns an iterator over the edges.
@@ -398,6 +401,52 @@ impl<P, C, S> Face<P, C, S> {
         self.try_add_boundary(wire).remove_try()
     }
 
+    pub(super) fn sub_try_mapped<'a, Q, D, T, KF, KV>(
+        &'a self,
+        edge_map: &mut wire::EdgeEntryMapForTryMapping<'a, P, C, Q, D, KF, KV>,
+        surface_mapping: &mut impl FnMut(&S) -> Option<T>,
+    ) -> Option<Face<Q, D, T>>
+    where
+        KF: FnMut(&'a Edge<P, C>) -> EdgeID<C>,
+        KV: FnMut(&'a Edge<P, C>) -> Option<Edge<Q, D>>,
+    {
+        let wires = self
+            .absolute_boundaries()
+            .iter()
+            .map(|wire| wire.sub_try_mapped(edge_map))
+            .collect::<Option<Vec<_>>>()?;
+        let surface = surface_mapping(&*self.surface.lock())?;
+        let mut face = Face::debug_new(wires, surface);
+        face.set_attributes(self.attributes());
+        if !self.orientation() {
+            face.invert();
+        }
+        Some(face)
+    }
+
+    pub(super) fn sub_mapped<'a, Q, D, T, KF, KV>(
+        &'a self,
+        edge_map: &mut wire::EdgeEntryMapForMapping<'a, P, C, Q, D, KF, KV>,
+        surface_mapping: &mut impl FnMut(&S) -> T,
+    ) -> Face<Q, D, T>
+    where
+        KF: FnMut(&'a Edge<P, C>) -> EdgeID<C>,
+        KV: FnMut(&'a Edge<P, C>) -> Edge<Q, D>,
+    {
+        let wires: Vec<Wire<_, _>> = self
+            .absolute_boundaries()
+            .iter()
+            .map(|wire| wire.sub_mapped(edge_map))
+            .collect();
+        let surface = surface_mapping(&*self.surface.lock());
+        let mut face = Face::debug_new(wires, surface);
+        face.set_attributes(self.attributes());
+        if !self.orientation() {
+            face.invert();
+        }
+        face
+    }
+
     /// Returns a new face whose surface is mapped by `surface_mapping`,
     /// curves are mapped by `curve_mapping` and points are mapped by `point_mapping`.
     /// # Remarks
@@ -417,6 +466,7 @@ impl<P, C, S> Face<P, C, S> {
             .collect::<Option<Vec<_>>>()?;
         let surface = surface_mapping(&*self.surface.lock())?;
         let mut face = Face::debug_new(wires, surface);
+        face.set_attributes(self.attributes());
         if !self.orientation() {
             face.invert();
         }
@@ -489,6 +539,7 @@ impl<P, C, S> Face<P, C, S> {
             .collect();
         let surface = surface_mapping(&*self.surface.lock());
         let mut face = Face::debug_new(wires, surface);
+        face.set_attributes(self.attributes());
         if !self.orientation() {
             face.invert();
         }
@@ -607,7 +658,7 @@ impl<P, C, S> Face<P, C, S> {
     /// assert_ne!(face0.id(), face2.id());
     /// ```
     #[inline(always)]
-    pub fn id(&self) -> FaceID<S> { ID::new(Arc::as_ptr(&self.surface)) }
+    pub fn id(&self) -> FaceID<S> { ID::new(Arc::as_ptr(&self.surface).cast()) }
 
     /// Returns how many same faces.
     ///
@@ -876,7 +927,10 @@ impl<P, C, S> Face<P, C, S> {
         let mut face0 = Face {
             boundaries: self.boundaries.clone(),
             orientation: self.orientation,
-            surface: Arc::new(Mutex::new(self.surface())),
+            surface: Arc::new(attribute::Shared::with_attributes(
+                self.surface(),
+                self.attributes(),
+            )),
         };
         let boundary = &mut face0.boundaries[0];
         let i = boundary
@@ -899,7 +953,10 @@ impl<P, C, S> Face<P, C, S> {
         let face1 = Face {
             boundaries: vec![new_wire],
             orientation: self.orientation,
-            surface: Arc::new(Mutex::new(self.surface())),
+            surface: Arc::new(attribute::Shared::with_attributes(
+                self.surface(),
+                self.attributes(),
+            )),
         };
         Some((face0, face1))
     }
@@ -1000,7 +1057,7 @@ impl<P, C, S> Face<P, C, S> {
         Some(Face {
             boundaries,
             orientation: self.orientation(),
-            surface: Arc::new(Mutex::new(surface)),
+            surface: Arc::new(attribute::Shared::new(surface)),
         })
     }
 
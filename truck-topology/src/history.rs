@@ -0,0 +1,389 @@
+use crate::*;
+use rustc_hash::{FxHashMap as HashMap, FxHashSet as HashSet};
+
+/// The id of a vertex, an edge, or a face, the key of [`History`].
+///
+/// Since the ids are given by the addresses of the geometries, the ids of the entities
+/// are valid as long as the entities are alive.
+pub enum EntityID<P, C, S> {
+    /// the id of a vertex
+    Vertex(VertexID<P>),
+    /// the id of an edge
+    Edge(EdgeID<C>),
+    /// the id of a face
+    Face(FaceID<S>),
+}
+
+impl<P, C, S> Clone for EntityID<P, C, S> {
+    #[inline(always)]
+    fn clone(&self) -> Self { *self }
+}
+
+impl<P, C, S> Copy for EntityID<P, C, S> {}
+
+impl<P, C, S> PartialEq for EntityID<P, C, S> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Vertex(a), Self::Vertex(b)) => a == b,
+            (Self::Edge(a), Self::Edge(b)) => a == b,
+            (Self::Face(a), Self::Face(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl<P, C, S> Eq for EntityID<P, C, S> {}
+
+impl<P, C, S> Hash for EntityID<P, C, S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            Self::Vertex(id) => (0, id).hash(state),
+            Self::Edge(id) => (1, id).hash(state),
+            Self::Face(id) => (2, id).hash(state),
+        }
+    }
+}
+
+impl<P, C, S> Debug for EntityID<P, C, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Vertex(id) => f.debug_tuple("Vertex").field(id).finish(),
+            Self::Edge(id) => f.debug_tuple("Edge").field(id).finish(),
+            Self::Face(id) => f.debug_tuple("Face").field(id).finish(),
+        }
+    }
+}
+
+impl<P, C, S> From<&Vertex<P>> for EntityID<P, C, S> {
+    #[inline(always)]
+    fn from(vertex: &Vertex<P>) -> Self { Self::Vertex(vertex.id()) }
+}
+
+impl<P, C, S> From<&Edge<P, C>> for EntityID<P, C, S> {
+    #[inline(always)]
+    fn from(edge: &Edge<P, C>) -> Self { Self::Edge(edge.id()) }
+}
+
+impl<P, C, S> From<&Face<P, C, S>> for EntityID<P, C, S> {
+    #[inline(always)]
+    fn from(face: &Face<P, C, S>) -> Self { Self::Face(face.id()) }
+}
+
+/// Enumerates the vertices, the edges, and the faces of topological elements without duplication.
+pub trait Entities<P, C, S> {
+    /// Returns the vertices in the order of appearance.
+    fn vertices(&self) -> Vec<Vertex<P>>;
+    /// Returns the edges in the order of appearance.
+    fn edges(&self) -> Vec<Edge<P, C>>;
+    /// Returns the faces in the order of appearance.
+    fn faces(&self) -> Vec<Face<P, C, S>>;
+}
+
+fn unique<T, I: Hash + Eq>(iter: impl IntoIterator<Item = T>, id: impl Fn(&T) -> I) -> Vec<T> {
+    let mut set = HashSet::default();
+    iter.into_iter().filter(|x| set.insert(id(x))).collect()
+}
+
+impl<P, C, S> Entities<P, C, S> for Vertex<P> {
+    fn vertices(&self) -> Vec<Vertex<P>> { vec![self.clone()] }
+    fn edges(&self) -> Vec<Edge<P, C>> { Vec::new() }
+    fn faces(&self) -> Vec<Face<P, C, S>> { Vec::new() }
+}
+
+impl<P, C, S> Entities<P, C, S> for Edge<P, C> {
+    fn vertices(&self) -> Vec<Vertex<P>> {
+        unique([self.front().clone(), self.back().clone()], Vertex::id)
+    }
+    fn edges(&self) -> Vec<Edge<P, C>> { vec![self.clone()] }
+    fn faces(&self) -> Vec<Face<P, C, S>> { Vec::new() }
+}
+
+impl<P, C, S> Entities<P, C, S> for Wire<P, C> {
+    fn vertices(&self) -> Vec<Vertex<P>> {
+        let iter = self
+            .edge_iter()
+            .flat_map(|edge| [edge.front(), edge.back()]);
+        unique(iter.cloned(), Vertex::id)
+    }
+    fn edges(&self) -> Vec<Edge<P, C>> { unique(self.edge_iter().cloned(), Edge::id) }
+    fn faces(&self) -> Vec<Face<P, C, S>> { Vec::new() }
+}
+
+impl<P, C, S> Entities<P, C, S> for Face<P, C, S> {
+    fn vertices(&self) -> Vec<Vertex<P>> { unique(self.vertex_iter(), Vertex::id) }
+    fn edges(&self) -> Vec<Edge<P, C>> { unique(self.edge_iter(), Edge::id) }
+    fn faces(&self) -> Vec<Face<P, C, S>> { vec![self.clone()] }
+}
+
+impl<P, C, S> Entities<P, C, S> for Shell<P, C, S> {
+    fn vertices(&self) -> Vec<Vertex<P>> { unique(self.vertex_iter(), Vertex::id) }
+    fn edges(&self) -> Vec<Edge<P, C>> { unique(self.edge_iter(), Edge::id) }
+    fn faces(&self) -> Vec<Face<P, C, S>> { unique(self.face_iter().cloned(), Face::id) }
+}
+
+impl<P, C, S> Entities<P, C, S> for Solid<P, C, S> {
+    fn vertices(&self) -> Vec<Vertex<P>> { unique(self.vertex_iter(), Vertex::id) }
+    fn edges(&self) -> Vec<Edge<P, C>> { unique(self.edge_iter(), Edge::id) }
+    fn faces(&self) -> Vec<Face<P, C, S>> { unique(self.face_iter().cloned(), Face::id) }
+}
+
+impl<P, C, S> Entities<P, C, S> for Compound<P, C, S> {
+    fn vertices(&self) -> Vec<Vertex<P>> { unique(self.vertex_iter(), Vertex::id) }
+    fn edges(&self) -> Vec<Edge<P, C>> { unique(self.edge_iter(), Edge::id) }
+    fn faces(&self) -> Vec<Face<P, C, S>> { unique(self.face_iter().cloned(), Face::id) }
+}
+
+impl<P, C, S, T: Entities<P, C, S>> Entities<P, C, S> for [T] {
+    fn vertices(&self) -> Vec<Vertex<P>> { unique(self.iter().flat_map(T::vertices), Vertex::id) }
+    fn edges(&self) -> Vec<Edge<P, C>> { unique(self.iter().flat_map(T::edges), Edge::id) }
+    fn faces(&self) -> Vec<Face<P, C, S>> { unique(self.iter().flat_map(T::faces), Face::id) }
+}
+
+impl<P, C, S, T: Entities<P, C, S>> Entities<P, C, S> for Vec<T> {
+    #[inline(always)]
+    fn vertices(&self) -> Vec<Vertex<P>> { self.as_slice().vertices() }
+    #[inline(always)]
+    fn edges(&self) -> Vec<Edge<P, C>> { self.as_slice().edges() }
+    #[inline(always)]
+    fn faces(&self) -> Vec<Face<P, C, S>> { self.as_slice().faces() }
+}
+
+/// The failed results have no entities.
+impl<P, C, S, T: Entities<P, C, S>, E> Entities<P, C, S> for std::result::Result<T, E> {
+    fn vertices(&self) -> Vec<Vertex<P>> { self.as_ref().map_or_else(|_| Vec::new(), T::vertices) }
+    fn edges(&self) -> Vec<Edge<P, C>> { self.as_ref().map_or_else(|_| Vec::new(), T::edges) }
+    fn faces(&self) -> Vec<Face<P, C, S>> { self.as_ref().map_or_else(|_| Vec::new(), T::faces) }
+}
+
+/// The map from the input entities to the output entities.
+type Images<P, C, S> = HashMap<EntityID<P, C, S>, Vec<EntityID<P, C, S>>>;
+/// The pair of an input entity and the output entities.
+type ImagesItem<'a, P, C, S> = (EntityID<P, C, S>, &'a [EntityID<P, C, S>]);
+
+/// The history of a modeling operation, the correspondence from the entities of the input shapes
+/// to the entities of the output shape.
+///
+/// # Details
+/// The ids of the topological entities are given by the addresses, so the operations return
+/// the new entities with the new ids. The history records what happened to the input entities.
+/// - An input entity is *modified* into the output entities which replace it, e.g. the pieces
+///   of a face split by a boolean operation, or the copy of a face moved by a transformation.
+/// - An input entity *generates* the output entities of the other dimensions made from it,
+///   e.g. the side face of an extrusion generated by an edge of the profile.
+/// - An input entity is *deleted* if it has no counterpart in the output.
+///
+/// The input entities which are neither modified nor deleted remain in the output as they are.
+/// The histories of the sequential operations are composed by [`History::then`].
+/// # Examples
+/// ```
+/// use truck_topology::{*, history::*};
+/// let v = Vertex::news([0, 1]);
+/// let edge = Edge::new(&v[0], &v[1], ());
+///
+/// // a copy of the edge
+/// let moved = edge.mapped(|x| x + 10, Clone::clone);
+/// let history: History<i32, (), ()> = History::from_mapped(&edge, &moved).unwrap();
+/// assert_eq!(history.modified(&edge), &[EntityID::from(&moved)]);
+/// assert_eq!(history.modified(&v[1]), &[EntityID::from(moved.back())]);
+/// assert!(history.generated(&edge).is_empty());
+/// assert!(!history.is_deleted(&edge));
+/// ```
+pub struct History<P, C, S> {
+    modified: Images<P, C, S>,
+    generated: Images<P, C, S>,
+    deleted: HashSet<EntityID<P, C, S>>,
+}
+
+impl<P, C, S> Default for History<P, C, S> {
+    fn default() -> Self {
+        Self {
+            modified: HashMap::default(),
+            generated: HashMap::default(),
+            deleted: HashSet::default(),
+        }
+    }
+}
+
+impl<P, C, S> Clone for History<P, C, S> {
+    fn clone(&self) -> Self {
+        Self {
+            modified: self.modified.clone(),
+            generated: self.generated.clone(),
+            deleted: self.deleted.clone(),
+        }
+    }
+}
+
+impl<P, C, S> Debug for History<P, C, S> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("History")
+            .field("modified", &self.modified)
+            .field("generated", &self.generated)
+            .field("deleted", &self.deleted)
+            .finish()
+    }
+}
+
+impl<P, C, S> History<P, C, S> {
+    /// Creates the empty history, i.e. the history of the operation which changes nothing.
+    #[inline(always)]
+    pub fn new() -> Self { Self::default() }
+
+    /// Creates the history of a mapping, e.g. a transformation, between two elements with the same structure.
+    ///
+    /// Each vertex, edge, and face of `input` is modified into the corresponding one of `output`.
+    /// Returns `None` if the numbers of the entities are different.
+    pub fn from_mapped<T0, T1>(input: &T0, output: &T1) -> Option<Self>
+    where
+        T0: Entities<P, C, S> + ?Sized,
+        T1: Entities<P, C, S> + ?Sized, {
+        let (vertices0, vertices1) = (input.vertices(), output.vertices());
+        let (edges0, edges1) = (input.edges(), output.edges());
+        let (faces0, faces1) = (input.faces(), output.faces());
+        if vertices0.len() != vertices1.len()
+            || edges0.len() != edges1.len()
+            || faces0.len() != faces1.len()
+        {
+            return None;
+        }
+        let mut history = Self::new();
+        vertices0
+            .iter()
+            .zip(&vertices1)
+            .for_each(|(v0, v1)| history.add_modified(v0, v1));
+        edges0
+            .iter()
+            .zip(&edges1)
+            .for_each(|(e0, e1)| history.add_modified(e0, e1));
+        faces0
+            .iter()
+            .zip(&faces1)
+            .for_each(|(f0, f1)| history.add_modified(f0, f1));
+        Some(history)
+    }
+
+    /// Records that `input` is modified into `output`.
+    pub fn add_modified(
+        &mut self,
+        input: impl Into<EntityID<P, C, S>>,
+        output: impl Into<EntityID<P, C, S>>,
+    ) {
+        push_unique(
+            self.modified.entry(input.into()).or_default(),
+            output.into(),
+        );
+    }
+
+    /// Records that `input` generates `output`.
+    pub fn add_generated(
+        &mut self,
+        input: impl Into<EntityID<P, C, S>>,
+        output: impl Into<EntityID<P, C, S>>,
+    ) {
+        push_unique(
+            self.generated.entry(input.into()).or_default(),
+            output.into(),
+        );
+    }
+
+    /// Records that `input` is deleted.
+    pub fn add_deleted(&mut self, input: impl Into<EntityID<P, C, S>>) {
+        self.deleted.insert(input.into());
+    }
+
+    /// Returns the output entities into which `input` is modified.
+    pub fn modified(&self, input: impl Into<EntityID<P, C, S>>) -> &[EntityID<P, C, S>] {
+        self.modified.get(&input.into()).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the output entities generated by `input`.
+    pub fn generated(&self, input: impl Into<EntityID<P, C, S>>) -> &[EntityID<P, C, S>] {
+        self.generated.get(&input.into()).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns whether `input` is deleted.
+    pub fn is_deleted(&self, input: impl Into<EntityID<P, C, S>>) -> bool {
+        self.deleted.contains(&input.into())
+    }
+
+    /// Returns the iterator over the pairs of the input entities and the output entities into which they are modified.
+    pub fn modified_iter(&self) -> impl Iterator<Item = ImagesItem<'_, P, C, S>> {
+        self.modified.iter().map(|(id, vec)| (*id, vec.as_slice()))
+    }
+
+    /// Returns the iterator over the pairs of the input entities and the output entities generated by them.
+    pub fn generated_iter(&self) -> impl Iterator<Item = ImagesItem<'_, P, C, S>> {
+        self.generated.iter().map(|(id, vec)| (*id, vec.as_slice()))
+    }
+
+    /// Returns the iterator over the deleted input entities.
+    pub fn deleted_iter(&self) -> impl Iterator<Item = EntityID<P, C, S>> + '_ {
+        self.deleted.iter().copied()
+    }
+
+    /// Returns the images of the entity of the intermediate shape in the output of `self`.
+    fn images(&self, id: EntityID<P, C, S>) -> Vec<EntityID<P, C, S>> {
+        match (self.deleted.contains(&id), self.modified.get(&id)) {
+            (true, _) => Vec::new(),
+            (false, Some(vec)) => vec.clone(),
+            (false, None) => vec![id],
+        }
+    }
+
+    /// Composes the histories of the sequential operations: `self` is the history of the first
+    /// operation, and `next` is the history of the second operation applied to the output of the first one.
+    /// # Examples
+    /// ```
+    /// use truck_topology::{*, history::*};
+    /// let v = Vertex::new(0);
+    /// let v1 = v.mapped(|x| x + 1);
+    /// let v2 = v1.mapped(|x| x + 1);
+    /// let history0: History<i32, (), ()> = History::from_mapped(&v, &v1).unwrap();
+    /// let history1: History<i32, (), ()> = History::from_mapped(&v1, &v2).unwrap();
+    /// let history = history0.then(&history1);
+    /// assert_eq!(history.modified(&v), &[EntityID::from(&v2)]);
+    /// ```
+    pub fn then(&self, next: &Self) -> Self {
+        let mut res = Self::new();
+        // the entities remaining in the intermediate shape and their images
+        let survivors = self
+            .modified
+            .iter()
+            .map(|(id, vec)| (*id, vec.clone()))
+            .chain(
+                next.modified
+                    .keys()
+                    .chain(next.generated.keys())
+                    .chain(&next.deleted)
+                    .filter(|id| !self.modified.contains_key(id) && !self.deleted.contains(id))
+                    .map(|id| (*id, vec![*id])),
+            )
+            .collect::<HashMap<_, _>>();
+        survivors.into_iter().for_each(|(id, vec)| {
+            let images: Vec<_> = vec.iter().flat_map(|x| next.images(*x)).collect();
+            match images.is_empty() {
+                true => res.add_deleted(id),
+                false if images != [id] => images
+                    .into_iter()
+                    .for_each(|x| push_unique(res.modified.entry(id).or_default(), x)),
+                false => {}
+            }
+            vec.iter()
+                .flat_map(|x| next.generated.get(x).into_iter().flatten())
+                .for_each(|x| push_unique(res.generated.entry(id).or_default(), *x));
+        });
+        self.generated.iter().for_each(|(id, vec)| {
+            vec.iter()
+                .flat_map(|x| next.images(*x))
+                .for_each(|x| push_unique(res.generated.entry(*id).or_default(), x));
+        });
+        res.deleted.extend(self.deleted.iter().copied());
+        res
+    }
+}
+
+fn push_unique<T: PartialEq>(vec: &mut Vec<T>, x: T) {
+    if !vec.contains(&x) {
+        vec.push(x);
+    }
+}
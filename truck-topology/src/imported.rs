@@ -79,6 +79,9 @@ macro_rules! prelude {
         /// Solid, attached to a closed shells.
         #[allow(unused)]
         $($pub$($super)?)? type Solid = $crate::Solid<$point, $curve, $surface>;
+        /// Compound, a collection of solids, shells, wires and vertices.
+        #[allow(unused)]
+        $($pub$($super)?)? type Compound = $crate::Compound<$point, $curve, $surface>;
 
         /// The id of vertex. `Copy` trait is implemented.
         #[allow(unused)]
@@ -89,6 +92,12 @@ macro_rules! prelude {
         /// The id that does not depend on the direction of the face.
         #[allow(unused)]
         $($pub$($super)?)? type FaceID = $crate::FaceID<$surface>;
+        /// The id of a vertex, an edge, or a face, the key of history.
+        #[allow(unused)]
+        $($pub$($super)?)? type EntityID = $crate::history::EntityID<$point, $curve, $surface>;
+        /// The history of a modeling operation.
+        #[allow(unused)]
+        $($pub$($super)?)? type History = $crate::history::History<$point, $curve, $surface>;
 
         /// Serialized compressed edge
         #[allow(unused)]
@@ -102,6 +111,9 @@ macro_rules! prelude {
         /// Serialized compressed solid
         #[allow(unused)]
         $($pub$($super)?)? type CompressedSolid = $crate::compress::CompressedSolid<$point, $curve, $surface>;
+        /// Serialized compressed compound
+        #[allow(unused)]
+        $($pub$($super)?)? type CompressedCompound = $crate::compress::CompressedCompound<$point, $curve, $surface>;
     };
 }
 
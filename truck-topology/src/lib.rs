@@ -42,7 +42,7 @@
 //! let solid = Solid::new(vec![shell]);
 //! ```
 //! ## Elements and containers
-//! Main structures in `truck_topology` consist 4 topological elements and 2 topological containers.
+//! Main structures in `truck_topology` consist 4 topological elements and 3 topological containers.
 //! ### Topological elements
 //! The following structures are topological elements.
 //!
@@ -58,11 +58,14 @@
 //!
 //! * [`Wire`](./struct.Wire.html)
 //! * [`Shell`](./struct.Shell.html)
+//! * [`Compound`](./struct.Compound.html)
 //!
 //! The entities of `Wire` and `Shell` are `std::collections::VecDeque<Edge>` and `std::vec::Vec<Face>`,
 //! respectively, and many methods inherited by `Deref` and `DerefMut`.
 //! These containers are used for creating higher-dimensional topological elements and checked the
 //! regularity (e.g. connectivity, closedness, and so on) before creating these elements.
+//! `Compound` is a collection of solids, shells, wires and vertices with no regularity conditions,
+//! e.g. several solids with loose wires and faces.
 //! ## Features
 //! * `nightly` – Use features available only in a `nightly` toolchain.
 //! * `rclite` – Use of `rclite::Arc` instead of `std::syn::Arc`. The latter
@@ -107,7 +110,7 @@ const SEARCH_PARAMETER_TRIALS: usize = 100;
 /// ```
 #[derive(Debug)]
 pub struct Vertex<P> {
-    point: Arc<Mutex<P>>,
+    point: Arc<attribute::Shared<P>>,
 }
 
 /// Edge, which consists two vertices.
@@ -126,7 +129,7 @@ pub struct Vertex<P> {
 pub struct Edge<P, C> {
     vertices: (Vertex<P>, Vertex<P>),
     orientation: bool,
-    curve: Arc<Mutex<C>>,
+    curve: Arc<attribute::Shared<C>>,
 }
 
 /// Wire, a path or cycle which consists some edges.
@@ -157,7 +160,7 @@ pub struct Wire<P, C> {
 pub struct Face<P, C, S> {
     boundaries: Vec<Wire<P, C>>,
     orientation: bool,
-    surface: Arc<Mutex<S>>,
+    surface: Arc<attribute::Shared<S>>,
 }
 
 /// Shell, a connected compounded faces.
@@ -175,6 +178,18 @@ pub struct Solid<P, C, S> {
     boundaries: Vec<Shell<P, C, S>>,
 }
 
+/// Compound, a collection of solids, shells, wires and vertices.
+///
+/// A compound imposes no conditions on its members. The loose faces are contained as shells,
+/// and the loose edges as wires.
+#[derive(Clone, Debug)]
+pub struct Compound<P, C, S> {
+    solids: Vec<Solid<P, C, S>>,
+    shells: Vec<Shell<P, C, S>>,
+    wires: Vec<Wire<P, C>>,
+    vertices: Vec<Vertex<P>>,
+}
+
 /// `Result` with crate's errors.
 pub type Result<T> = std::result::Result<T, errors::Error>;
 
@@ -384,12 +399,20 @@ pub enum SolidDisplayFormat {
     },
 }
 
+pub mod attribute;
+/// versioned binary format of the compressed solids.
+pub mod binary;
+mod compound;
 pub mod compress;
 mod edge;
 /// classifies the errors that can occur in this crate.
 pub mod errors;
+/// Euler operators, the validated editing of the shells keeping the Euler-Poincaré characteristic.
+pub mod euler;
 /// Defines the boundary iterator.
 pub mod face;
+/// records the correspondence of the entities between the input and the output of modeling operations.
+pub mod history;
 /// classifies shell conditions and defines the face iterators.
 pub mod shell;
 mod solid;
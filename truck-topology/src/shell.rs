@@ -541,6 +541,34 @@ impl<P, C, S> Shell<P, C, S> {
             .collect()
     }
 
+    pub(super) fn sub_try_mapped<'a, Q, D, T, KF, KV>(
+        &'a self,
+        edge_map: &mut wire::EdgeEntryMapForTryMapping<'a, P, C, Q, D, KF, KV>,
+        surface_mapping: &mut impl FnMut(&S) -> Option<T>,
+    ) -> Option<Shell<Q, D, T>>
+    where
+        KF: FnMut(&'a Edge<P, C>) -> EdgeID<C>,
+        KV: FnMut(&'a Edge<P, C>) -> Option<Edge<Q, D>>,
+    {
+        self.face_iter()
+            .map(|face| face.sub_try_mapped(edge_map, surface_mapping))
+            .collect()
+    }
+
+    pub(super) fn sub_mapped<'a, Q, D, T, KF, KV>(
+        &'a self,
+        edge_map: &mut wire::EdgeEntryMapForMapping<'a, P, C, Q, D, KF, KV>,
+        surface_mapping: &mut impl FnMut(&S) -> T,
+    ) -> Shell<Q, D, T>
+    where
+        KF: FnMut(&'a Edge<P, C>) -> EdgeID<C>,
+        KV: FnMut(&'a Edge<P, C>) -> Edge<Q, D>,
+    {
+        self.face_iter()
+            .map(|face| face.sub_mapped(edge_map, surface_mapping))
+            .collect()
+    }
+
     /// Returns a new shell whose surfaces are mapped by `surface_mapping`,
     /// curves are mapped by `curve_mapping` and points are mapped by `point_mapping`.
     /// # Remarks
@@ -558,21 +586,7 @@ impl<P, C, S> Shell<P, C, S> {
             Edge::id,
             wire::edge_entry_map_try_closure(&mut vertex_map, &mut curve_mapping),
         );
-        self.face_iter()
-            .map(|face| {
-                let wires = face
-                    .absolute_boundaries()
-                    .iter()
-                    .map(|wire| wire.sub_try_mapped(&mut edge_map))
-                    .collect::<Option<Vec<_>>>()?;
-                let surface = surface_mapping(&*face.surface.lock())?;
-                let mut new_face = Face::debug_new(wires, surface);
-                if !face.orientation() {
-                    new_face.invert();
-                }
-                Some(new_face)
-            })
-            .collect()
+        self.sub_try_mapped(&mut edge_map, &mut surface_mapping)
     }
 
     /// Returns a new shell whose surfaces are mapped by `surface_mapping`,
@@ -652,21 +666,7 @@ impl<P, C, S> Shell<P, C, S> {
             Edge::id,
             wire::edge_entry_map_closure(&mut vertex_map, &mut curve_mapping),
         );
-        self.face_iter()
-            .map(|face| {
-                let wires: Vec<Wire<_, _>> = face
-                    .absolute_boundaries()
-                    .iter()
-                    .map(|wire| wire.sub_mapped(&mut edge_map))
-                    .collect();
-                let surface = surface_mapping(&*face.surface.lock());
-                let mut new_face = Face::debug_new(wires, surface);
-                if !face.orientation() {
-                    new_face.invert();
-                }
-                new_face
-            })
-            .collect()
+        self.sub_mapped(&mut edge_map, &mut surface_mapping)
     }
 
     /// Returns the consistence of the geometry of end vertices
@@ -730,6 +730,9 @@ impl<P, C, S> Shell<P, C, S> {
     }
     /// Removes `vertex` from `self` by concat two edges on both sides.
     ///
+    /// The two edges may be shared by the faces whose absolute boundaries have them in the same direction,
+    /// i.e. the faces with the different orientations.
+    ///
     /// # Returns
     /// Returns the new created edge.
     ///
@@ -765,13 +768,18 @@ impl<P, C, S> Shell<P, C, S> {
         } else {
             let (wire0, idx0) = vec.pop().unwrap();
             let (wire1, idx1) = vec.pop().unwrap();
-            if !wire0[idx0].is_same(&wire1[(idx1 + 1) % wire1.len()])
-                || !wire0[(idx0 + 1) % wire0.len()].is_same(&wire1[idx1])
-            {
+            let (next0, next1) = ((idx0 + 1) % wire0.len(), (idx1 + 1) % wire1.len());
+            // The absolute boundaries of the faces with different orientations have the same direction.
+            let opposite = wire0[idx0].is_same(&wire1[next1]) && wire0[next0].is_same(&wire1[idx1]);
+            let same = wire0[idx0].is_same(&wire1[idx1]) && wire0[next0].is_same(&wire1[next1]);
+            if !opposite && !same {
                 return None;
             }
-            let edge = wire0[idx0].concat(&wire0[(idx0 + 1) % wire0.len()]).ok()?;
-            wire1.swap_subwire_into_edges(idx1, edge.inverse());
+            let edge = wire0[idx0].concat(&wire0[next0]).ok()?;
+            match opposite {
+                true => wire1.swap_subwire_into_edges(idx1, edge.inverse()),
+                false => wire1.swap_subwire_into_edges(idx1, edge.clone()),
+            };
             wire0.swap_subwire_into_edges(idx0, edge.clone());
             Some(edge)
         }
@@ -14,7 +14,7 @@ impl<P> Vertex<P> {
     #[inline(always)]
     pub fn new(point: P) -> Vertex<P> {
         Vertex {
-            point: Arc::new(Mutex::new(point)),
+            point: Arc::new(attribute::Shared::new(point)),
         }
     }
 
@@ -70,7 +70,9 @@ impl<P> Vertex<P> {
         &self,
         mut point_mapping: impl FnMut(&P) -> Option<Q>,
     ) -> Option<Vertex<Q>> {
-        Some(Vertex::new(point_mapping(&*self.point.lock())?))
+        let vertex = Vertex::new(point_mapping(&*self.point.lock())?);
+        vertex.set_attributes(self.attributes());
+        Some(vertex)
     }
 
     /// Returns vertex whose point is converted by `point_mapping`.
@@ -87,12 +89,14 @@ impl<P> Vertex<P> {
     #[doc(hidden)]
     #[inline(always)]
     pub fn mapped<Q>(&self, mut point_mapping: impl FnMut(&P) -> Q) -> Vertex<Q> {
-        Vertex::new(point_mapping(&*self.point.lock()))
+        let vertex = Vertex::new(point_mapping(&*self.point.lock()));
+        vertex.set_attributes(self.attributes());
+        vertex
     }
 
     /// Returns the id of the vertex.
     #[inline(always)]
-    pub fn id(&self) -> VertexID<P> { ID::new(Arc::as_ptr(&self.point)) }
+    pub fn id(&self) -> VertexID<P> { ID::new(Arc::as_ptr(&self.point).cast()) }
 
     /// Returns how many same vertices.
     ///
@@ -541,9 +541,9 @@ impl<P, C> Wire<P, C> {
     }
 }
 
-type EdgeEntryMapForTryMapping<'a, P, C, Q, D, KF, KV> =
+pub(super) type EdgeEntryMapForTryMapping<'a, P, C, Q, D, KF, KV> =
     EntryMap<EdgeID<C>, Option<Edge<Q, D>>, KF, KV, &'a Edge<P, C>>;
-type EdgeEntryMapForMapping<'a, P, C, Q, D, KF, KV> =
+pub(super) type EdgeEntryMapForMapping<'a, P, C, Q, D, KF, KV> =
     EntryMap<EdgeID<C>, Edge<Q, D>, KF, KV, &'a Edge<P, C>>;
 
 pub(super) fn edge_entry_map_try_closure<'a, P, C, Q, D, KF, VF>(
@@ -560,7 +560,9 @@ where
         let vb = edge.absolute_back();
         let vertex1 = vertex_map.entry_or_insert(vb).clone()?;
         let curve = curve_mapping(&*edge.curve.lock())?;
-        Some(Edge::debug_new(&vertex0, &vertex1, curve))
+        let new_edge = Edge::debug_new(&vertex0, &vertex1, curve);
+        new_edge.set_attributes(edge.attributes());
+        Some(new_edge)
     }
 }
 
@@ -578,7 +580,9 @@ where
         let vb = edge.absolute_back();
         let vertex1 = vertex_map.entry_or_insert(vb).clone();
         let curve = curve_mapping(&*edge.curve.lock());
-        Edge::debug_new(&vertex0, &vertex1, curve)
+        let new_edge = Edge::debug_new(&vertex0, &vertex1, curve);
+        new_edge.set_attributes(edge.attributes());
+        new_edge
     }
 }
 
@@ -1,7 +1,7 @@
 use std::ops::Bound;
 use truck_base::{assert_near, cgmath64::*, tolerance::*};
 use truck_geotrait::*;
-use truck_topology::*;
+use truck_topology::{shell::ShellCondition, *};
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 struct Segment {
@@ -208,3 +208,206 @@ fn solid_cut_edge() {
     let count = tri.edge_iter().count();
     assert_eq!(count, 12);
 }
+
+#[test]
+fn concat_edges_of_inverted_face() {
+    let p = vec![
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+        Point3::new(0.0, 0.5, 0.0),
+    ];
+    let v = Vertex::news(&p);
+    let edge = [
+        Edge::new(&v[0], &v[1], Segment::new(p[0], p[1])),
+        Edge::new(&v[0], &v[2], Segment::new(p[0], p[2])),
+        Edge::new(&v[0], &v[3], Segment::new(p[0], p[3])),
+        Edge::new(&v[1], &v[2], Segment::new(p[1], p[2])),
+        Edge::new(&v[1], &v[3], Segment::new(p[1], p[3])),
+        Edge::new(&v[2], &v[3], Segment::new(p[2], p[3])),
+    ];
+    // The absolute boundaries of the first two faces have `edge[1]` in the same direction.
+    let mut shell: Shell<_, _, _> = vec![
+        Face::new(
+            vec![vec![edge[0].clone(), edge[3].clone(), edge[1].inverse()].into()],
+            (),
+        ),
+        Face::new(
+            vec![vec![edge[2].clone(), edge[5].inverse(), edge[1].inverse()].into()],
+            (),
+        )
+        .inverse(),
+        Face::new(
+            vec![vec![edge[2].clone(), edge[4].inverse(), edge[0].inverse()].into()],
+            (),
+        ),
+        Face::new(
+            vec![vec![edge[3].clone(), edge[5].clone(), edge[4].inverse()].into()],
+            (),
+        )
+        .inverse(),
+    ]
+    .into();
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+    assert!(shell.cut_edge(edge[1].id(), &v[4]).is_some());
+    assert_eq!(shell.edge_iter().count(), 14);
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+
+    let new_edge = shell.remove_vertex_by_concat_edges(v[4].id()).unwrap();
+    assert_eq!(shell.edge_iter().count(), 12);
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+    assert!(shell.vertex_iter().all(|vertex| vertex != v[4]));
+    // the new edge is used by the two faces in the opposite directions.
+    let used: Vec<_> = shell
+        .face_iter()
+        .flat_map(|face| face.boundaries()[0].clone())
+        .filter(|edge| edge.is_same(&new_edge))
+        .collect();
+    assert_eq!(used.len(), 2);
+    assert_eq!(used[0].front(), used[1].back());
+    assert_eq!(used[0].back(), used[1].front());
+}
+
+fn tetrahedron() -> (Vec<Edge<Point3, Segment>>, Shell<Point3, Segment, ()>) {
+    let p = [
+        Point3::new(0.0, 0.0, 0.0),
+        Point3::new(1.0, 0.0, 0.0),
+        Point3::new(0.0, 1.0, 0.0),
+        Point3::new(0.0, 0.0, 1.0),
+    ];
+    let v = Vertex::news(p);
+    let edge = vec![
+        Edge::new(&v[0], &v[1], Segment::new(p[0], p[1])),
+        Edge::new(&v[0], &v[2], Segment::new(p[0], p[2])),
+        Edge::new(&v[0], &v[3], Segment::new(p[0], p[3])),
+        Edge::new(&v[1], &v[2], Segment::new(p[1], p[2])),
+        Edge::new(&v[1], &v[3], Segment::new(p[1], p[3])),
+        Edge::new(&v[2], &v[3], Segment::new(p[2], p[3])),
+    ];
+    let shell = vec![
+        Face::new(
+            vec![vec![edge[0].clone(), edge[3].clone(), edge[1].inverse()].into()],
+            (),
+        ),
+        Face::new(
+            vec![vec![edge[1].clone(), edge[5].clone(), edge[2].inverse()].into()],
+            (),
+        ),
+        Face::new(
+            vec![vec![edge[2].clone(), edge[4].inverse(), edge[0].inverse()].into()],
+            (),
+        ),
+        Face::new(
+            vec![vec![edge[3].clone(), edge[5].clone(), edge[4].inverse()].into()],
+            (),
+        )
+        .inverse(),
+    ]
+    .into();
+    (edge, shell)
+}
+
+#[test]
+fn make_kill_edge_vertex() {
+    let (edge, mut shell) = tetrahedron();
+    assert_eq!(shell.euler_characteristic(), 2);
+    let vertex = Vertex::new(Point3::new(0.0, 0.5, 0.0));
+    let record = shell.make_edge_vertex(edge[1].id(), &vertex).unwrap();
+    assert_eq!(record.vertices, vec![vertex.clone()]);
+    assert_eq!(record.edges.len(), 2);
+    assert_eq!(shell.edge_iter().count(), 14);
+    assert_eq!(shell.euler_characteristic(), 2);
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+    // the vertex is already included.
+    assert!(shell.make_edge_vertex(edge[0].id(), &vertex).is_none());
+
+    // the original vertices have three adjacent edges.
+    let v0 = edge[0].front().id();
+    assert!(shell.kill_edge_vertex(v0).is_none());
+    let record = shell.kill_edge_vertex(vertex.id()).unwrap();
+    assert_eq!(record.edges.len(), 1);
+    assert_eq!(shell.edge_iter().count(), 12);
+    assert_eq!(shell.euler_characteristic(), 2);
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+}
+
+#[test]
+fn make_kill_edge_face() {
+    let (edge, mut shell) = tetrahedron();
+    let v0 = edge[0].front();
+    let vertex = Vertex::new(Point3::new(0.5, 0.5, 0.0));
+    let halves = shell.make_edge_vertex(edge[3].id(), &vertex).unwrap().edges;
+    let face_id = shell[0].id();
+    let new_edge = Edge::new(v0, &vertex, Segment::new(v0.point(), vertex.point()));
+
+    // the faces on both sides also share the other half of `edge[3]`.
+    assert!(shell.kill_edge_face(halves[0].id()).is_none());
+    assert!(shell.kill_edge_face(halves[1].id()).is_none());
+
+    // the edge is already included.
+    assert!(shell.make_edge_face(face_id, &edge[0]).is_none());
+    let record = shell.make_edge_face(face_id, &new_edge).unwrap();
+    assert_eq!(record.faces.len(), 2);
+    assert_eq!(shell.len(), 5);
+    assert_eq!(shell.euler_characteristic(), 2);
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+
+    let record = shell.kill_edge_face(new_edge.id()).unwrap();
+    assert_eq!(shell.len(), 4);
+    assert_eq!(record.faces[0].boundaries()[0].len(), 4);
+    assert_eq!(shell.euler_characteristic(), 2);
+    assert_eq!(shell.shell_condition(), ShellCondition::Closed);
+}
+
+#[test]
+fn make_kill_edge_ring() {
+    // a square with a square hole, and its inner face
+    let v = Vertex::news([(); 8]);
+    let outer: Wire<(), ()> = (0..4)
+        .map(|i| Edge::new(&v[i], &v[(i + 1) % 4], ()))
+        .collect();
+    let inner: Wire<(), ()> = (0..4)
+        .map(|i| Edge::new(&v[4 + i], &v[4 + (i + 1) % 4], ()))
+        .collect();
+    let inner_edge = inner[0].clone();
+    let mut shell: Shell<(), (), ()> = vec![
+        Face::new(vec![outer, inner.inverse()], ()),
+        Face::new(vec![inner], ()),
+    ]
+    .into();
+    assert_eq!(shell.euler_characteristic(), 1);
+    let face_id = shell[0].id();
+    let bridges = [Edge::new(&v[4], &v[0], ()), Edge::new(&v[2], &v[6], ())];
+    // the ends of the bridges on the outer boundary must be different.
+    let wrong = Edge::new(&v[0], &v[5], ());
+    assert!(shell
+        .make_edge_kill_ring(face_id, [&bridges[0], &wrong])
+        .is_none());
+    // the face of the hole has only one boundary.
+    let inner_id = shell[1].id();
+    assert!(shell
+        .make_edge_kill_ring(inner_id, [&bridges[0], &bridges[1]])
+        .is_none());
+
+    let record = shell
+        .make_edge_kill_ring(face_id, [&bridges[0], &bridges[1]])
+        .unwrap();
+    assert_eq!(record.edges.len(), 2);
+    assert_eq!(shell.len(), 3);
+    assert!(shell.iter().all(|face| face.boundaries().len() == 1));
+    assert_eq!(shell.euler_characteristic(), 1);
+    assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+
+    // the edges are not shared by the same two faces.
+    assert!(shell
+        .kill_edge_make_ring([bridges[0].id(), inner_edge.id()])
+        .is_none());
+    let record = shell
+        .kill_edge_make_ring([bridges[0].id(), bridges[1].id()])
+        .unwrap();
+    assert_eq!(record.faces[0].boundaries().len(), 2);
+    assert_eq!(shell.len(), 2);
+    assert_eq!(shell.euler_characteristic(), 1);
+    assert_eq!(shell.shell_condition(), ShellCondition::Oriented);
+}